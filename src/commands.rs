@@ -1,16 +1,48 @@
 use crate::commands::{
-    add_player::AddPlayer, add_score::AddScore, delete_player::DeletePlayer,
-    delete_score::DeleteScore, list_games::ListGames,
+    add_player::AddPlayer, add_score::AddScore, balance::Balance, breakdown::Breakdown,
+    delete_player::DeletePlayer, delete_score::DeleteScore, export::Export, game::GameCommand,
+    history::History, import::Import, import_bundle::ImportBundle, leaderboard::Leaderboard,
+    list_games::ListGames, list_players::ListPlayers, merge::Merge, player::PlayerCommand,
+    predict::Predict, promote_guest::PromoteGuest, ratings::Ratings, redo::Redo,
+    rename_game::RenameGame, report::ReportCommand, round::RoundCommand, season::SeasonCommand,
+    session::SessionCommand, show_player::ShowPlayer, stats::Stats,
+    storage_mode::StorageModeCommand, tournament::TournamentCommand, undo::Undo, versus::Versus,
 };
 use std::collections::HashMap;
 
 mod add_player;
 mod add_score;
+mod balance;
+mod breakdown;
 mod delete_player;
 mod delete_score;
+mod export;
+mod game;
+mod history;
+mod import;
+mod import_bundle;
+mod leaderboard;
 mod list_games;
+mod list_players;
+mod merge;
 mod models;
+mod player;
+mod predict;
+mod promote_guest;
+mod ratings;
+mod redo;
+mod rename_game;
+mod report;
+mod round;
+mod season;
+mod session;
+mod show_player;
+mod stats;
+mod storage_mode;
+mod tournament;
+mod undo;
 mod utils;
+mod versus;
 
 #[derive(Debug)]
 enum CommandType {
@@ -19,11 +51,113 @@ enum CommandType {
     AddScore,
     DeleteScore,
     ListGames,
+    Import,
+    Export,
+    ImportBundle,
+    Merge,
+    Undo,
+    Redo,
+    History,
+    StorageMode,
+    Versus,
+    Leaderboard,
+    Stats,
+    Ratings,
+    Predict,
+    Balance,
+    Season,
+    Tournament,
+    Game,
+    RenameGame,
+    Session,
+    Breakdown,
+    Round,
+    Player,
+    ListPlayers,
+    ShowPlayer,
+    PromoteGuest,
+    Report,
     Invalid,
 }
 
 pub const SAVE_DIR_OPTIONAL_ARGUMENT: &str = "--save-dir";
 pub const TIME_OPTIONAL_ARGUMENT: &str = "--time";
+pub const PLAYER_MAP_OPTIONAL_ARGUMENT: &str = "--player-map";
+pub const OUTPUT_OPTIONAL_ARGUMENT: &str = "--output";
+pub const POLICY_OPTIONAL_ARGUMENT: &str = "--policy";
+pub const AS_OF_OPTIONAL_ARGUMENT: &str = "--as-of";
+pub const GAME_OPTIONAL_ARGUMENT: &str = "--game";
+pub const LAST_OPTIONAL_ARGUMENT: &str = "--last";
+pub const FORMAT_OPTIONAL_ARGUMENT: &str = "--format";
+pub const MIN_PLAYS_OPTIONAL_ARGUMENT: &str = "--min-plays";
+pub const ALGORITHM_OPTIONAL_ARGUMENT: &str = "--algorithm";
+pub const TEAMS_OPTIONAL_ARGUMENT: &str = "--teams";
+pub const START_OPTIONAL_ARGUMENT: &str = "--start";
+pub const END_OPTIONAL_ARGUMENT: &str = "--end";
+pub const GAMES_OPTIONAL_ARGUMENT: &str = "--games";
+pub const SCHEME_OPTIONAL_ARGUMENT: &str = "--scheme";
+pub const MIN_PLAYERS_OPTIONAL_ARGUMENT: &str = "--min-players";
+pub const MAX_PLAYERS_OPTIONAL_ARGUMENT: &str = "--max-players";
+pub const DURATION_OPTIONAL_ARGUMENT: &str = "--duration";
+pub const CATEGORY_OPTIONAL_ARGUMENT: &str = "--category";
+pub const LOCATION_OPTIONAL_ARGUMENT: &str = "--location";
+pub const NOTES_OPTIONAL_ARGUMENT: &str = "--notes";
+pub const TAGS_OPTIONAL_ARGUMENT: &str = "--tags";
+pub const SESSION_OPTIONAL_ARGUMENT: &str = "--session";
+pub const CATEGORIES_OPTIONAL_ARGUMENT: &str = "--categories";
+pub const DISPLAY_NAME_OPTIONAL_ARGUMENT: &str = "--display-name";
+pub const NICKNAME_OPTIONAL_ARGUMENT: &str = "--nickname";
+pub const JOINED_OPTIONAL_ARGUMENT: &str = "--joined";
+pub const FAVORITE_GAMES_OPTIONAL_ARGUMENT: &str = "--favorite-games";
+pub const COLOR_OPTIONAL_ARGUMENT: &str = "--color";
+pub const EMOJI_OPTIONAL_ARGUMENT: &str = "--emoji";
+pub const ACTIVE_OPTIONAL_ARGUMENT: &str = "--active";
+pub const SORT_OPTIONAL_ARGUMENT: &str = "--sort";
+pub const AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT: &str = "--auto-add-players";
+pub const INCLUDE_GUESTS_OPTIONAL_ARGUMENT: &str = "--include-guests";
+
+const OPTIONAL_ARGUMENTS: [&str; 33] = [
+    TIME_OPTIONAL_ARGUMENT,
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    PLAYER_MAP_OPTIONAL_ARGUMENT,
+    OUTPUT_OPTIONAL_ARGUMENT,
+    POLICY_OPTIONAL_ARGUMENT,
+    AS_OF_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    LAST_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+    MIN_PLAYS_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+    TEAMS_OPTIONAL_ARGUMENT,
+    START_OPTIONAL_ARGUMENT,
+    END_OPTIONAL_ARGUMENT,
+    GAMES_OPTIONAL_ARGUMENT,
+    SCHEME_OPTIONAL_ARGUMENT,
+    MIN_PLAYERS_OPTIONAL_ARGUMENT,
+    MAX_PLAYERS_OPTIONAL_ARGUMENT,
+    DURATION_OPTIONAL_ARGUMENT,
+    CATEGORY_OPTIONAL_ARGUMENT,
+    LOCATION_OPTIONAL_ARGUMENT,
+    NOTES_OPTIONAL_ARGUMENT,
+    TAGS_OPTIONAL_ARGUMENT,
+    SESSION_OPTIONAL_ARGUMENT,
+    CATEGORIES_OPTIONAL_ARGUMENT,
+    DISPLAY_NAME_OPTIONAL_ARGUMENT,
+    NICKNAME_OPTIONAL_ARGUMENT,
+    JOINED_OPTIONAL_ARGUMENT,
+    FAVORITE_GAMES_OPTIONAL_ARGUMENT,
+    COLOR_OPTIONAL_ARGUMENT,
+    EMOJI_OPTIONAL_ARGUMENT,
+    ACTIVE_OPTIONAL_ARGUMENT,
+    SORT_OPTIONAL_ARGUMENT,
+];
+
+/// Optional arguments that take no value. They are set to an empty string
+/// when given.
+const FLAG_ARGUMENTS: [&str; 2] = [
+    AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+];
 
 pub struct Command {
     command: CommandType,
@@ -39,8 +173,8 @@ impl Command {
     ) -> Command {
         Command {
             command: command_type,
-            args: args,
-            optional_args: optional_args,
+            args,
+            optional_args,
         }
     }
 
@@ -74,17 +208,149 @@ impl Command {
                 let command = ListGames::create(&self.get_args(), &self.get_optional_args())?;
                 command.run()
             }
+            CommandType::Import => {
+                let command = Import::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Export => {
+                let command = Export::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::ImportBundle => {
+                let command = ImportBundle::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Merge => {
+                let command = Merge::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Undo => {
+                let command = Undo::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Redo => {
+                let command = Redo::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::History => {
+                let command = History::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::StorageMode => {
+                let command =
+                    StorageModeCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Versus => {
+                let command = Versus::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Leaderboard => {
+                let command = Leaderboard::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Stats => {
+                let command = Stats::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Ratings => {
+                let command = Ratings::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Predict => {
+                let command = Predict::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Balance => {
+                let command = Balance::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Season => {
+                let command = SeasonCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Tournament => {
+                let command =
+                    TournamentCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Game => {
+                let command = GameCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::RenameGame => {
+                let command = RenameGame::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Session => {
+                let command = SessionCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Breakdown => {
+                let command = Breakdown::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Round => {
+                let command = RoundCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Player => {
+                let command = PlayerCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::ListPlayers => {
+                let command = ListPlayers::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::ShowPlayer => {
+                let command = ShowPlayer::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::PromoteGuest => {
+                let command = PromoteGuest::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
+            CommandType::Report => {
+                let command = ReportCommand::create(&self.get_args(), &self.get_optional_args())?;
+                command.run()
+            }
             CommandType::Invalid => Err("Invalid or missing command.".to_string()),
         }
     }
 
     pub fn parse(args: &[String]) -> Result<Command, String> {
-        let command_type = match args.get(0).map(|s| s.as_str()) {
+        let command_type = match args.first().map(|s| s.as_str()) {
             Some("add-player") => CommandType::AddPlayer,
             Some("delete-player") => CommandType::DeletePlayer,
             Some("add-score") => CommandType::AddScore,
             Some("delete-score") => CommandType::DeleteScore,
             Some("list-games") => CommandType::ListGames,
+            Some("import") => CommandType::Import,
+            Some("export") => CommandType::Export,
+            Some("import-bundle") => CommandType::ImportBundle,
+            Some("merge") => CommandType::Merge,
+            Some("undo") => CommandType::Undo,
+            Some("redo") => CommandType::Redo,
+            Some("history") => CommandType::History,
+            Some("storage-mode") => CommandType::StorageMode,
+            Some("versus") => CommandType::Versus,
+            Some("leaderboard") => CommandType::Leaderboard,
+            Some("stats") => CommandType::Stats,
+            Some("ratings") => CommandType::Ratings,
+            Some("predict") => CommandType::Predict,
+            Some("balance") => CommandType::Balance,
+            Some("season") => CommandType::Season,
+            Some("tournament") => CommandType::Tournament,
+            Some("game") => CommandType::Game,
+            Some("rename-game") => CommandType::RenameGame,
+            Some("session") => CommandType::Session,
+            Some("breakdown") => CommandType::Breakdown,
+            Some("round") => CommandType::Round,
+            Some("player") => CommandType::Player,
+            Some("list-players") => CommandType::ListPlayers,
+            Some("show-player") => CommandType::ShowPlayer,
+            Some("promote-guest") => CommandType::PromoteGuest,
+            Some("report") => CommandType::Report,
             _ => CommandType::Invalid,
         };
 
@@ -122,7 +388,10 @@ fn get_optional_args(args: &[String]) -> Result<HashMap<String, String>, String>
 
     let mut i = 0;
     while i < args.len() {
-        if [TIME_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT].contains(&args[i].as_str()) {
+        if FLAG_ARGUMENTS.contains(&args[i].as_str()) {
+            optional_args.insert(args[i].clone(), String::new());
+            i += 1;
+        } else if OPTIONAL_ARGUMENTS.contains(&args[i].as_str()) {
             if i + 1 < args.len() {
                 if args[i + 1].as_str().starts_with("--") {
                     return Err(format!("Missing value for optional argument {}", args[i]));
@@ -243,7 +512,7 @@ mod tests {
             "path/to/dir".to_string(),
         ];
 
-        let expected_positional_args = vec!["player-name".to_string()];
+        let expected_positional_args = ["player-name".to_string()];
         let positional_args = get_positional_args(&args);
 
         assert_eq!(expected_positional_args.len(), positional_args.len());
@@ -311,6 +580,22 @@ mod tests {
         assert_eq!(err_msg, "Missing value for optional argument --save-dir");
     }
 
+    #[test]
+    fn get_optional_args_flag_without_value() {
+        let args = vec![
+            "--auto-add-players".to_string(),
+            "--save-dir".to_string(),
+            "path/to/dir".to_string(),
+        ];
+        let optional_args = get_optional_args(&args).unwrap();
+
+        assert_eq!(
+            optional_args.get(AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT),
+            Some(&String::new())
+        );
+        assert_eq!(optional_args.get("--save-dir").unwrap(), "path/to/dir");
+    }
+
     #[test]
     fn get_optional_args_standard_behaviour() {
         let args = vec!["--save-dir".to_string(), "path/to/dir".to_string()];
@@ -3,7 +3,9 @@ use crate::commands::models::player;
 use crate::commands::models::player::FILE_NAME_DATA;
 use crate::commands::utils::{
     file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
+    transaction::Transaction,
 };
+use chrono::Utc;
 use std::collections::HashMap;
 
 const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
@@ -19,7 +21,7 @@ impl AddPlayer {
         args: &[String],
         optional_args: &HashMap<String, String>,
     ) -> Result<AddPlayer, String> {
-        for (key, _) in optional_args {
+        for key in optional_args.keys() {
             if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
                 return Err(format!("Unknown optional command for add-player {}.", key));
             }
@@ -29,7 +31,8 @@ impl AddPlayer {
             return Err("Invalid number of arguments for add-player.".to_string());
         }
 
-        let new_player = player::Player::new(args[0].clone());
+        let mut new_player = player::Player::new(args[0].clone());
+        new_player.set_joined(Utc::now().date_naive());
 
         Ok(AddPlayer {
             player: new_player,
@@ -40,6 +43,7 @@ impl AddPlayer {
     pub fn run(&self) -> Result<(), String> {
         let players_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
 
+        let transaction = Transaction::begin(players_file_path)?;
         let file_options = FileWrapperOptions::default();
         let mut file =
             FileWrapper::from_string(&[FILE_NAME_DATA], players_file_path, file_options)?;
@@ -55,6 +59,10 @@ impl AddPlayer {
             players.add_player(self.player.clone())?;
             file.save(&players)?;
         }
+        transaction.commit(
+            "add-player",
+            format!("Added player {}.", self.player.get_name()),
+        )?;
         println!("Added player {}.", self.player.get_name());
 
         Ok(())
@@ -1,17 +1,32 @@
 use crate::commands::models::game;
 use crate::commands::models::player;
-use crate::commands::utils::{
-    file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT, DURATION_OPTIONAL_ARGUMENT, LOCATION_OPTIONAL_ARGUMENT,
+    NOTES_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT, SESSION_OPTIONAL_ARGUMENT,
+    START_OPTIONAL_ARGUMENT, TAGS_OPTIONAL_ARGUMENT, TIME_OPTIONAL_ARGUMENT,
 };
-use crate::commands::{SAVE_DIR_OPTIONAL_ARGUMENT, TIME_OPTIONAL_ARGUMENT};
 use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
 
-const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] = [SAVE_DIR_OPTIONAL_ARGUMENT, TIME_OPTIONAL_ARGUMENT];
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 9] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    TIME_OPTIONAL_ARGUMENT,
+    START_OPTIONAL_ARGUMENT,
+    DURATION_OPTIONAL_ARGUMENT,
+    LOCATION_OPTIONAL_ARGUMENT,
+    NOTES_OPTIONAL_ARGUMENT,
+    TAGS_OPTIONAL_ARGUMENT,
+    SESSION_OPTIONAL_ARGUMENT,
+    AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT,
+];
 pub const GAMES_FOLER: &str = "games";
 
 #[derive(Debug)]
 pub struct AddScore {
     game: game::Game,
+    /// Records the players that do not exist as guests instead of failing.
+    auto_add_players: bool,
     optional_args: HashMap<String, String>,
 }
 
@@ -20,22 +35,46 @@ impl AddScore {
         args: &[String],
         optional_args: &HashMap<String, String>,
     ) -> Result<AddScore, String> {
-        for (key, _) in optional_args {
+        for key in optional_args.keys() {
             if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
                 return Err(format!("Unknown optional command for add-score {}.", key));
             }
         }
 
-        let new_game = game::Game::build(
+        let details = game::MatchDetails::build(
+            optional_args
+                .get(START_OPTIONAL_ARGUMENT)
+                .map(|s| s.as_str()),
+            optional_args
+                .get(DURATION_OPTIONAL_ARGUMENT)
+                .map(|d| game::parse_duration(d))
+                .transpose()?,
+            optional_args.get(LOCATION_OPTIONAL_ARGUMENT).cloned(),
+            optional_args.get(NOTES_OPTIONAL_ARGUMENT).cloned(),
+            optional_args
+                .get(TAGS_OPTIONAL_ARGUMENT)
+                .map(|s| s.as_str()),
+        )?;
+        // Without --time, the match is dated on the day it started.
+        let start_date = details
+            .get_start()
+            .map(|start| start.date_naive().to_string());
+        let mut new_game = game::Game::build(
             args[0].clone(),
             args[1..].to_vec(),
             optional_args
                 .get(TIME_OPTIONAL_ARGUMENT)
-                .map(|s| s.as_str()),
+                .map(|s| s.as_str())
+                .or(start_date.as_deref()),
         )?;
+        new_game.set_details(details)?;
+        if let Some(session) = optional_args.get(SESSION_OPTIONAL_ARGUMENT) {
+            new_game.set_session(session.clone());
+        }
 
         Ok(AddScore {
             game: new_game,
+            auto_add_players: optional_args.contains_key(AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT),
             optional_args: optional_args.to_owned(),
         })
     }
@@ -43,57 +82,138 @@ impl AddScore {
     pub fn run(&self) -> Result<(), String> {
         let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
 
-        self.check_players_existance()?;
-
-        let file_name = format!("{}.json", self.game.get_name());
+        let mut players = data::load_players(data_file_path)?;
+        let (mut new_game, guests) = self.resolve_players(&players)?;
+        let catalog = data::load_catalog(data_file_path)?;
+        match catalog.resolve(new_game.get_name()) {
+            Some(entry) => {
+                entry.check_player_count(new_game.get_scores().len())?;
+                entry.check_breakdown(&new_game)?;
+                new_game.set_name(entry.get_name().to_string());
+            }
+            // Score categories come from the catalog.
+            None if !new_game.get_breakdown().is_empty() => {
+                return Err(format!(
+                    "Game {} not found in the catalog.",
+                    new_game.get_name()
+                ));
+            }
+            None => {}
+        }
 
-        let file_options = FileWrapperOptions::default();
-        let mut file =
-            FileWrapper::from_string(&[GAMES_FOLER, &file_name], data_file_path, file_options)?;
+        let sessions = match new_game.get_session() {
+            Some(id) => {
+                // Whoever plays a match of the session attended it.
+                let mut sessions = data::load_sessions(data_file_path)?;
+                let session = sessions.get_mut(id)?;
+                session.check_open()?;
+                session.add_attendees(new_game.get_scores().keys().cloned());
+                Some(sessions)
+            }
+            None => None,
+        };
 
-        if file.is_empty()? {
-            let games = game::Games::from_games(HashMap::from([(
-                self.game.get_id().clone(),
-                self.game.clone(),
-            )]));
-            file.save(&games)?;
-        } else {
-            let mut games: game::Games = file.load()?;
-            games.add_game(self.game.clone());
-            file.save(&games)?;
+        let transaction = Transaction::begin(data_file_path)?;
+        // Guests are registered on their first match.
+        let mut registered = false;
+        for guest in &guests {
+            if !players.get_players().contains_key(guest) {
+                let mut player = player::Player::new(guest.clone());
+                player.set_joined(*new_game.get_datetime());
+                players.add_player(player)?;
+                registered = true;
+            }
+        }
+        if registered {
+            data::save_players(data_file_path, &players)?;
+        }
+        if let Some(sessions) = &sessions {
+            data::save_sessions(data_file_path, sessions)?;
+        }
+        let mut games = data::load_games(data_file_path, new_game.get_name())?;
+        games.add_game(new_game.clone());
+        data::save_games(data_file_path, new_game.get_name(), &games)?;
+        transaction.commit(
+            "add-score",
+            format!(
+                "Added game of {} with id {}.",
+                new_game.get_name(),
+                new_game.get_id()
+            ),
+        )?;
+        for guest in &guests {
+            println!("Recorded {} as a guest.", guest);
         }
         println!(
             "Added game of {} with id: {}.",
-            self.game.get_name(),
-            self.game.get_id()
+            new_game.get_name(),
+            new_game.get_id()
         );
 
         Ok(())
     }
 
-    fn check_players_existance(&self) -> Result<(), String> {
-        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
-
-        let file_options = FileWrapperOptions::default();
-        let mut player_file =
-            FileWrapper::from_string(&[player::FILE_NAME_DATA], data_file_path, file_options)?;
+    /// The match with every player entered by name or alias recorded under
+    /// the canonical player name, and the guests recorded for the names that
+    /// match no player. Missing players are recorded as guests with
+    /// `--auto-add-players` or, in a terminal, when the user agrees.
+    fn resolve_players(
+        &self,
+        players: &player::Players,
+    ) -> Result<(game::Game, Vec<String>), String> {
+        // Guests are recorded as entered, without a player.
+        let mut entered = self
+            .game
+            .get_scores()
+            .keys()
+            .filter(|name| !player::is_guest(name))
+            .collect::<Vec<_>>();
+        entered.sort();
 
-        if player_file.is_empty()? {
+        let interactive = !self.auto_add_players && io::stdin().is_terminal();
+        if players.get_players().is_empty()
+            && !entered.is_empty()
+            && !self.auto_add_players
+            && !interactive
+        {
             return Err("No Players' data found.".to_string());
-        } else {
-            let players: player::Players = player_file.load()?;
-            for (player, _) in self.game.get_scores() {
-                let player_to_check = player::Player::new(player.to_string());
-                if !players.exists(player_to_check) {
-                    return Err(format!("Player {} does not exist.", player));
-                }
+        }
+
+        let mut game = self.game.clone();
+        let mut guests = Vec::new();
+        for name in entered {
+            if let Some(player) = players.resolve(name) {
+                game.rename_player(name, player.get_name())?;
+                continue;
+            }
+
+            let message = players.unknown_player(name, format!("Player {} does not exist.", name));
+            let add = self.auto_add_players
+                || (interactive && confirm(&format!("{} Add {} as a guest?", message, name))?);
+            if !add {
+                return Err(message);
             }
+            let guest = format!("{}{}", player::GUEST_PREFIX, name);
+            game.rename_player(name, &guest)?;
+            guests.push(guest);
         }
 
-        Ok(())
+        Ok((game, guests))
     }
 }
 
+/// Asks a yes or no question on the terminal. Anything but yes is a no.
+fn confirm(question: &str) -> Result<bool, String> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| e.to_string())?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +239,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_dates_match_on_start() {
+        let args = vec!["Catan".to_string(), "player1-name::10".to_string()];
+        let optional_args = HashMap::from([
+            (
+                START_OPTIONAL_ARGUMENT.to_string(),
+                "2025-07-25T21:00:00+02:00".to_string(),
+            ),
+            (DURATION_OPTIONAL_ARGUMENT.to_string(), "1h30m".to_string()),
+            (TAGS_OPTIONAL_ARGUMENT.to_string(), "evening".to_string()),
+        ]);
+
+        let add_score = AddScore::create(&args, &optional_args).unwrap();
+        assert_eq!(add_score.game.get_datetime().to_string(), "2025-07-25");
+    }
+
+    #[test]
+    fn test_create_with_auto_add_players() {
+        let args = vec!["Catan".to_string(), "player1-name::10".to_string()];
+        let optional_args = HashMap::from([(
+            AUTO_ADD_PLAYERS_OPTIONAL_ARGUMENT.to_string(),
+            String::new(),
+        )]);
+
+        let add_score = AddScore::create(&args, &optional_args).unwrap();
+        assert!(add_score.auto_add_players);
+        assert!(
+            !AddScore::create(&args, &HashMap::new())
+                .unwrap()
+                .auto_add_players
+        );
+    }
+
+    #[test]
+    fn test_create_with_invalid_duration() {
+        let args = vec!["Catan".to_string(), "player1-name::10".to_string()];
+        let optional_args =
+            HashMap::from([(DURATION_OPTIONAL_ARGUMENT.to_string(), "long".to_string())]);
+
+        let result = AddScore::create(&args, &optional_args);
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid duration long. Use minutes, or hours and minutes like 1h30m."
+        );
+    }
+
     #[test]
     fn test_create_with_unknown_optional_arg() {
         let args = vec!["Catan".to_string(), "player1-name::10".to_string()];
@@ -0,0 +1,136 @@
+use crate::commands::models::prediction::{self, MAX_BALANCED_PLAYERS};
+use crate::commands::models::rating;
+use crate::commands::predict::{check_duplicates, resolve_lineup};
+use crate::commands::ratings::select_algorithm;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    ALGORITHM_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT, GAME_OPTIONAL_ARGUMENT,
+    SAVE_DIR_OPTIONAL_ARGUMENT, TEAMS_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 5] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    TEAMS_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+const DEFAULT_TEAMS: usize = 2;
+
+#[derive(Debug)]
+pub struct Balance {
+    players: Vec<String>,
+    teams: usize,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl Balance {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Balance, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for balance {}.", key));
+            }
+        }
+
+        let teams = match optional_args.get(TEAMS_OPTIONAL_ARGUMENT) {
+            Some(teams) => teams
+                .parse()
+                .map_err(|_| format!("Invalid number of teams {}.", teams))?,
+            None => DEFAULT_TEAMS,
+        };
+        if teams < 2 {
+            return Err("At least 2 teams are needed.".to_string());
+        }
+        if args.len() < teams {
+            return Err(format!("At least {} players are needed.", teams));
+        }
+        if args.len() > MAX_BALANCED_PLAYERS {
+            return Err(format!(
+                "At most {} players can be balanced.",
+                MAX_BALANCED_PLAYERS
+            ));
+        }
+        check_duplicates(args)?;
+
+        Ok(Balance {
+            players: args.to_vec(),
+            teams,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let algorithm = select_algorithm(&self.optional_args)?;
+        let players = resolve_lineup(&data::load_players(data_file_path)?, &self.players)?;
+        let games = match self.optional_args.get(GAME_OPTIONAL_ARGUMENT) {
+            Some(game_name) => data::load_games(data_file_path, game_name)?,
+            None => data::load_all_games(data_file_path)?,
+        };
+        let ratings = rating::rate(&games.finished().order_by_date(), algorithm);
+        let players = players
+            .iter()
+            .map(|player| {
+                let rating = ratings
+                    .get(player)
+                    .copied()
+                    .unwrap_or_else(|| algorithm.initial_rating());
+                (player.clone(), rating.get_rating())
+            })
+            .collect::<Vec<_>>();
+        let teams = prediction::balance_teams(&players, self.teams);
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&teams)?,
+            OutputFormat::Table => {
+                println!("{}", Table::new(prediction::to_team_rows(&teams)));
+                println!("Strength gap: {:.1}", prediction::strength_gap(&teams));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_players(count: usize) -> Vec<String> {
+        (1..=count).map(|i| format!("player{}", i)).collect()
+    }
+
+    #[test]
+    fn test_create_valid_input() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(TEAMS_OPTIONAL_ARGUMENT.to_string(), "3".to_string());
+
+        let balance = Balance::create(&create_players(6), &optional_args).unwrap();
+        assert_eq!(balance.teams, 3);
+        assert_eq!(balance.players.len(), 6);
+    }
+
+    #[test]
+    fn test_create_not_enough_players() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(TEAMS_OPTIONAL_ARGUMENT.to_string(), "3".to_string());
+
+        let result = Balance::create(&create_players(2), &optional_args);
+        assert_eq!(result.unwrap_err(), "At least 3 players are needed.");
+    }
+
+    #[test]
+    fn test_create_too_many_players() {
+        let result = Balance::create(&create_players(17), &HashMap::new());
+        assert_eq!(result.unwrap_err(), "At most 16 players can be balanced.");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::commands::models::breakdown::CategoryAnalysis;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{FORMAT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] =
+    [SAVE_DIR_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct Breakdown {
+    game_name: String,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl Breakdown {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Breakdown, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for breakdown {}.", key));
+            }
+        }
+
+        if args.len() != 1 {
+            return Err("Invalid number of arguments for breakdown.".to_string());
+        }
+
+        Ok(Breakdown {
+            game_name: args[0].clone(),
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let catalog = data::load_catalog(data_file_path)?;
+        let entry = catalog.resolve(&self.game_name);
+        let game_name = entry.map_or(self.game_name.as_str(), |entry| entry.get_name());
+        let categories = entry.map_or(&[][..], |entry| entry.get_score_categories());
+
+        let games = data::load_games(data_file_path, game_name)?;
+        let analysis =
+            CategoryAnalysis::compute(game_name, &games.finished().order_by_date(), categories);
+        if analysis.is_empty() {
+            return Err(format!("No score breakdowns found for {}.", game_name));
+        }
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&analysis)?,
+            OutputFormat::Table => println!("{}", Table::new(analysis.to_profile_rows())),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["7 Wonders".to_string()];
+        let optional_args =
+            HashMap::from([(FORMAT_OPTIONAL_ARGUMENT.to_string(), "json".to_string())]);
+
+        let breakdown = Breakdown::create(&args, &optional_args).unwrap();
+        assert_eq!(breakdown.game_name, "7 Wonders");
+        assert_eq!(breakdown.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_arguments() {
+        let result = Breakdown::create(&[], &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for breakdown."
+        );
+    }
+}
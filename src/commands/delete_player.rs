@@ -2,6 +2,7 @@ use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
 use crate::commands::models::player;
 use crate::commands::utils::{
     file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
+    transaction::Transaction,
 };
 use std::collections::HashMap;
 
@@ -20,7 +21,7 @@ impl DeletePlayer {
         args: &[String],
         optional_args: &HashMap<String, String>,
     ) -> Result<DeletePlayer, String> {
-        for (key, _) in optional_args {
+        for key in optional_args.keys() {
             if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
                 return Err(format!(
                     "Unknown optional command for delete-player {}.",
@@ -44,6 +45,7 @@ impl DeletePlayer {
     pub fn run(&self) -> Result<(), String> {
         let players_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
 
+        let transaction = Transaction::begin(players_file_path)?;
         let file_options = FileWrapperOptions::default();
         let mut file =
             FileWrapper::from_string(&[FILE_NAME_DATA], players_file_path, file_options)?;
@@ -55,6 +57,10 @@ impl DeletePlayer {
             players.remove_player(self.player.clone())?;
             file.save(&players)?;
         }
+        transaction.commit(
+            "delete-player",
+            format!("Deleted player {}.", self.player.get_name()),
+        )?;
         println!("Deleted player: {}.", self.player.get_name());
         Ok(())
     }
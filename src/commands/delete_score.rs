@@ -1,16 +1,10 @@
 use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
-use crate::commands::models::game;
-use crate::commands::utils::utils::create_path;
-use crate::commands::utils::{
-    file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
-};
+use crate::commands::utils::{data, transaction::Transaction};
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::{fs, io};
 use uuid::Uuid;
 
 const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
-const GAMES_FOLER: &str = "games";
 
 #[derive(Debug)]
 pub struct DeleteScore {
@@ -23,7 +17,7 @@ impl DeleteScore {
         args: &[String],
         optional_args: &HashMap<String, String>,
     ) -> Result<DeleteScore, String> {
-        for (key, _) in optional_args {
+        for key in optional_args.keys() {
             if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
                 return Err(format!(
                     "Unknown optional command for delete-score {}.",
@@ -45,28 +39,28 @@ impl DeleteScore {
     pub fn run(&self) -> Result<(), String> {
         let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
 
-        let file_options = FileWrapperOptions::default();
-        let game_dir = create_path(&[GAMES_FOLER], data_file_path)?;
-
-        let game_files = fs::read_dir(&game_dir)
-            .map_err(|_| "An error occurred while accessing the data.")?
-            .map(|res| res.map(|e| e.path()))
-            .collect::<Result<Vec<_>, io::Error>>()
-            .map_err(|_| "An error occurred whil accessing the data.")?;
-
-        for game_file in game_files {
-            let mut file = FileWrapper::from_path(game_file, file_options.clone())?;
-            let mut games: game::Games = file.load()?;
-            let uuid = Uuid::from_str(&self.game_id)
-                .map_err(|_| format!("Impossible to decode id {}.", self.game_id))?;
-            if let Ok(game) = games.delete(uuid) {
-                file.save(&games)?;
-                println!("Removed game with id {}.", game.get_id());
-                return Ok(());
-            }
-        }
-
-        Err(format!("Game with id {} not found.", self.game_id))
+        let uuid = Uuid::from_str(&self.game_id)
+            .map_err(|_| format!("Impossible to decode id {}.", self.game_id))?;
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let game_name = match data::load_all_games(data_file_path)?.get_games().get(&uuid) {
+            Some(game) => game.get_name().to_string(),
+            None => return Err(format!("Game with id {} not found.", self.game_id)),
+        };
+        let mut games = data::load_games(data_file_path, &game_name)?;
+        let game = games.delete(uuid)?;
+        data::save_games(data_file_path, &game_name, &games)?;
+        transaction.commit(
+            "delete-score",
+            format!(
+                "Removed game of {} with id {}.",
+                game.get_name(),
+                game.get_id()
+            ),
+        )?;
+        println!("Removed game with id {}.", game.get_id());
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,109 @@
+use crate::commands::models::bundle::Bundle;
+use crate::commands::utils::data;
+use crate::commands::{OUTPUT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT};
+use std::collections::HashMap;
+use std::fs;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] =
+    [SAVE_DIR_OPTIONAL_ARGUMENT, OUTPUT_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct Export {
+    output: String,
+    optional_args: HashMap<String, String>,
+}
+
+impl Export {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Export, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for export {}.", key));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for export.".to_string());
+        }
+
+        let output = optional_args
+            .get(OUTPUT_OPTIONAL_ARGUMENT)
+            .ok_or("Missing output file for export. Use --output <file>.".to_string())?;
+
+        Ok(Export {
+            output: output.clone(),
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let players = data::load_players(data_file_path)?;
+        let games = data::load_all_games(data_file_path)?;
+        let mut bundle = Bundle::new(players, games);
+        bundle.catalog = data::load_catalog(data_file_path)?;
+        bundle.seasons = data::load_seasons(data_file_path)?;
+        bundle.sessions = data::load_sessions(data_file_path)?;
+        bundle.tournaments = data::load_tournaments(data_file_path)?;
+        bundle.game_index = data::load_game_index(data_file_path)?;
+        bundle.config = data::load_config(data_file_path)?;
+
+        let bundle_serialized = serde_json::to_string_pretty(&bundle)
+            .map_err(|_| "Error serializing the data bundle.".to_string())?;
+        fs::write(&self.output, bundle_serialized)
+            .map_err(|_| format!("Error writing file: {}", self.output))?;
+
+        println!(
+            "Exported {} players and {} games to {}.",
+            bundle.players.get_players().len(),
+            bundle.games.get_games().len(),
+            self.output
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(
+            OUTPUT_OPTIONAL_ARGUMENT.to_string(),
+            "backup.json".to_string(),
+        );
+
+        let result = Export::create(&[], &optional_args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().output, "backup.json");
+    }
+
+    #[test]
+    fn test_create_missing_output() {
+        let result = Export::create(&[], &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Missing output file for export. Use --output <file>."
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_optional_argument() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert("--unknown".to_string(), "value".to_string());
+
+        let result = Export::create(&[], &optional_args);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown optional command for export --unknown."
+        );
+    }
+}
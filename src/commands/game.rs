@@ -0,0 +1,247 @@
+use crate::commands::models::catalog::{Catalog, CatalogEntry};
+use crate::commands::models::game::parse_duration;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    CATEGORIES_OPTIONAL_ARGUMENT, CATEGORY_OPTIONAL_ARGUMENT, DURATION_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT, MAX_PLAYERS_OPTIONAL_ARGUMENT, MIN_PLAYERS_OPTIONAL_ARGUMENT,
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 7] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    MIN_PLAYERS_OPTIONAL_ARGUMENT,
+    MAX_PLAYERS_OPTIONAL_ARGUMENT,
+    DURATION_OPTIONAL_ARGUMENT,
+    CATEGORY_OPTIONAL_ARGUMENT,
+    CATEGORIES_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum GameAction {
+    Add(CatalogEntry),
+    List,
+    Show(String),
+    Rename(String, String),
+    Alias(String, String),
+    Categories(String, Vec<String>),
+}
+
+#[derive(Debug)]
+pub struct GameCommand {
+    action: GameAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+fn parse_number<T: FromStr>(
+    optional_args: &HashMap<String, String>,
+    key: &str,
+) -> Result<Option<T>, String> {
+    optional_args
+        .get(key)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("Invalid value {} for {}.", value, key))
+        })
+        .transpose()
+}
+
+impl GameCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<GameCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for game {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("add") if args.len() == 2 => {
+                let mut entry = CatalogEntry::build(
+                    &args[1],
+                    parse_number(optional_args, MIN_PLAYERS_OPTIONAL_ARGUMENT)?,
+                    parse_number(optional_args, MAX_PLAYERS_OPTIONAL_ARGUMENT)?,
+                    optional_args
+                        .get(DURATION_OPTIONAL_ARGUMENT)
+                        .map(|d| parse_duration(d))
+                        .transpose()?,
+                    optional_args.get(CATEGORY_OPTIONAL_ARGUMENT).cloned(),
+                )?;
+                if let Some(categories) = optional_args.get(CATEGORIES_OPTIONAL_ARGUMENT) {
+                    entry.set_score_categories(
+                        categories
+                            .split(',')
+                            .map(|c| c.trim().to_string())
+                            .collect(),
+                    )?;
+                }
+                GameAction::Add(entry)
+            }
+            Some("list") if args.len() == 1 => GameAction::List,
+            Some("show") if args.len() == 2 => GameAction::Show(args[1].clone()),
+            Some("rename") if args.len() == 3 => {
+                GameAction::Rename(args[1].clone(), args[2].clone())
+            }
+            Some("alias") if args.len() == 3 => GameAction::Alias(args[1].clone(), args[2].clone()),
+            Some("categories") if args.len() >= 3 => GameAction::Categories(
+                args[1].clone(),
+                args[2..].iter().map(|c| c.trim().to_string()).collect(),
+            ),
+            _ => {
+                return Err(
+                    "Invalid arguments for game. Use game add <name>, game list, game show <name>, game rename <name> <new-name>, game alias <name> <alias> or game categories <name> <categories>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(GameCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            GameAction::Add(entry) => self.update_catalog(
+                |catalog| catalog.add_game(entry.clone()),
+                format!("Added game {} to the catalog.", entry.get_name()),
+            )?,
+            GameAction::List => {
+                let catalog = data::load_catalog(data_file_path)?;
+                match self.format {
+                    OutputFormat::Json => output::print_json(&catalog)?,
+                    OutputFormat::Table => println!("{}", Table::new(catalog.to_catalog_rows())),
+                }
+            }
+            GameAction::Show(name) => {
+                let catalog = data::load_catalog(data_file_path)?;
+                let entry = catalog.get(name)?;
+                match self.format {
+                    OutputFormat::Json => output::print_json(entry)?,
+                    OutputFormat::Table => println!("{}", Table::new([entry.to_row()])),
+                }
+            }
+            GameAction::Rename(name, new_name) => self.update_catalog(
+                |catalog| catalog.rename(name, new_name),
+                format!("Renamed game {} to {}.", name, new_name.trim()),
+            )?,
+            GameAction::Alias(name, alias) => self.update_catalog(
+                |catalog| catalog.add_alias(name, alias),
+                format!("Added alias {} to game {}.", alias.trim(), name),
+            )?,
+            GameAction::Categories(name, categories) => self.update_catalog(
+                |catalog| catalog.set_score_categories(name, categories.clone()),
+                format!(
+                    "Set the score categories of {} to {}.",
+                    name,
+                    categories.join(", ")
+                ),
+            )?,
+        }
+
+        Ok(())
+    }
+
+    fn update_catalog(
+        &self,
+        change: impl FnOnce(&mut Catalog) -> Result<(), String>,
+        message: String,
+    ) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let mut catalog = data::load_catalog(data_file_path)?;
+        change(&mut catalog)?;
+        data::save_catalog(data_file_path, &catalog)?;
+        transaction.commit("game", message.clone())?;
+        println!("{}", message);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_add() {
+        let args = vec!["add".to_string(), "Catan".to_string()];
+        let optional_args = HashMap::from([
+            (MIN_PLAYERS_OPTIONAL_ARGUMENT.to_string(), "3".to_string()),
+            (MAX_PLAYERS_OPTIONAL_ARGUMENT.to_string(), "4".to_string()),
+            (DURATION_OPTIONAL_ARGUMENT.to_string(), "90".to_string()),
+        ]);
+
+        let command = GameCommand::create(&args, &optional_args).unwrap();
+        assert_eq!(
+            command.action,
+            GameAction::Add(
+                CatalogEntry::build("Catan", Some(3), Some(4), Some(90), None).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_add_with_invalid_players() {
+        let args = vec!["add".to_string(), "Catan".to_string()];
+        let optional_args =
+            HashMap::from([(MIN_PLAYERS_OPTIONAL_ARGUMENT.to_string(), "few".to_string())]);
+
+        let result = GameCommand::create(&args, &optional_args);
+        assert_eq!(result.unwrap_err(), "Invalid value few for --min-players.");
+    }
+
+    #[test]
+    fn test_create_alias() {
+        let args = vec![
+            "alias".to_string(),
+            "Catan".to_string(),
+            "Settlers".to_string(),
+        ];
+
+        let command = GameCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(
+            command.action,
+            GameAction::Alias("Catan".to_string(), "Settlers".to_string())
+        );
+    }
+
+    #[test]
+    fn test_create_add_with_categories() {
+        let args = vec!["add".to_string(), "7 Wonders".to_string()];
+        let optional_args = HashMap::from([(
+            CATEGORIES_OPTIONAL_ARGUMENT.to_string(),
+            "military, science,coins".to_string(),
+        )]);
+
+        let command = GameCommand::create(&args, &optional_args).unwrap();
+        let GameAction::Add(entry) = command.action else {
+            panic!("Expected an add action.");
+        };
+        assert_eq!(
+            entry.get_score_categories(),
+            ["military", "science", "coins"]
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_action() {
+        let args = vec!["remove".to_string(), "Catan".to_string()];
+
+        let result = GameCommand::create(&args, &HashMap::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::utils::transaction;
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct History {
+    optional_args: HashMap<String, String>,
+}
+
+impl History {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<History, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for history {}.", key));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for history.".to_string());
+        }
+
+        Ok(History {
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let journal = transaction::load_journal(data_file_path)?;
+        let table = Table::new(journal.to_history_rows()).to_string();
+        println!("{}", table);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let result = History::create(&[], &HashMap::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_create_unknown_optional_argument() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert("--unknown".to_string(), "value".to_string());
+
+        let result = History::create(&[], &optional_args);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown optional command for history --unknown."
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::commands::models::game::{Game, Games};
+use crate::commands::models::player::{Player, Players};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{PLAYER_MAP_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::fs;
+
+mod bg_stats;
+mod bgg;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] =
+    [SAVE_DIR_OPTIONAL_ARGUMENT, PLAYER_MAP_OPTIONAL_ARGUMENT];
+
+/// A play read from an external export, before player names are reconciled.
+#[derive(Debug)]
+pub struct ImportedPlay {
+    pub game_name: String,
+    pub time: NaiveDate,
+    pub scores: Vec<(String, i64)>,
+    pub winners: Vec<String>,
+    /// How many times the play was played with the same result.
+    pub quantity: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum ImportFormat {
+    BgStats,
+    Bgg,
+}
+
+#[derive(Debug)]
+pub struct Import {
+    format: ImportFormat,
+    source: String,
+    optional_args: HashMap<String, String>,
+}
+
+impl Import {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Import, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for import {}.", key));
+            }
+        }
+
+        if args.len() != 2 {
+            return Err("Invalid number of arguments for import.".to_string());
+        }
+
+        let format = match args[0].as_str() {
+            "bgstats" => ImportFormat::BgStats,
+            "bgg" => ImportFormat::Bgg,
+            other => {
+                return Err(format!(
+                    "Unknown import format {}. Supported formats are bgstats and bgg.",
+                    other
+                ));
+            }
+        };
+
+        Ok(Import {
+            format,
+            source: args[1].clone(),
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let source_data = fs::read_to_string(&self.source)
+            .map_err(|_| format!("An error occurred reading file: {}", self.source))?;
+        let plays = match self.format {
+            ImportFormat::BgStats => bg_stats::parse(&source_data)?,
+            ImportFormat::Bgg => bgg::parse(&source_data)?,
+        };
+        let player_map = self.load_player_map()?;
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let mut players = data::load_players(data_file_path)?;
+        let mut games_by_name: HashMap<String, Games> = HashMap::new();
+        // Matches recorded before this import that no play has claimed yet,
+        // so identical plays within one file are not taken for duplicates.
+        let mut unclaimed_by_name: HashMap<String, Games> = HashMap::new();
+        let mut new_players = 0;
+        let mut imported = 0;
+        let mut duplicates = 0;
+        let mut skipped = 0;
+
+        for play in plays {
+            let (mut game, quantity) = match play.and_then(|play| {
+                let quantity = play.quantity;
+                map_play(play, &player_map).map(|game| (game, quantity))
+            }) {
+                Ok(mapped) => mapped,
+                Err(err) => {
+                    eprintln!("Skipped play: {}", err);
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            if let Err(err) = resolve_players(&mut game, &players) {
+                eprintln!("Skipped play: {}", err);
+                skipped += 1;
+                continue;
+            }
+
+            if !games_by_name.contains_key(game.get_name()) {
+                let games = data::load_games(data_file_path, game.get_name())?;
+                unclaimed_by_name.insert(
+                    game.get_name().to_string(),
+                    games.with_name(game.get_name()),
+                );
+                games_by_name.insert(game.get_name().to_string(), games);
+            }
+            let games = games_by_name.get_mut(game.get_name()).unwrap();
+            let unclaimed = unclaimed_by_name.get_mut(game.get_name()).unwrap();
+            let recorded = claim_matches(unclaimed, &game, quantity);
+            if recorded >= quantity {
+                duplicates += 1;
+                continue;
+            }
+
+            for player_name in game.get_scores().keys() {
+                let player = Player::new(player_name.to_string());
+                if !players.exists(player.clone()) {
+                    players.add_player(player)?;
+                    new_players += 1;
+                }
+            }
+            for _ in recorded + 1..quantity {
+                games.add_game(game.copy());
+            }
+            games.add_game(game);
+            imported += quantity - recorded;
+        }
+
+        data::save_players(data_file_path, &players)?;
+        for (game_name, games) in &games_by_name {
+            data::save_games(data_file_path, game_name, games)?;
+        }
+        transaction.commit(
+            "import",
+            format!("Imported {} matches from {}.", imported, self.source),
+        )?;
+
+        println!(
+            "Imported {} matches and {} new players from {}.",
+            imported, new_players, self.source
+        );
+        if duplicates > 0 {
+            println!("Skipped {} matches already recorded.", duplicates);
+        }
+        if skipped > 0 {
+            println!("Skipped {} invalid plays.", skipped);
+        }
+
+        Ok(())
+    }
+
+    fn load_player_map(&self) -> Result<HashMap<String, String>, String> {
+        match self.optional_args.get(PLAYER_MAP_OPTIONAL_ARGUMENT) {
+            Some(path) => {
+                let map_data = fs::read_to_string(path)
+                    .map_err(|_| format!("An error occurred reading file: {}", path))?;
+                serde_json::from_str(&map_data).map_err(|_| {
+                    format!(
+                        "Error parsing player map {}. Expected a JSON object of names.",
+                        path
+                    )
+                })
+            }
+            None => Ok(HashMap::new()),
+        }
+    }
+}
+
+/// Removes up to `limit` recorded matches equal to `game` from `unclaimed`
+/// and returns how many were removed.
+fn claim_matches(unclaimed: &mut Games, game: &Game, limit: usize) -> usize {
+    let ids: Vec<_> = unclaimed
+        .get_games()
+        .values()
+        .filter(|recorded| recorded.is_same_match(game))
+        .map(|recorded| *recorded.get_id())
+        .take(limit)
+        .collect();
+    for id in &ids {
+        let _ = unclaimed.delete(*id);
+    }
+    ids.len()
+}
+
+/// Reconciles the player names of an imported play through the player map.
+fn map_play(play: ImportedPlay, player_map: &HashMap<String, String>) -> Result<Game, String> {
+    let map_name = |name: &String| player_map.get(name).unwrap_or(name).to_string();
+
+    let mut scores = HashMap::new();
+    for (player, score) in &play.scores {
+        if scores.insert(map_name(player), *score).is_some() {
+            return Err(format!(
+                "Player {} appears twice in a play of {} on {}.",
+                map_name(player),
+                play.game_name,
+                play.time
+            ));
+        }
+    }
+    let winners = play.winners.iter().map(map_name).collect();
+
+    Ok(Game::new(play.game_name, scores, play.time, winners))
+}
+
+/// Records the players known under another case or by an alias under
+/// their player name.
+fn resolve_players(game: &mut Game, players: &Players) -> Result<(), String> {
+    let mut names = game.get_scores().keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        if let Some(player) = players.resolve(&name) {
+            let player_name = player.get_name().to_string();
+            game.rename_player(&name, &player_name)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["bgg".to_string(), "plays.xml".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(
+            PLAYER_MAP_OPTIONAL_ARGUMENT.to_string(),
+            "map.json".to_string(),
+        );
+
+        let result = Import::create(&args, &optional_args);
+        assert!(result.is_ok());
+
+        let import = result.unwrap();
+        assert_eq!(import.format, ImportFormat::Bgg);
+        assert_eq!(import.source, "plays.xml");
+    }
+
+    #[test]
+    fn test_create_unknown_format() {
+        let args = vec!["csv".to_string(), "plays.csv".to_string()];
+
+        let result = Import::create(&args, &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown import format csv. Supported formats are bgstats and bgg."
+        );
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_args() {
+        let args = vec!["bgstats".to_string()];
+
+        let result = Import::create(&args, &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for import."
+        );
+    }
+
+    #[test]
+    fn map_play_reconciles_names() {
+        let play = ImportedPlay {
+            game_name: "Catan".to_string(),
+            time: NaiveDate::from_ymd_opt(2025, 8, 7).unwrap(),
+            scores: vec![("Giovanni".to_string(), 10), ("Emma".to_string(), 8)],
+            winners: vec!["Giovanni".to_string()],
+            quantity: 1,
+        };
+        let player_map = HashMap::from([("Giovanni".to_string(), "giovi98".to_string())]);
+
+        let game = map_play(play, &player_map).unwrap();
+        assert_eq!(game.get_scores().get("giovi98"), Some(&10));
+        assert_eq!(game.get_scores().get("Emma"), Some(&8));
+        assert_eq!(
+            serde_json::to_value(&game).unwrap()["winners"],
+            serde_json::json!(["giovi98"])
+        );
+    }
+
+    #[test]
+    fn map_play_rejects_names_mapped_twice() {
+        let play = ImportedPlay {
+            game_name: "Catan".to_string(),
+            time: NaiveDate::from_ymd_opt(2025, 8, 7).unwrap(),
+            scores: vec![("Gio".to_string(), 10), ("Giovanni".to_string(), 8)],
+            winners: vec![],
+            quantity: 1,
+        };
+        let player_map = HashMap::from([
+            ("Gio".to_string(), "giovi98".to_string()),
+            ("Giovanni".to_string(), "giovi98".to_string()),
+        ]);
+
+        let result = map_play(play, &player_map);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Player giovi98 appears twice in a play of Catan on 2025-08-07."
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::commands::import::ImportedPlay;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct Backup {
+    #[serde(default)]
+    players: Vec<BgStatsPlayer>,
+    #[serde(default)]
+    games: Vec<BgStatsGame>,
+    #[serde(default)]
+    plays: Vec<BgStatsPlay>,
+}
+
+#[derive(Deserialize)]
+struct BgStatsPlayer {
+    id: u64,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct BgStatsGame {
+    id: u64,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BgStatsPlay {
+    game_ref_id: u64,
+    play_date: String,
+    #[serde(default)]
+    ignored: bool,
+    #[serde(default)]
+    player_scores: Vec<BgStatsPlayerScore>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BgStatsPlayerScore {
+    player_ref_id: u64,
+    #[serde(default)]
+    score: Value,
+    #[serde(default)]
+    winner: bool,
+}
+
+/// Parses a BG Stats JSON backup. Each play is parsed on its own so that a
+/// single malformed play does not prevent the rest of the backup from loading.
+pub fn parse(data: &str) -> Result<Vec<Result<ImportedPlay, String>>, String> {
+    let backup: Backup = serde_json::from_str(data)
+        .map_err(|_| "Error parsing BG Stats backup. Expected a JSON export.".to_string())?;
+
+    let players: HashMap<u64, &str> = backup
+        .players
+        .iter()
+        .map(|p| (p.id, p.name.as_str()))
+        .collect();
+    let games: HashMap<u64, &str> = backup
+        .games
+        .iter()
+        .map(|g| (g.id, g.name.as_str()))
+        .collect();
+
+    Ok(backup
+        .plays
+        .iter()
+        .filter(|play| !play.ignored)
+        .map(|play| parse_play(play, &players, &games))
+        .collect())
+}
+
+fn parse_play(
+    play: &BgStatsPlay,
+    players: &HashMap<u64, &str>,
+    games: &HashMap<u64, &str>,
+) -> Result<ImportedPlay, String> {
+    let game_name = games
+        .get(&play.game_ref_id)
+        .ok_or(format!("Unknown game reference {}.", play.game_ref_id))?;
+
+    let date = play.play_date.get(..10).unwrap_or(&play.play_date);
+    let time = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid play date {} for {}.", play.play_date, game_name))?;
+
+    let mut scores = Vec::new();
+    let mut winners = Vec::new();
+    for player_score in &play.player_scores {
+        let player = players
+            .get(&player_score.player_ref_id)
+            .ok_or(format!(
+                "Unknown player reference {}.",
+                player_score.player_ref_id
+            ))?
+            .to_string();
+        let score = parse_score(&player_score.score)
+            .map_err(|reason| format!("{} for {} in {}.", reason, player, game_name))?;
+        if player_score.winner {
+            winners.push(player.clone());
+        }
+        scores.push((player, score));
+    }
+
+    if scores.is_empty() {
+        return Err(format!(
+            "Play of {} on {} has no players.",
+            game_name, play.play_date
+        ));
+    }
+
+    Ok(ImportedPlay {
+        game_name: game_name.to_string(),
+        time,
+        scores,
+        winners,
+        quantity: 1,
+    })
+}
+
+/// Scores are whole numbers of points. BG Stats also allows fractional
+/// scores, which are reported rather than rounded.
+fn parse_score(score: &Value) -> Result<i64, String> {
+    let number = match score {
+        Value::Null => return Ok(0),
+        Value::String(text) if text.trim().is_empty() => return Ok(0),
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .filter(|number| number.is_finite())
+    .ok_or_else(|| format!("Invalid score {}", score))?;
+
+    if number.fract() != 0.0 {
+        Err(format!("Fractional score {} is not supported", number))
+    } else {
+        Ok(number as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BACKUP: &str = r#"{
+        "players": [{"id": 1, "name": "Giovanni"}, {"id": 2, "name": "Emma"}],
+        "games": [{"id": 7, "name": "Catan"}],
+        "plays": [
+            {
+                "gameRefId": 7,
+                "playDate": "2025-08-07 21:30:00",
+                "playerScores": [
+                    {"playerRefId": 1, "score": "10", "winner": true},
+                    {"playerRefId": 2, "score": 8, "winner": false}
+                ]
+            },
+            {
+                "gameRefId": 7,
+                "playDate": "2025-08-08 21:30:00",
+                "ignored": true,
+                "playerScores": [{"playerRefId": 1, "score": "3"}]
+            },
+            {
+                "gameRefId": 7,
+                "playDate": "2025-08-09 21:30:00",
+                "playerScores": [{"playerRefId": 1, "score": "-3"}]
+            },
+            {
+                "gameRefId": 7,
+                "playDate": "2025-08-10 21:30:00",
+                "playerScores": [{"playerRefId": 2, "score": 7.5}]
+            },
+            {
+                "gameRefId": 7,
+                "playDate": "2025-08-11 21:30:00",
+                "playerScores": [{"playerRefId": 2, "score": "many"}]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parse_valid_backup() {
+        let plays = parse(BACKUP).unwrap();
+        assert_eq!(plays.len(), 4);
+
+        let play = plays[0].as_ref().unwrap();
+        assert_eq!(play.game_name, "Catan");
+        assert_eq!(play.time, NaiveDate::from_ymd_opt(2025, 8, 7).unwrap());
+        assert_eq!(
+            play.scores,
+            vec![("Giovanni".to_string(), 10), ("Emma".to_string(), 8)]
+        );
+        assert_eq!(play.winners, vec!["Giovanni"]);
+    }
+
+    #[test]
+    fn parse_unsupported_scores_are_reported() {
+        let plays = parse(BACKUP).unwrap();
+        assert_eq!(
+            plays[1].as_ref().unwrap().scores,
+            vec![("Giovanni".to_string(), -3)]
+        );
+        assert_eq!(
+            plays[2].as_ref().unwrap_err(),
+            "Fractional score 7.5 is not supported for Emma in Catan."
+        );
+        assert_eq!(
+            plays[3].as_ref().unwrap_err(),
+            "Invalid score \"many\" for Emma in Catan."
+        );
+    }
+
+    #[test]
+    fn parse_invalid_json() {
+        let result = parse("<plays/>");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Error parsing BG Stats backup. Expected a JSON export."
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::commands::import::ImportedPlay;
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+
+/// Parses a BoardGameGeek plays XML export. Each play is parsed on its own so
+/// that a single malformed play does not prevent the rest of the file from loading.
+pub fn parse(data: &str) -> Result<Vec<Result<ImportedPlay, String>>, String> {
+    let document = Document::parse(data)
+        .map_err(|_| "Error parsing BoardGameGeek plays. Expected an XML export.".to_string())?;
+
+    let root = document.root_element();
+    if !root.has_tag_name("plays") {
+        return Err("Error parsing BoardGameGeek plays. Expected an XML export.".to_string());
+    }
+
+    Ok(root
+        .children()
+        .filter(|node| node.has_tag_name("play"))
+        .map(parse_play)
+        .collect())
+}
+
+fn parse_play(play: Node) -> Result<ImportedPlay, String> {
+    let play_id = play.attribute("id").unwrap_or("?");
+
+    let game_name = play
+        .children()
+        .find(|node| node.has_tag_name("item"))
+        .and_then(|item| item.attribute("name"))
+        .ok_or(format!("Play {} has no game.", play_id))?;
+
+    let date = play.attribute("date").unwrap_or_default();
+    let time = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid play date {} for {}.", date, game_name))?;
+
+    // BGG logs identical plays once with the number of times they were played.
+    let quantity = match play.attribute("quantity").map(str::trim) {
+        None | Some("") => 1,
+        Some(quantity) => quantity
+            .parse()
+            .ok()
+            .filter(|quantity| *quantity > 0)
+            .ok_or(format!(
+                "Invalid quantity {} for play {}.",
+                quantity, play_id
+            ))?,
+    };
+
+    let mut scores = Vec::new();
+    let mut winners = Vec::new();
+    let players = play
+        .children()
+        .filter(|node| node.has_tag_name("players"))
+        .flat_map(|node| node.children())
+        .filter(|node| node.has_tag_name("player"));
+    for player in players {
+        let name = [player.attribute("name"), player.attribute("username")]
+            .into_iter()
+            .flatten()
+            .find(|name| !name.trim().is_empty())
+            .ok_or(format!("Play {} has a player without a name.", play_id))?
+            .trim()
+            .to_string();
+        let score = match player.attribute("score").map(str::trim) {
+            None | Some("") => 0,
+            Some(score) => score
+                .parse()
+                .map_err(|_| format!("Invalid score for {} in {}.", name, game_name))?,
+        };
+        if player.attribute("win") == Some("1") {
+            winners.push(name.clone());
+        }
+        scores.push((name, score));
+    }
+
+    if scores.is_empty() {
+        return Err(format!("Play {} of {} has no players.", play_id, game_name));
+    }
+
+    Ok(ImportedPlay {
+        game_name: game_name.to_string(),
+        time,
+        scores,
+        winners,
+        quantity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <plays username="giovi98" userid="1" total="2" page="1">
+            <play id="101" date="2025-08-07" quantity="1" length="90" incomplete="0" location="Home">
+                <item name="Catan" objecttype="thing" objectid="13">
+                    <subtypes><subtype value="boardgame"/></subtypes>
+                </item>
+                <players>
+                    <player username="giovi98" userid="1" name="Giovanni" score="10" new="0" win="1"/>
+                    <player username="" userid="0" name="Emma" score="8" new="0" win="0"/>
+                </players>
+            </play>
+            <play id="102" date="2025-08-08" quantity="1">
+                <item name="Azul" objecttype="thing" objectid="230802"/>
+            </play>
+        </plays>"#;
+
+    #[test]
+    fn parse_valid_plays() {
+        let plays = parse(PLAYS).unwrap();
+        assert_eq!(plays.len(), 2);
+
+        let play = plays[0].as_ref().unwrap();
+        assert_eq!(play.game_name, "Catan");
+        assert_eq!(play.time, NaiveDate::from_ymd_opt(2025, 8, 7).unwrap());
+        assert_eq!(
+            play.scores,
+            vec![("Giovanni".to_string(), 10), ("Emma".to_string(), 8)]
+        );
+        assert_eq!(play.winners, vec!["Giovanni"]);
+        assert_eq!(play.quantity, 1);
+    }
+
+    #[test]
+    fn parse_play_quantity() {
+        let plays = parse(
+            r#"<plays>
+                <play id="103" date="2025-08-09" quantity="3">
+                    <item name="Azul"/>
+                    <players><player name="Emma" score="50"/></players>
+                </play>
+                <play id="104" date="2025-08-09" quantity="0">
+                    <item name="Azul"/>
+                    <players><player name="Emma" score="50"/></players>
+                </play>
+            </plays>"#,
+        )
+        .unwrap();
+
+        assert_eq!(plays[0].as_ref().unwrap().quantity, 3);
+        assert_eq!(
+            plays[1].as_ref().unwrap_err(),
+            "Invalid quantity 0 for play 104."
+        );
+    }
+
+    #[test]
+    fn parse_play_without_players_is_reported() {
+        let plays = parse(PLAYS).unwrap();
+        assert_eq!(
+            plays[1].as_ref().unwrap_err(),
+            "Play 102 of Azul has no players."
+        );
+    }
+
+    #[test]
+    fn parse_invalid_xml() {
+        let result = parse("{\"plays\": []}");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::models::bundle::Bundle;
+use crate::commands::models::config::CONFIG_FILE_NAME;
+use crate::commands::models::merge::{self, ConflictPolicy};
+use crate::commands::utils::{data, transaction::Transaction, utils::create_path};
+use std::collections::HashMap;
+use std::fs;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct ImportBundle {
+    source: String,
+    optional_args: HashMap<String, String>,
+}
+
+impl ImportBundle {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<ImportBundle, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!(
+                    "Unknown optional command for import-bundle {}.",
+                    key
+                ));
+            }
+        }
+
+        if args.len() != 1 {
+            return Err("Invalid number of arguments for import-bundle.".to_string());
+        }
+
+        Ok(ImportBundle {
+            source: args[0].clone(),
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let bundle_data = fs::read_to_string(&self.source)
+            .map_err(|_| format!("An error occurred reading file: {}", self.source))?;
+        let mut bundle: Bundle = serde_json::from_str(&bundle_data)
+            .map_err(|_| format!("Error deserializing file: {}", self.source))?;
+        bundle.check_version()?;
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let mut players = data::load_players(data_file_path)?;
+        let mut games = data::load_all_games(data_file_path)?;
+        let mut catalog = data::load_catalog(data_file_path)?;
+        let mut seasons = data::load_seasons(data_file_path)?;
+        let mut sessions = data::load_sessions(data_file_path)?;
+        let mut tournaments = data::load_tournaments(data_file_path)?;
+        let mut game_index = data::load_game_index(data_file_path)?;
+        for (name, our_name) in merge::player_renames(&players, &bundle.players) {
+            bundle.sessions.rename_player(&name, &our_name);
+            bundle.tournaments.rename_player(&name, &our_name);
+        }
+        let mut report = merge::merge(
+            &mut players,
+            &mut games,
+            &bundle.players,
+            &bundle.games,
+            ConflictPolicy::Report,
+        )?;
+        // The configuration is only restored into a save dir without one,
+        // where the files written below become the first events if the
+        // bundle uses the events storage mode.
+        if !create_path(&[CONFIG_FILE_NAME], data_file_path)?.exists() {
+            data::save_config(data_file_path, &bundle.config)?;
+        }
+        if merge::merge_records(&mut catalog, &bundle.catalog, &mut report) {
+            data::save_catalog(data_file_path, &catalog)?;
+        }
+        if merge::merge_records(&mut seasons, &bundle.seasons, &mut report) {
+            data::save_seasons(data_file_path, &seasons)?;
+        }
+        if merge::merge_records(&mut sessions, &bundle.sessions, &mut report) {
+            data::save_sessions(data_file_path, &sessions)?;
+        }
+        if merge::merge_records(&mut tournaments, &bundle.tournaments, &mut report) {
+            data::save_tournaments(data_file_path, &tournaments)?;
+        }
+        // Their file names are kept for the games we have no file for yet.
+        if game_index.adopt(&bundle.game_index) {
+            data::save_game_index(data_file_path, &game_index)?;
+        }
+        data::save_players(data_file_path, &players)?;
+        data::save_all_games(data_file_path, &games)?;
+        transaction.commit("import-bundle", format!("Imported bundle {}.", self.source))?;
+
+        println!(
+            "Imported {} players, {} games and {} other records from {}.",
+            report.added_players, report.added_games, report.added_records, self.source
+        );
+        if report.unchanged_games + report.duplicate_matches > 0 {
+            println!(
+                "Skipped {} games already present.",
+                report.unchanged_games + report.duplicate_matches
+            );
+        }
+        let conflict_count =
+            report.existing_players.len() + report.existing_records.len() + report.conflicts.len();
+        if conflict_count > 0 {
+            println!("{} conflicts:", conflict_count);
+            for player_name in &report.existing_players {
+                println!(
+                    "  Player {} already exists, kept the existing one.",
+                    player_name
+                );
+            }
+            for reason in &report.existing_records {
+                println!("  {} Kept the existing one.", reason);
+            }
+            for conflict in &report.conflicts {
+                println!(
+                    "  Game {} differs from the existing one, kept the existing one.",
+                    conflict.id
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["backup.json".to_string()];
+
+        let result = ImportBundle::create(&args, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().source, "backup.json");
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_args() {
+        let result = ImportBundle::create(&[], &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for import-bundle."
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_optional_argument() {
+        let args = vec!["backup.json".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert("--unknown".to_string(), "value".to_string());
+
+        let result = ImportBundle::create(&args, &optional_args);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown optional command for import-bundle --unknown."
+        );
+    }
+}
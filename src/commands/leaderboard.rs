@@ -0,0 +1,125 @@
+use crate::commands::list_games::parse_as_of;
+use crate::commands::models::leaderboard;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    AS_OF_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT, INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    MIN_PLAYS_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 5] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    MIN_PLAYS_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    AS_OF_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct Leaderboard {
+    game_name: String,
+    min_plays: usize,
+    include_guests: bool,
+    as_of: Option<NaiveDate>,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl Leaderboard {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Leaderboard, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for leaderboard {}.", key));
+            }
+        }
+
+        if args.len() != 1 {
+            return Err("Invalid number of arguments for leaderboard.".to_string());
+        }
+
+        let min_plays = match optional_args.get(MIN_PLAYS_OPTIONAL_ARGUMENT) {
+            Some(min_plays) => min_plays
+                .parse()
+                .map_err(|_| format!("Invalid minimum number of plays {}.", min_plays))?,
+            None => 1,
+        };
+
+        Ok(Leaderboard {
+            game_name: args[0].clone(),
+            min_plays,
+            include_guests: optional_args.contains_key(INCLUDE_GUESTS_OPTIONAL_ARGUMENT),
+            as_of: parse_as_of(optional_args)?,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let catalog = data::load_catalog(data_file_path)?;
+        let game_name = catalog
+            .resolve(&self.game_name)
+            .map_or(self.game_name.as_str(), |entry| entry.get_name());
+        let games = match self.as_of {
+            Some(as_of) => data::load_games_as_of(data_file_path, as_of)?.with_name(game_name),
+            None => data::load_games(data_file_path, game_name)?,
+        };
+        let mut leaderboard = leaderboard::Leaderboard::compute(
+            game_name,
+            &games.finished().order_by_date(),
+            self.min_plays,
+        );
+        if leaderboard.get_matches() == 0 {
+            return Err(format!("No matches found for {}.", game_name));
+        }
+        if !self.include_guests {
+            leaderboard.exclude_guests();
+        }
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&leaderboard)?,
+            OutputFormat::Table => {
+                println!("{}", Table::new(leaderboard.to_standing_rows()));
+                println!("Records:");
+                println!("{}", Table::new(leaderboard.to_record_rows()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["catan".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(MIN_PLAYS_OPTIONAL_ARGUMENT.to_string(), "3".to_string());
+
+        let command = Leaderboard::create(&args, &optional_args).unwrap();
+        assert_eq!(command.game_name, "catan");
+        assert_eq!(command.min_plays, 3);
+        assert!(!command.include_guests);
+        assert_eq!(command.format, OutputFormat::Table);
+    }
+
+    #[test]
+    fn test_create_invalid_min_plays() {
+        let args = vec!["catan".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(MIN_PLAYS_OPTIONAL_ARGUMENT.to_string(), "-1".to_string());
+
+        let result = Leaderboard::create(&args, &optional_args);
+        assert_eq!(result.unwrap_err(), "Invalid minimum number of plays -1.");
+    }
+}
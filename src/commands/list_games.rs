@@ -1,29 +1,40 @@
-use crate::commands::models::game::{self, Games};
-use crate::commands::utils::{
-    file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
-    utils::create_path,
-};
-use crate::commands::{SAVE_DIR_OPTIONAL_ARGUMENT, add_score::GAMES_FOLER};
-use std::{collections::HashMap, fs, io};
+use crate::commands::models::game;
+use crate::commands::utils::data;
+use crate::commands::{AS_OF_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT};
+use chrono::NaiveDate;
+use std::collections::HashMap;
 use tabled::Table;
 
-const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] =
+    [SAVE_DIR_OPTIONAL_ARGUMENT, AS_OF_OPTIONAL_ARGUMENT];
 
 #[derive(Debug)]
 pub struct ListGames {
     optional_args: HashMap<String, String>,
 }
 
+/// The date given with `--as-of`, if any.
+pub fn parse_as_of(optional_args: &HashMap<String, String>) -> Result<Option<NaiveDate>, String> {
+    optional_args
+        .get(AS_OF_OPTIONAL_ARGUMENT)
+        .map(|as_of| {
+            NaiveDate::parse_from_str(as_of, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid date {}. Expected format is YYYY-MM-DD.", as_of))
+        })
+        .transpose()
+}
+
 impl ListGames {
     pub fn create(
         _args: &[String],
         optional_args: &HashMap<String, String>,
     ) -> Result<ListGames, String> {
-        for (key, _) in optional_args {
+        for key in optional_args.keys() {
             if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
                 return Err(format!("Unknown optional command for list-games {}.", key));
             }
         }
+        parse_as_of(optional_args)?;
 
         Ok(ListGames {
             optional_args: optional_args.to_owned(),
@@ -33,21 +44,10 @@ impl ListGames {
     pub fn run(&self) -> Result<(), String> {
         let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
 
-        let file_options = FileWrapperOptions::default();
-        let game_dir = create_path(&[GAMES_FOLER], data_file_path)?;
-
-        let game_files = fs::read_dir(&game_dir)
-            .map_err(|_| "An error occurred while accessing the data.")?
-            .map(|res| res.map(|e| e.path()))
-            .collect::<Result<Vec<_>, io::Error>>()
-            .map_err(|_| "An error occurred whil accessing the data.")?;
-
-        let mut all_games = Games::create_empy();
-        for game_file in game_files {
-            let mut file = FileWrapper::from_path(game_file, file_options.clone())?;
-            let games_curr: game::Games = file.load()?;
-            all_games.extend(&games_curr);
-        }
+        let all_games = match parse_as_of(&self.optional_args)? {
+            Some(as_of) => data::load_games_as_of(data_file_path, as_of)?,
+            None => data::load_all_games(data_file_path)?,
+        };
 
         let all_games_vec = all_games.order_by_date();
         let table_rows = game::from_vec_to_game_rows(all_games_vec);
@@ -0,0 +1,115 @@
+use crate::commands::models::player_report::{self, PlayerSort};
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT, SORT_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 3] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+    SORT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct ListPlayers {
+    sort: PlayerSort,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl ListPlayers {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<ListPlayers, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!(
+                    "Unknown optional command for list-players {}.",
+                    key
+                ));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for list-players.".to_string());
+        }
+
+        Ok(ListPlayers {
+            sort: match optional_args.get(SORT_OPTIONAL_ARGUMENT) {
+                Some(sort) => PlayerSort::parse(sort)?,
+                None => PlayerSort::Name,
+            },
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let players = data::load_players(data_file_path)?;
+        let games = data::load_all_games(data_file_path)?
+            .finished()
+            .order_by_date();
+        let activity = player_report::activity(&players, &games, self.sort);
+        match self.format {
+            OutputFormat::Json => output::print_json(&activity)?,
+            OutputFormat::Table => {
+                println!("{}", Table::new(player_report::to_activity_rows(&activity)))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let optional_args =
+            HashMap::from([(FORMAT_OPTIONAL_ARGUMENT.to_string(), "json".to_string())]);
+
+        let list_players = ListPlayers::create(&[], &optional_args).unwrap();
+        assert_eq!(list_players.format, OutputFormat::Json);
+        assert_eq!(list_players.sort, PlayerSort::Name);
+    }
+
+    #[test]
+    fn test_create_with_sort() {
+        let optional_args = HashMap::from([(
+            SORT_OPTIONAL_ARGUMENT.to_string(),
+            "last-played".to_string(),
+        )]);
+
+        let list_players = ListPlayers::create(&[], &optional_args).unwrap();
+        assert_eq!(list_players.sort, PlayerSort::LastPlayed);
+    }
+
+    #[test]
+    fn test_create_with_unknown_sort() {
+        let optional_args =
+            HashMap::from([(SORT_OPTIONAL_ARGUMENT.to_string(), "wins".to_string())]);
+
+        let result = ListPlayers::create(&[], &optional_args);
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown sort wins. Use name, games or last-played."
+        );
+    }
+
+    #[test]
+    fn test_create_with_arguments() {
+        let result = ListPlayers::create(&["alice".to_string()], &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for list-players."
+        );
+    }
+}
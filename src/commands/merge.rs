@@ -0,0 +1,177 @@
+use crate::commands::models::merge::{self, CONFLICTS_FILE_NAME, ConflictPolicy};
+use crate::commands::utils::{
+    data, file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, storage::Storage,
+    transaction::Transaction, utils::create_path,
+};
+use crate::commands::{POLICY_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 2] =
+    [SAVE_DIR_OPTIONAL_ARGUMENT, POLICY_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct Merge {
+    other_dir: String,
+    policy: ConflictPolicy,
+    optional_args: HashMap<String, String>,
+}
+
+impl Merge {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Merge, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for merge {}.", key));
+            }
+        }
+
+        if args.len() != 1 {
+            return Err("Invalid number of arguments for merge.".to_string());
+        }
+
+        let policy = match optional_args.get(POLICY_OPTIONAL_ARGUMENT) {
+            Some(policy) => ConflictPolicy::parse(policy)?,
+            None => ConflictPolicy::Report,
+        };
+
+        Ok(Merge {
+            other_dir: args[0].clone(),
+            policy,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        if !Path::new(&self.other_dir).is_dir() {
+            return Err(format!("Directory {} not found.", self.other_dir));
+        }
+        let other_dir = Some(&self.other_dir);
+        let their_players = data::load_players(other_dir)?;
+        let their_games = data::load_all_games(other_dir)?;
+        let their_catalog = data::load_catalog(other_dir)?;
+        let their_seasons = data::load_seasons(other_dir)?;
+        let mut their_sessions = data::load_sessions(other_dir)?;
+        let mut their_tournaments = data::load_tournaments(other_dir)?;
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let mut players = data::load_players(data_file_path)?;
+        let mut games = data::load_all_games(data_file_path)?;
+        let mut catalog = data::load_catalog(data_file_path)?;
+        let mut seasons = data::load_seasons(data_file_path)?;
+        let mut sessions = data::load_sessions(data_file_path)?;
+        let mut tournaments = data::load_tournaments(data_file_path)?;
+        for (name, our_name) in merge::player_renames(&players, &their_players) {
+            their_sessions.rename_player(&name, &our_name);
+            their_tournaments.rename_player(&name, &our_name);
+        }
+        let mut report = merge::merge(
+            &mut players,
+            &mut games,
+            &their_players,
+            &their_games,
+            self.policy,
+        )?;
+
+        data::save_players(data_file_path, &players)?;
+        // Their version of a game may be filed under another game name.
+        data::replace_all_games(data_file_path, &games)?;
+        if merge::merge_records(&mut catalog, &their_catalog, &mut report) {
+            data::save_catalog(data_file_path, &catalog)?;
+        }
+        if merge::merge_records(&mut seasons, &their_seasons, &mut report) {
+            data::save_seasons(data_file_path, &seasons)?;
+        }
+        if merge::merge_records(&mut sessions, &their_sessions, &mut report) {
+            data::save_sessions(data_file_path, &sessions)?;
+        }
+        if merge::merge_records(&mut tournaments, &their_tournaments, &mut report) {
+            data::save_tournaments(data_file_path, &tournaments)?;
+        }
+        // The conflicts of an earlier merge no longer apply.
+        let conflicts_path = create_path(&[CONFLICTS_FILE_NAME], data_file_path)?;
+        if !report.conflicts.is_empty() {
+            let mut file = FileWrapper::from_string(
+                &[CONFLICTS_FILE_NAME],
+                data_file_path,
+                FileWrapperOptions::default(),
+            )?;
+            file.save(&report.conflicts)?;
+        } else if conflicts_path.exists() {
+            fs::remove_file(&conflicts_path)
+                .map_err(|_| format!("Error removing file: {}", conflicts_path.display()))?;
+        }
+        transaction.commit("merge", format!("Merged data from {}.", self.other_dir))?;
+
+        println!(
+            "Merged {} new players, {} new games and {} other records from {}.",
+            report.added_players, report.added_games, report.added_records, self.other_dir
+        );
+        if report.duplicate_matches > 0 {
+            println!(
+                "Skipped {} matches recorded on both sides with different ids.",
+                report.duplicate_matches
+            );
+        }
+        if report.resolved_conflicts > 0 {
+            println!(
+                "Resolved {} conflicting games by policy.",
+                report.resolved_conflicts
+            );
+        }
+        for reason in &report.existing_records {
+            println!("{} Kept the existing one.", reason);
+        }
+        if !report.conflicts.is_empty() {
+            println!(
+                "{} conflicting games kept unchanged, see {}.",
+                report.conflicts.len(),
+                conflicts_path.display()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["other/dir".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(POLICY_OPTIONAL_ARGUMENT.to_string(), "theirs".to_string());
+
+        let result = Merge::create(&args, &optional_args);
+        assert!(result.is_ok());
+
+        let merge = result.unwrap();
+        assert_eq!(merge.other_dir, "other/dir");
+        assert_eq!(merge.policy, ConflictPolicy::KeepTheirs);
+    }
+
+    #[test]
+    fn test_create_default_policy() {
+        let args = vec!["other/dir".to_string()];
+
+        let merge = Merge::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(merge.policy, ConflictPolicy::Report);
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_args() {
+        let result = Merge::create(&[], &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for merge."
+        );
+    }
+}
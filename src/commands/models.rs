@@ -1,2 +1,20 @@
+pub mod breakdown;
+pub mod bundle;
+pub mod catalog;
+pub mod config;
+pub mod event;
 pub mod game;
+pub mod game_index;
+pub mod head_to_head;
+pub mod journal;
+pub mod leaderboard;
+pub mod merge;
 pub mod player;
+pub mod player_report;
+pub mod prediction;
+pub mod rating;
+pub mod season;
+pub mod session;
+pub mod site;
+pub mod streak;
+pub mod tournament;
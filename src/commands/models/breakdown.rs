@@ -0,0 +1,207 @@
+use crate::commands::models::game::Game;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tabled::Tabled;
+
+/// How a player scores by category, compared with everyone else.
+#[derive(Serialize, Debug)]
+pub struct CategoryProfile {
+    player: String,
+    matches: usize,
+    averages: BTreeMap<String, f64>,
+    /// The category where the player's average is furthest above the
+    /// average of all players.
+    best: Option<String>,
+    /// How far above the average of all players, in percent.
+    edge: Option<f64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CategoryAnalysis {
+    game_name: String,
+    categories: Vec<String>,
+    averages: BTreeMap<String, f64>,
+    players: Vec<CategoryProfile>,
+}
+
+#[derive(Tabled)]
+pub struct CategoryProfileRow {
+    player: String,
+    matches: usize,
+    averages: String,
+    best: String,
+    edge: String,
+}
+
+fn average(total: i64, count: usize) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total as f64 / count as f64
+    }
+}
+
+impl CategoryAnalysis {
+    /// Analyzes the score breakdowns recorded in `games`. A category missing
+    /// from a breakdown counts as zero points. `categories` sets the order
+    /// of the categories; any other recorded category is added after them.
+    pub fn compute(game_name: &str, games: &[Game], categories: &[String]) -> CategoryAnalysis {
+        let mut categories = categories.to_vec();
+        let breakdowns = games
+            .iter()
+            .flat_map(|game| game.get_breakdown())
+            .collect::<Vec<_>>();
+        for (_, points) in &breakdowns {
+            for category in points.keys() {
+                if !categories.contains(category) {
+                    categories.push(category.clone());
+                }
+            }
+        }
+
+        let category_average = |points: &[&BTreeMap<String, i64>], category: &String| {
+            average(
+                points.iter().filter_map(|p| p.get(category)).sum(),
+                points.len(),
+            )
+        };
+
+        let all = breakdowns.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+        let averages = categories
+            .iter()
+            .map(|category| (category.clone(), category_average(&all, category)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut by_player: BTreeMap<&String, Vec<&BTreeMap<String, i64>>> = BTreeMap::new();
+        for (player, points) in &breakdowns {
+            by_player.entry(player).or_default().push(points);
+        }
+
+        let players = by_player
+            .into_iter()
+            .map(|(player, points)| {
+                let player_averages = categories
+                    .iter()
+                    .map(|category| (category.clone(), category_average(&points, category)))
+                    .collect::<BTreeMap<_, _>>();
+
+                let mut best: Option<(&String, f64)> = None;
+                for category in &categories {
+                    let overall = averages[category];
+                    if overall == 0.0 {
+                        continue;
+                    }
+                    let edge = (player_averages[category] / overall - 1.0) * 100.0;
+                    if best.is_none_or(|(_, best_edge)| edge > best_edge) {
+                        best = Some((category, edge));
+                    }
+                }
+
+                CategoryProfile {
+                    player: player.clone(),
+                    matches: points.len(),
+                    averages: player_averages,
+                    best: best.map(|(category, _)| category.clone()),
+                    edge: best.map(|(_, edge)| edge),
+                }
+            })
+            .collect();
+
+        CategoryAnalysis {
+            game_name: game_name.to_string(),
+            categories,
+            averages,
+            players,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn to_profile_rows(&self) -> Vec<CategoryProfileRow> {
+        let format_averages = |averages: &BTreeMap<String, f64>| {
+            self.categories
+                .iter()
+                .map(|category| format!("{} {:.1}", category, averages[category]))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let mut rows = self
+            .players
+            .iter()
+            .map(|profile| CategoryProfileRow {
+                player: profile.player.clone(),
+                matches: profile.matches,
+                averages: format_averages(&profile.averages),
+                best: profile.best.clone().unwrap_or_default(),
+                edge: profile
+                    .edge
+                    .map(|edge| format!("{:+.0}%", edge))
+                    .unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+        rows.push(CategoryProfileRow {
+            player: "all players".to_string(),
+            matches: self.players.iter().map(|profile| profile.matches).sum(),
+            averages: format_averages(&self.averages),
+            best: String::new(),
+            edge: String::new(),
+        });
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(scores: &[&str]) -> Game {
+        Game::build(
+            "7 Wonders".to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some("2025-01-01"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn compute_finds_best_category() {
+        let games = vec![
+            create_game(&[
+                "alice::30::military=20,science=10",
+                "bob::30::military=5,science=25",
+            ]),
+            create_game(&["alice::20::military=10,science=10", "carol::12"]),
+        ];
+
+        let analysis = CategoryAnalysis::compute(
+            "7 Wonders",
+            &games,
+            &["science".to_string(), "military".to_string()],
+        );
+        assert_eq!(analysis.categories, ["science", "military"]);
+        assert!((analysis.averages["military"] - 35.0 / 3.0).abs() < 1e-9);
+
+        let alice = &analysis.players[0];
+        assert_eq!(alice.player, "alice");
+        assert_eq!(alice.matches, 2);
+        assert_eq!(alice.averages["military"], 15.0);
+        assert_eq!(alice.best.as_deref(), Some("military"));
+
+        let bob = &analysis.players[1];
+        assert_eq!(bob.best.as_deref(), Some("science"));
+        assert_eq!(analysis.players.len(), 2);
+    }
+
+    #[test]
+    fn compute_adds_recorded_categories() {
+        let games = vec![create_game(&["alice::5::coins=5"])];
+
+        let analysis = CategoryAnalysis::compute("7 Wonders", &games, &["military".to_string()]);
+        assert_eq!(analysis.categories, ["military", "coins"]);
+        assert_eq!(analysis.players[0].averages["military"], 0.0);
+        assert_eq!(analysis.players[0].best.as_deref(), Some("coins"));
+    }
+}
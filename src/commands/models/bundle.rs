@@ -0,0 +1,132 @@
+use crate::commands::models::catalog::Catalog;
+use crate::commands::models::config::Config;
+use crate::commands::models::game::Games;
+use crate::commands::models::game_index::GameIndex;
+use crate::commands::models::player::Players;
+use crate::commands::models::season::Seasons;
+use crate::commands::models::session::Sessions;
+use crate::commands::models::tournament::Tournaments;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Version 2 added everything besides players and games. Bundles of version
+/// 1 are read with those left empty.
+pub const BUNDLE_FORMAT_VERSION: u32 = 2;
+
+/// The whole content of a save dir, apart from the journal and the event
+/// log.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Bundle {
+    format_version: u32,
+    exported_at: DateTime<Utc>,
+    pub players: Players,
+    pub games: Games,
+    #[serde(default = "Catalog::create_empty")]
+    pub catalog: Catalog,
+    #[serde(default = "Seasons::create_empty")]
+    pub seasons: Seasons,
+    #[serde(default = "Sessions::create_empty")]
+    pub sessions: Sessions,
+    #[serde(default = "Tournaments::create_empty")]
+    pub tournaments: Tournaments,
+    #[serde(default)]
+    pub game_index: GameIndex,
+    #[serde(default)]
+    pub config: Config,
+}
+
+impl Bundle {
+    /// A bundle of `players` and `games`, with everything else empty.
+    pub fn new(players: Players, games: Games) -> Bundle {
+        Bundle {
+            format_version: BUNDLE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            players,
+            games,
+            catalog: Catalog::create_empty(),
+            seasons: Seasons::create_empty(),
+            sessions: Sessions::create_empty(),
+            tournaments: Tournaments::create_empty(),
+            game_index: GameIndex::create_empty(),
+            config: Config::default(),
+        }
+    }
+
+    pub fn check_version(&self) -> Result<(), String> {
+        if self.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported bundle format version {}. The latest supported version is {}.",
+                self.format_version, BUNDLE_FORMAT_VERSION
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::game::Game;
+    use crate::commands::models::player::Player;
+
+    fn create_bundle() -> Bundle {
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        players.add_player(Player::new("bob".to_string())).unwrap();
+
+        let mut games = Games::create_empy();
+        games.add_game(
+            Game::build(
+                "catan".to_string(),
+                vec!["alice::10".to_string(), "bob::8".to_string()],
+                Some("2025-01-01"),
+            )
+            .unwrap(),
+        );
+
+        Bundle::new(players, games)
+    }
+
+    #[test]
+    fn bundle_serialization_roundtrip() {
+        let bundle = create_bundle();
+        let serialized = serde_json::to_string(&bundle).unwrap();
+
+        let loaded: Bundle = serde_json::from_str(&serialized).unwrap();
+        assert!(loaded.check_version().is_ok());
+        assert_eq!(loaded.players.get_players().len(), 2);
+        assert_eq!(loaded.games.order_by_date(), bundle.games.order_by_date());
+    }
+
+    #[test]
+    fn read_version_1_bundle() {
+        let bundle = create_bundle();
+        let mut serialized = serde_json::to_value(&bundle).unwrap();
+        let fields = serialized.as_object_mut().unwrap();
+        for field in [
+            "catalog",
+            "seasons",
+            "sessions",
+            "tournaments",
+            "game_index",
+            "config",
+        ] {
+            fields.remove(field);
+        }
+        fields.insert("format_version".to_string(), serde_json::json!(1));
+
+        let loaded: Bundle = serde_json::from_value(serialized).unwrap();
+        assert!(loaded.check_version().is_ok());
+        assert_eq!(loaded.players.get_players().len(), 2);
+        assert!(loaded.catalog.resolve("catan").is_none());
+    }
+
+    #[test]
+    fn check_version_newer_bundle() {
+        let mut bundle = create_bundle();
+        bundle.format_version = BUNDLE_FORMAT_VERSION + 1;
+        assert!(bundle.check_version().is_err());
+    }
+}
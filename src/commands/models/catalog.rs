@@ -0,0 +1,384 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::merge::Records;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tabled::Tabled;
+
+pub const FILE_NAME_DATA: &str = "catalog.json";
+
+/// A game of the catalog, under its canonical name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct CatalogEntry {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_players: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_players: Option<usize>,
+    /// Typical duration in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    /// The categories a final score is made of, such as military or science.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    score_categories: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Catalog {
+    games: BTreeMap<String, CatalogEntry>,
+}
+
+#[derive(Tabled)]
+pub struct CatalogRow {
+    name: String,
+    aliases: String,
+    players: String,
+    duration: String,
+    category: String,
+    scoring: String,
+}
+
+impl CatalogEntry {
+    pub fn build(
+        name: &str,
+        min_players: Option<usize>,
+        max_players: Option<usize>,
+        duration: Option<u32>,
+        category: Option<String>,
+    ) -> Result<CatalogEntry, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("The game name cannot be empty.".to_string());
+        }
+        if let (Some(min), Some(max)) = (min_players, max_players)
+            && min > max
+        {
+            return Err("The minimum number of players cannot exceed the maximum.".to_string());
+        }
+
+        Ok(CatalogEntry {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            min_players,
+            max_players,
+            duration,
+            category,
+            score_categories: Vec::new(),
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_score_categories(&self) -> &[String] {
+        &self.score_categories
+    }
+
+    pub fn set_score_categories(&mut self, categories: Vec<String>) -> Result<(), String> {
+        for (index, category) in categories.iter().enumerate() {
+            if category.is_empty() {
+                return Err("The category cannot be empty.".to_string());
+            }
+            if categories[..index].contains(category) {
+                return Err(format!("Duplicate category {}.", category));
+            }
+        }
+
+        self.score_categories = categories;
+        Ok(())
+    }
+
+    /// Checks that the score breakdown of `game` only uses the categories of
+    /// the game.
+    pub fn check_breakdown(&self, game: &Game) -> Result<(), String> {
+        let categories = game
+            .get_breakdown()
+            .values()
+            .flat_map(|points| points.keys());
+        for category in categories {
+            if self.score_categories.is_empty() {
+                return Err(format!("{} has no score categories.", self.name));
+            }
+            if !self.score_categories.contains(category) {
+                return Err(format!(
+                    "Unknown category {} for {}. Use one of {}.",
+                    category,
+                    self.name,
+                    self.score_categories.join(", ")
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `name` is the canonical name or an alias, ignoring case and
+    /// surrounding whitespace.
+    fn matches(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.name.to_lowercase() == name || self.aliases.iter().any(|a| a.to_lowercase() == name)
+    }
+
+    /// Checks that a match with `count` players is allowed.
+    pub fn check_player_count(&self, count: usize) -> Result<(), String> {
+        if let Some(min) = self.min_players
+            && count < min
+        {
+            return Err(format!(
+                "{} needs at least {} players, got {}.",
+                self.name, min, count
+            ));
+        }
+        if let Some(max) = self.max_players
+            && count > max
+        {
+            return Err(format!(
+                "{} allows at most {} players, got {}.",
+                self.name, max, count
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn to_row(&self) -> CatalogRow {
+        CatalogRow {
+            name: self.name.clone(),
+            aliases: self.aliases.join(", "),
+            players: match (self.min_players, self.max_players) {
+                (Some(min), Some(max)) if min == max => min.to_string(),
+                (Some(min), Some(max)) => format!("{}-{}", min, max),
+                (Some(min), None) => format!("{}+", min),
+                (None, Some(max)) => format!("up to {}", max),
+                (None, None) => String::new(),
+            },
+            duration: self
+                .duration
+                .map(|minutes| format!("{} min", minutes))
+                .unwrap_or_default(),
+            category: self.category.clone().unwrap_or_default(),
+            scoring: self.score_categories.join(", "),
+        }
+    }
+}
+
+impl Catalog {
+    pub fn create_empty() -> Catalog {
+        Catalog {
+            games: BTreeMap::new(),
+        }
+    }
+
+    /// The entry `name` refers to, by canonical name or alias.
+    pub fn resolve(&self, name: &str) -> Option<&CatalogEntry> {
+        self.games.values().find(|entry| entry.matches(name))
+    }
+
+    pub fn get(&self, name: &str) -> Result<&CatalogEntry, String> {
+        self.resolve(name)
+            .ok_or_else(|| format!("Game {} not found in the catalog.", name))
+    }
+
+    fn get_mut(&mut self, name: &str) -> Result<&mut CatalogEntry, String> {
+        self.games
+            .values_mut()
+            .find(|entry| entry.matches(name))
+            .ok_or_else(|| format!("Game {} not found in the catalog.", name))
+    }
+
+    fn check_available(&self, name: &str) -> Result<(), String> {
+        match self.resolve(name) {
+            Some(entry) => Err(format!(
+                "{} is already used by game {}.",
+                name.trim(),
+                entry.get_name()
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn add_game(&mut self, entry: CatalogEntry) -> Result<(), String> {
+        self.check_available(entry.get_name())?;
+        self.games.insert(entry.get_name().to_string(), entry);
+        Ok(())
+    }
+
+    pub fn add_alias(&mut self, name: &str, alias: &str) -> Result<(), String> {
+        let alias = alias.trim();
+        if alias.is_empty() {
+            return Err("The alias cannot be empty.".to_string());
+        }
+        self.check_available(alias)?;
+        self.get_mut(name)?.aliases.push(alias.to_string());
+        Ok(())
+    }
+
+    /// Renames a game. The previous name is kept as an alias, so that it
+    /// still resolves to the game.
+    pub fn rename(&mut self, name: &str, new_name: &str) -> Result<(), String> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err("The game name cannot be empty.".to_string());
+        }
+        let old_name = self.get(name)?.get_name().to_string();
+        if let Some(entry) = self.resolve(new_name)
+            && entry.get_name() != old_name
+        {
+            return Err(format!(
+                "{} is already used by game {}.",
+                new_name,
+                entry.get_name()
+            ));
+        }
+
+        let mut entry = self.games.remove(&old_name).unwrap_or_default();
+        entry.aliases.retain(|alias| alias != new_name);
+        if old_name != new_name {
+            entry.aliases.push(old_name);
+        }
+        entry.name = new_name.to_string();
+        self.games.insert(new_name.to_string(), entry);
+        Ok(())
+    }
+
+    pub fn set_score_categories(
+        &mut self,
+        name: &str,
+        categories: Vec<String>,
+    ) -> Result<(), String> {
+        self.get_mut(name)?.set_score_categories(categories)
+    }
+
+    pub fn to_catalog_rows(&self) -> Vec<CatalogRow> {
+        self.games.values().map(CatalogEntry::to_row).collect()
+    }
+}
+
+impl Records for Catalog {
+    type Record = CatalogEntry;
+
+    fn records(&self) -> Vec<&CatalogEntry> {
+        self.games.values().collect()
+    }
+
+    fn add_record(&mut self, entry: CatalogEntry) -> Result<(), String> {
+        self.add_game(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_catalog() -> Catalog {
+        let mut catalog = Catalog::create_empty();
+        catalog
+            .add_game(CatalogEntry::build("Catan", Some(3), Some(4), Some(90), None).unwrap())
+            .unwrap();
+        catalog.add_alias("catan", "Settlers").unwrap();
+        catalog
+    }
+
+    #[test]
+    fn resolve_ignores_case_and_whitespace() {
+        let catalog = create_catalog();
+
+        assert_eq!(catalog.resolve(" CATAN ").unwrap().get_name(), "Catan");
+        assert_eq!(catalog.resolve("settlers").unwrap().get_name(), "Catan");
+        assert!(catalog.resolve("azul").is_none());
+    }
+
+    #[test]
+    fn add_game_rejects_used_names() {
+        let mut catalog = create_catalog();
+
+        let entry = CatalogEntry::build("settlers", None, None, None, None).unwrap();
+        assert_eq!(
+            catalog.add_game(entry).unwrap_err(),
+            "settlers is already used by game Catan."
+        );
+    }
+
+    #[test]
+    fn build_rejects_inverted_player_range() {
+        let entry = CatalogEntry::build("Catan", Some(4), Some(3), None, None);
+        assert_eq!(
+            entry.unwrap_err(),
+            "The minimum number of players cannot exceed the maximum."
+        );
+    }
+
+    #[test]
+    fn check_player_count_uses_range() {
+        let catalog = create_catalog();
+        let catan = catalog.get("catan").unwrap();
+
+        assert!(catan.check_player_count(3).is_ok());
+        assert_eq!(
+            catan.check_player_count(2).unwrap_err(),
+            "Catan needs at least 3 players, got 2."
+        );
+        assert_eq!(
+            catan.check_player_count(5).unwrap_err(),
+            "Catan allows at most 4 players, got 5."
+        );
+    }
+
+    #[test]
+    fn rename_keeps_old_name_as_alias() {
+        let mut catalog = create_catalog();
+        catalog.rename("settlers", "Catan Classic").unwrap();
+
+        let entry = catalog.get("catan").unwrap();
+        assert_eq!(entry.get_name(), "Catan Classic");
+        assert_eq!(entry.aliases, vec!["Settlers", "Catan"]);
+    }
+
+    #[test]
+    fn check_breakdown_uses_score_categories() {
+        let mut catalog = create_catalog();
+        let game = Game::build(
+            "Catan".to_string(),
+            vec!["alice::10::roads=2,cities=8".to_string()],
+            Some("2025-01-01"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            catalog
+                .get("catan")
+                .unwrap()
+                .check_breakdown(&game)
+                .unwrap_err(),
+            "Catan has no score categories."
+        );
+
+        catalog
+            .set_score_categories(
+                "catan",
+                vec!["roads".to_string(), "settlements".to_string()],
+            )
+            .unwrap();
+        assert_eq!(
+            catalog
+                .get("catan")
+                .unwrap()
+                .check_breakdown(&game)
+                .unwrap_err(),
+            "Unknown category cities for Catan. Use one of roads, settlements."
+        );
+    }
+
+    #[test]
+    fn set_score_categories_rejects_duplicates() {
+        let mut catalog = create_catalog();
+
+        let result =
+            catalog.set_score_categories("catan", vec!["roads".to_string(), "roads".to_string()]);
+        assert_eq!(result.unwrap_err(), "Duplicate category roads.");
+    }
+}
@@ -0,0 +1,84 @@
+use crate::commands::models::rating::RatingAlgorithm;
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMode {
+    /// Players and games files are the canonical data.
+    #[default]
+    Snapshot,
+    /// The event log is the canonical data and the files are its projection.
+    Events,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Config {
+    #[serde(default)]
+    storage: StorageMode,
+    /// Used by the rating commands when no `--algorithm` is given.
+    #[serde(default)]
+    rating_algorithm: RatingAlgorithm,
+}
+
+impl StorageMode {
+    pub fn parse(mode: &str) -> Result<StorageMode, String> {
+        match mode {
+            "snapshot" => Ok(StorageMode::Snapshot),
+            "events" => Ok(StorageMode::Events),
+            other => Err(format!(
+                "Unknown storage mode {}. Supported modes are snapshot and events.",
+                other
+            )),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self {
+            StorageMode::Snapshot => "snapshot",
+            StorageMode::Events => "events",
+        }
+    }
+}
+
+impl Config {
+    pub fn get_storage(&self) -> StorageMode {
+        self.storage
+    }
+
+    pub fn set_storage(&mut self, storage: StorageMode) {
+        self.storage = storage;
+    }
+
+    pub fn get_rating_algorithm(&self) -> RatingAlgorithm {
+        self.rating_algorithm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_uses_snapshots() {
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.get_storage(), StorageMode::Snapshot);
+        assert_eq!(config.get_rating_algorithm(), RatingAlgorithm::TrueSkill);
+    }
+
+    #[test]
+    fn config_with_rating_algorithm() {
+        let config: Config = serde_json::from_str(r#"{"rating_algorithm": "glicko2"}"#).unwrap();
+        assert_eq!(config.get_rating_algorithm(), RatingAlgorithm::Glicko2);
+    }
+
+    #[test]
+    fn parse_storage_mode() {
+        assert_eq!(StorageMode::parse("events"), Ok(StorageMode::Events));
+        assert_eq!(
+            StorageMode::parse("sqlite").unwrap_err(),
+            "Unknown storage mode sqlite. Supported modes are snapshot and events."
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::commands::models::game::{Game, Games};
+use crate::commands::models::player::{Player, Players};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+pub const EVENTS_FILE_NAME: &str = "events.jsonl";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum EventKind {
+    PlayerAdded { player: Player },
+    PlayerUpdated { player: Player },
+    PlayerRemoved { player_name: String },
+    MatchRecorded { game: Game },
+    MatchUpdated { game: Game },
+    MatchDeleted { game_id: Uuid },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Event {
+    recorded_at: DateTime<Utc>,
+    #[serde(flatten)]
+    kind: EventKind,
+}
+
+/// Players and games as rebuilt from the event log.
+pub struct Projection {
+    pub players: Players,
+    pub games: Games,
+}
+
+impl Event {
+    pub fn new(kind: EventKind) -> Event {
+        Event {
+            recorded_at: Utc::now(),
+            kind,
+        }
+    }
+
+    pub fn get_recorded_at(&self) -> &DateTime<Utc> {
+        &self.recorded_at
+    }
+}
+
+impl Projection {
+    /// Replays `events` in log order.
+    pub fn replay(events: &[Event]) -> Projection {
+        let mut projection = Projection {
+            players: Players::create_empty(),
+            games: Games::create_empy(),
+        };
+
+        for event in events {
+            match &event.kind {
+                EventKind::PlayerAdded { player } => {
+                    projection.players.restore_player(player.clone());
+                }
+                EventKind::PlayerUpdated { player } => {
+                    let _ = projection.players.update_player(player.clone());
+                }
+                EventKind::PlayerRemoved { player_name } => {
+                    let _ = projection
+                        .players
+                        .remove_player(Player::new(player_name.clone()));
+                }
+                EventKind::MatchRecorded { game } | EventKind::MatchUpdated { game } => {
+                    projection.games.add_game(game.clone());
+                }
+                EventKind::MatchDeleted { game_id } => {
+                    let _ = projection.games.delete(*game_id);
+                }
+            }
+        }
+
+        projection
+    }
+}
+
+/// Describes the differences between two states of the data as events.
+pub fn diff(
+    players_before: &Players,
+    games_before: &Games,
+    players_after: &Players,
+    games_after: &Games,
+) -> Vec<EventKind> {
+    let mut events = Vec::new();
+
+    let names = players_before
+        .get_players()
+        .keys()
+        .chain(players_after.get_players().keys())
+        .collect::<BTreeSet<_>>();
+    for name in names {
+        match (
+            players_before.get_players().get(name),
+            players_after.get_players().get(name),
+        ) {
+            (None, Some(player)) => events.push(EventKind::PlayerAdded {
+                player: player.clone(),
+            }),
+            (Some(_), None) => events.push(EventKind::PlayerRemoved {
+                player_name: name.clone(),
+            }),
+            (Some(before), Some(after)) if before != after => {
+                events.push(EventKind::PlayerUpdated {
+                    player: after.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let ids = games_before
+        .get_games()
+        .keys()
+        .chain(games_after.get_games().keys())
+        .collect::<BTreeSet<_>>();
+    for id in ids {
+        match (
+            games_before.get_games().get(id),
+            games_after.get_games().get(id),
+        ) {
+            (None, Some(game)) => events.push(EventKind::MatchRecorded { game: game.clone() }),
+            (Some(_), None) => events.push(EventKind::MatchDeleted { game_id: *id }),
+            (Some(before), Some(after)) if before != after => {
+                events.push(EventKind::MatchUpdated {
+                    game: after.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
+
+/// Builds the initial event log for existing data. Matches are dated on the
+/// day they were played and players on their first match, so that point in
+/// time queries also work for data recorded before the log existed.
+pub fn seed(players: &Players, games: &Games) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut added = BTreeSet::new();
+
+    for game in games.order_by_date() {
+        let recorded_at = game.get_datetime().and_time(NaiveTime::MIN).and_utc();
+        let mut game_players = game.get_scores().keys().collect::<Vec<_>>();
+        game_players.sort();
+        for name in game_players {
+            if let Some(player) = players.get_players().get(name)
+                && added.insert(name.clone())
+            {
+                events.push(Event {
+                    recorded_at,
+                    kind: EventKind::PlayerAdded {
+                        player: player.clone(),
+                    },
+                });
+            }
+        }
+        events.push(Event {
+            recorded_at,
+            kind: EventKind::MatchRecorded { game },
+        });
+    }
+
+    let mut remaining = players
+        .get_players()
+        .iter()
+        .filter(|(name, _)| !added.contains(*name))
+        .collect::<Vec<_>>();
+    remaining.sort_by_key(|(name, _)| *name);
+    for (_, player) in remaining {
+        events.push(Event::new(EventKind::PlayerAdded {
+            player: player.clone(),
+        }));
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn create_game(date: &str) -> Game {
+        Game::build(
+            "catan".to_string(),
+            vec!["alice::10".to_string(), "bob::8".to_string()],
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn create_players() -> Players {
+        let mut players = Players::create_empty();
+        for name in ["alice", "bob"] {
+            players.add_player(Player::new(name.to_string())).unwrap();
+        }
+        players
+    }
+
+    #[test]
+    fn diff_detects_added_updated_and_deleted() {
+        let kept = create_game("2025-01-01");
+        let deleted = create_game("2025-01-02");
+        let mut games_before = Games::create_empy();
+        games_before.add_game(kept.clone());
+        games_before.add_game(deleted.clone());
+
+        let added = create_game("2025-01-03");
+        let mut games_after = Games::create_empy();
+        games_after.add_game(kept);
+        games_after.add_game(added.clone());
+
+        let events = diff(
+            &Players::create_empty(),
+            &games_before,
+            &create_players(),
+            &games_after,
+        );
+        assert_eq!(events.len(), 4);
+        assert!(events.contains(&EventKind::PlayerAdded {
+            player: Player::new("alice".to_string())
+        }));
+        assert!(events.contains(&EventKind::MatchRecorded { game: added }));
+        assert!(events.contains(&EventKind::MatchDeleted {
+            game_id: *deleted.get_id()
+        }));
+    }
+
+    #[test]
+    fn replay_rebuilds_state() {
+        let game = create_game("2025-01-01");
+        let events = vec![
+            Event::new(EventKind::PlayerAdded {
+                player: Player::new("alice".to_string()),
+            }),
+            Event::new(EventKind::MatchRecorded { game: game.clone() }),
+            Event::new(EventKind::PlayerRemoved {
+                player_name: "alice".to_string(),
+            }),
+        ];
+
+        let projection = Projection::replay(&events);
+        assert!(!projection.players.exists(Player::new("alice".to_string())));
+        assert_eq!(projection.games.get_games().get(game.get_id()), Some(&game));
+    }
+
+    #[test]
+    fn diff_and_replay_player_updates() {
+        let players_before = create_players();
+        let mut players_after = create_players();
+        let mut alice = Player::new("alice".to_string());
+        alice.set_joined(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        players_after.update_player(alice.clone()).unwrap();
+
+        let events = diff(
+            &players_before,
+            &Games::create_empy(),
+            &players_after,
+            &Games::create_empy(),
+        );
+        assert_eq!(
+            events,
+            vec![EventKind::PlayerUpdated {
+                player: alice.clone()
+            }]
+        );
+
+        let mut log = seed(&players_before, &Games::create_empy());
+        log.extend(events.into_iter().map(Event::new));
+        let projection = Projection::replay(&log);
+        assert_eq!(projection.players.get("alice"), Ok(&alice));
+    }
+
+    #[test]
+    fn replay_then_filter_by_match_date() {
+        let mut games = Games::create_empy();
+        games.add_game(create_game("2025-01-01"));
+        games.add_game(create_game("2025-06-15"));
+        let mut events = seed(&create_players(), &games);
+        events.push(Event::new(EventKind::MatchRecorded {
+            game: create_game("2025-02-01"),
+        }));
+
+        let projection = Projection::replay(&events);
+        let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert_eq!(projection.games.played_until(as_of).get_games().len(), 2);
+        assert!(projection.players.exists(Player::new("bob".to_string())));
+    }
+
+    #[test]
+    fn event_serialization_is_tagged() {
+        let event = Event::new(EventKind::PlayerRemoved {
+            player_name: "alice".to_string(),
+        });
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["type"], "PlayerRemoved");
+        assert_eq!(value["player_name"], "alice");
+
+        let loaded: Event = serde_json::from_value(value).unwrap();
+        assert_eq!(loaded, event);
+    }
+}
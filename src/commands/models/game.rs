@@ -1,15 +1,62 @@
+use crate::commands::models::player;
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use tabled::Tabled;
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Points per category, per player.
+pub type Breakdown = BTreeMap<String, BTreeMap<String, i64>>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Game {
     id: Uuid,
     game_name: String,
-    scores: HashMap<String, usize>,
+    scores: HashMap<String, i64>,
     time: NaiveDate,
+    /// When the match was entered, to order matches played on the same day.
+    /// Missing on matches recorded by older versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recorded_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    winners: Vec<String>,
+    /// How the score of each player is made up, by category.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    breakdown: Breakdown,
+    /// Points scored in each round, when the match is scored by rounds. The
+    /// scores are then the totals of the rounds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rounds: Option<Vec<BTreeMap<String, i64>>>,
+    /// Whether rounds are still being added to the match. Matches in progress
+    /// are left out of ratings and statistics.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    in_progress: bool,
+    /// The tournament the match was played in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tournament: Option<Uuid>,
+    /// The session the match was played in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session: Option<String>,
+    #[serde(flatten)]
+    details: MatchDetails,
+}
+
+/// Optional information about when, where and how a match was played.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct MatchDetails {
+    /// When the match started, in the timezone it was played in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start: Option<DateTime<FixedOffset>>,
+    /// Length in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
 }
 
 #[derive(Tabled)]
@@ -18,6 +65,7 @@ pub struct GameRow {
     name: String,
     date: String,
     scores: String,
+    details: String,
 }
 
 impl From<&Game> for GameRow {
@@ -25,7 +73,19 @@ impl From<&Game> for GameRow {
         let game_scores = game
             .get_scores()
             .iter()
-            .map(|(player, score)| format!("{} {}", player, score))
+            .map(|(player, score)| match game.breakdown.get(player) {
+                Some(categories) => format!(
+                    "{} {} ({})",
+                    player,
+                    score,
+                    categories
+                        .iter()
+                        .map(|(category, points)| format!("{} {}", category, points))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                None => format!("{} {}", player, score),
+            })
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -34,10 +94,30 @@ impl From<&Game> for GameRow {
             name: game.get_name().to_string(),
             date: game.get_datetime().to_string(),
             scores: game_scores,
+            details: match &game.rounds {
+                Some(rounds) => [
+                    match game.in_progress {
+                        true => format!("{} rounds, in progress", rounds.len()),
+                        false => format!("{} rounds", rounds.len()),
+                    },
+                    game.details.describe(),
+                ]
+                .join("\n")
+                .trim_end()
+                .to_string(),
+                None => game.details.describe(),
+            },
         }
     }
 }
 
+#[derive(Tabled)]
+pub struct RoundRow {
+    player: String,
+    rounds: String,
+    total: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Games {
     games: HashMap<Uuid, Game>,
@@ -49,14 +129,14 @@ impl Game {
         scores: Vec<String>,
         time: Option<&str>,
     ) -> Result<Game, String> {
-        let parsed_scores = Self::parse_scores(&scores)?;
+        let (parsed_scores, breakdown) = Self::parse_scores(&scores)?;
 
-        if parsed_scores.len() == 0 {
+        if parsed_scores.is_empty() {
             return Err("No scores provided.".to_string());
         }
 
         let time = match time {
-            Some(time) => NaiveDate::parse_from_str(&time, "%Y-%m-%d")
+            Some(time) => NaiveDate::parse_from_str(time, "%Y-%m-%d")
                 .map_err(|_| "Error parsing date. The input format is YYYY-MM-DD.".to_string())?,
             None => Utc::now().date_naive(),
         };
@@ -65,25 +145,237 @@ impl Game {
             id: Uuid::new_v4(),
             game_name: gamename,
             scores: parsed_scores,
-            time: time,
+            time,
+            recorded_at: Some(Utc::now()),
+            winners: Vec::new(),
+            breakdown,
+            rounds: None,
+            in_progress: false,
+            tournament: None,
+            session: None,
+            details: MatchDetails::default(),
         })
     }
 
-    fn parse_scores(scores: &[String]) -> Result<HashMap<String, usize>, String> {
+    pub fn new(
+        game_name: String,
+        scores: HashMap<String, i64>,
+        time: NaiveDate,
+        winners: Vec<String>,
+    ) -> Game {
+        Game {
+            id: Uuid::new_v4(),
+            game_name,
+            scores,
+            time,
+            recorded_at: Some(Utc::now()),
+            winners,
+            breakdown: BTreeMap::new(),
+            rounds: None,
+            in_progress: false,
+            tournament: None,
+            session: None,
+            details: MatchDetails::default(),
+        }
+    }
+
+    /// Parses `player::score` entries, optionally followed by the breakdown
+    /// of the score as `::category=points,category=points`.
+    fn parse_scores(scores: &[String]) -> Result<(HashMap<String, i64>, Breakdown), String> {
         let mut hashed_scores = HashMap::new();
+        let mut breakdown = BTreeMap::new();
         for score in scores {
             let vec_score: Vec<&str> = score.as_str().split("::").collect();
-            if vec_score.len() != 2 {
+            if vec_score.len() != 2 && vec_score.len() != 3 {
                 return Err("Error parsing scores. The input format is player::score.".to_string());
             }
             let player = vec_score[0].to_string();
-            let score: usize = vec_score[1].parse().map_err(|_| {
+            if player::is_guest(&player) && player[player::GUEST_PREFIX.len()..].trim().is_empty() {
+                return Err("The guest name cannot be empty.".to_string());
+            }
+            let score: i64 = vec_score[1].parse().map_err(|_| {
                 "Error parsing scores. The input format is player::score.".to_string()
             })?;
+            if let Some(categories) = vec_score.get(2) {
+                breakdown.insert(
+                    player.clone(),
+                    Self::parse_breakdown(&player, score, categories)?,
+                );
+            }
             hashed_scores.insert(player, score);
         }
 
-        Ok(hashed_scores)
+        Ok((hashed_scores, breakdown))
+    }
+
+    fn parse_breakdown(
+        player: &str,
+        score: i64,
+        categories: &str,
+    ) -> Result<BTreeMap<String, i64>, String> {
+        let error = || {
+            format!(
+                "Error parsing the breakdown of {}. The input format is player::score::category=points,category=points.",
+                player
+            )
+        };
+
+        let mut breakdown = BTreeMap::new();
+        for category in categories.split(',') {
+            let (category, points) = category.split_once('=').ok_or_else(error)?;
+            let points: i64 = points.trim().parse().map_err(|_| error())?;
+            let category = category.trim();
+            if category.is_empty() || breakdown.insert(category.to_string(), points).is_some() {
+                return Err(error());
+            }
+        }
+
+        let total: i64 = breakdown.values().sum();
+        if total != score {
+            return Err(format!(
+                "The categories of {} add up to {}, not {}.",
+                player, total, score
+            ));
+        }
+        Ok(breakdown)
+    }
+
+    /// Parses the points of a round as `player::points`. Points can be
+    /// negative.
+    pub fn parse_round(points: &[String]) -> Result<BTreeMap<String, i64>, String> {
+        let mut round = BTreeMap::new();
+        for entry in points {
+            let (player, points) = entry
+                .split_once("::")
+                .and_then(|(player, points)| Some((player, points.parse::<i64>().ok()?)))
+                .ok_or("Error parsing round. The input format is player::points.")?;
+            round.insert(player.to_string(), points);
+        }
+
+        Ok(round)
+    }
+
+    /// Scores the match by rounds from now on, starting from zero. The match
+    /// is in progress until it is finished.
+    pub fn start_rounds(&mut self) {
+        self.scores.values_mut().for_each(|score| *score = 0);
+        self.rounds = Some(Vec::new());
+        self.in_progress = true;
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    /// Ends a match scored by rounds, so that it counts in ratings and
+    /// statistics.
+    pub fn finish_rounds(&mut self) -> Result<(), String> {
+        if self.rounds.is_none() {
+            return Err(format!("Match {} is not scored by rounds.", self.id));
+        }
+        if !self.in_progress {
+            return Err(format!("Match {} is already finished.", self.id));
+        }
+        self.in_progress = false;
+        Ok(())
+    }
+
+    /// The total points of every player over the rounds, which can be
+    /// negative.
+    pub fn round_totals(&self) -> HashMap<String, i64> {
+        self.scores
+            .keys()
+            .map(|player| {
+                let total = self
+                    .get_rounds()
+                    .iter()
+                    .map(|round| round.get(player).copied().unwrap_or(0))
+                    .sum();
+                (player.clone(), total)
+            })
+            .collect()
+    }
+
+    pub fn get_rounds(&self) -> &[BTreeMap<String, i64>] {
+        self.rounds.as_deref().unwrap_or_default()
+    }
+
+    /// Appends a round and updates the scores to the new totals. Players
+    /// left out of the round score nothing in it.
+    pub fn add_round(&mut self, round: BTreeMap<String, i64>) -> Result<(), String> {
+        let Some(rounds) = &mut self.rounds else {
+            return Err(format!("Match {} is not scored by rounds.", self.id));
+        };
+        if !self.in_progress {
+            return Err(format!("Match {} is already finished.", self.id));
+        }
+        if let Some(player) = round.keys().find(|p| !self.scores.contains_key(*p)) {
+            return Err(format!("Player {} is not in match {}.", player, self.id));
+        }
+
+        rounds.push(
+            self.scores
+                .keys()
+                .map(|player| (player.clone(), round.get(player).copied().unwrap_or(0)))
+                .collect(),
+        );
+        self.scores = self.round_totals();
+        Ok(())
+    }
+
+    /// The points of every player in each round, with the running total.
+    pub fn to_round_rows(&self) -> Vec<RoundRow> {
+        let mut rows = self
+            .round_totals()
+            .into_iter()
+            .map(|(player, total)| RoundRow {
+                player: player.clone(),
+                rounds: self
+                    .get_rounds()
+                    .iter()
+                    .map(|round| round.get(&player).copied().unwrap_or(0).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                total,
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| a.player.cmp(&b.player));
+        rows
+    }
+
+    /// Records the scores of `player` under `new_name`, as when a name
+    /// entered is resolved to the canonical player name.
+    pub fn rename_player(&mut self, player: &str, new_name: &str) -> Result<(), String> {
+        if player == new_name {
+            return Ok(());
+        }
+        if self.scores.contains_key(new_name) {
+            return Err(format!("Player {} is listed more than once.", new_name));
+        }
+        let Some(score) = self.scores.remove(player) else {
+            return Err(format!("Player {} is not in match {}.", player, self.id));
+        };
+        self.scores.insert(new_name.to_string(), score);
+        if let Some(breakdown) = self.breakdown.remove(player) {
+            self.breakdown.insert(new_name.to_string(), breakdown);
+        }
+        for round in self.rounds.iter_mut().flatten() {
+            if let Some(points) = round.remove(player) {
+                round.insert(new_name.to_string(), points);
+            }
+        }
+        for winner in self.winners.iter_mut().filter(|winner| *winner == player) {
+            *winner = new_name.to_string();
+        }
+        Ok(())
+    }
+
+    /// Another record of the same match, with a new id.
+    pub fn copy(&self) -> Game {
+        Game {
+            id: Uuid::new_v4(),
+            ..self.clone()
+        }
     }
 
     pub fn get_datetime(&self) -> &NaiveDate {
@@ -94,20 +386,220 @@ impl Game {
         &self.game_name
     }
 
+    pub fn set_name(&mut self, game_name: String) {
+        self.game_name = game_name;
+    }
+
     pub fn get_id(&self) -> &Uuid {
         &self.id
     }
 
-    pub fn get_scores(&self) -> &HashMap<String, usize> {
+    pub fn get_scores(&self) -> &HashMap<String, i64> {
         &self.scores
     }
+
+    pub fn set_tournament(&mut self, tournament: Uuid) {
+        self.tournament = Some(tournament);
+    }
+
+    pub fn get_breakdown(&self) -> &Breakdown {
+        &self.breakdown
+    }
+
+    pub fn get_session(&self) -> Option<&str> {
+        self.session.as_deref()
+    }
+
+    pub fn set_session(&mut self, session: String) {
+        self.session = Some(session);
+    }
+
+    pub fn get_details(&self) -> &MatchDetails {
+        &self.details
+    }
+
+    pub fn set_details(&mut self, details: MatchDetails) -> Result<(), String> {
+        if let Some(start) = details.start
+            && start.date_naive() != self.time
+        {
+            return Err(format!(
+                "The match cannot start on {} when it is dated {}.",
+                start.date_naive(),
+                self.time
+            ));
+        }
+
+        self.details = details;
+        Ok(())
+    }
+
+    /// Two records describe the same match when game, date and scores are equal,
+    /// regardless of their ids.
+    pub fn is_same_match(&self, other: &Game) -> bool {
+        self.game_name == other.game_name && self.time == other.time && self.scores == other.scores
+    }
+
+    /// The recorded winners or, when none were recorded, the players sharing
+    /// the highest score, sorted by name.
+    pub fn get_winners(&self) -> Vec<String> {
+        let mut winners = if !self.winners.is_empty() {
+            self.winners.clone()
+        } else {
+            let max_score = self.scores.values().max();
+            self.scores
+                .iter()
+                .filter(|(_, score)| Some(*score) == max_score)
+                .map(|(player, _)| player.clone())
+                .collect()
+        };
+        winners.sort();
+        winners
+    }
+
+    /// How `player` finished against `opponent`, or `None` if they did not
+    /// both play. Recorded winners take precedence over the scores, since
+    /// some games are won with the lowest score.
+    pub fn compare_players(&self, player: &str, opponent: &str) -> Option<Ordering> {
+        let player_score = self.scores.get(player)?;
+        let opponent_score = self.scores.get(opponent)?;
+
+        let player_won = self.winners.iter().any(|w| w == player);
+        let opponent_won = self.winners.iter().any(|w| w == opponent);
+        if player_won != opponent_won {
+            return Some(if player_won {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            });
+        }
+
+        Some(player_score.cmp(opponent_score))
+    }
+
+    /// The placement of every player, starting from 1. Tied players share
+    /// the same placement.
+    pub fn get_ranks(&self) -> HashMap<String, usize> {
+        self.scores
+            .keys()
+            .map(|player| {
+                let ahead = self
+                    .scores
+                    .keys()
+                    .filter(|other| self.compare_players(other, player) == Some(Ordering::Greater))
+                    .count();
+                (player.clone(), ahead + 1)
+            })
+            .collect()
+    }
 }
 
-impl Games {
-    pub fn from_games(games: HashMap<Uuid, Game>) -> Self {
-        Self { games: games }
+impl MatchDetails {
+    pub fn build(
+        start: Option<&str>,
+        duration: Option<u32>,
+        location: Option<String>,
+        notes: Option<String>,
+        tags: Option<&str>,
+    ) -> Result<MatchDetails, String> {
+        let start = start
+            .map(|start| {
+                DateTime::parse_from_rfc3339(start)
+                    .or_else(|_| DateTime::parse_from_str(start, "%Y-%m-%dT%H:%M%:z"))
+                    .map_err(|_| {
+                        "Error parsing start. The input format is YYYY-MM-DDTHH:MM+HH:MM."
+                            .to_string()
+                    })
+            })
+            .transpose()?;
+        let mut parsed_tags = Vec::new();
+        for tag in tags.unwrap_or_default().split(',').map(|t| t.trim()) {
+            if !tag.is_empty() && !parsed_tags.iter().any(|t| t == tag) {
+                parsed_tags.push(tag.to_string());
+            }
+        }
+
+        Ok(MatchDetails {
+            start,
+            duration,
+            location,
+            notes,
+            tags: parsed_tags,
+        })
+    }
+
+    pub fn get_start(&self) -> Option<&DateTime<FixedOffset>> {
+        self.start.as_ref()
+    }
+
+    pub fn get_duration(&self) -> Option<u32> {
+        self.duration
+    }
+
+    /// One line per detail, for tables.
+    pub fn describe(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(start) = self.start {
+            lines.push(format!("started {}", start.format("%H:%M %:z")));
+        }
+        if let Some(duration) = self.duration {
+            lines.push(format!("took {}", format_duration(duration)));
+        }
+        if let Some(location) = &self.location {
+            lines.push(format!("at {}", location));
+        }
+        if !self.tags.is_empty() {
+            lines.push(
+                self.tags
+                    .iter()
+                    .map(|tag| format!("#{}", tag))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+        }
+        if let Some(notes) = &self.notes {
+            lines.push(notes.clone());
+        }
+        lines.join("\n")
+    }
+}
+
+/// Parses a duration in minutes, such as `90`, or in hours and minutes, such
+/// as `3h` or `1h30m`.
+pub fn parse_duration(duration: &str) -> Result<u32, String> {
+    let error = || {
+        format!(
+            "Invalid duration {}. Use minutes, or hours and minutes like 1h30m.",
+            duration
+        )
+    };
+
+    if let Ok(minutes) = duration.parse::<u32>() {
+        return Ok(minutes);
     }
+    let (hours, minutes) = match duration.split_once('h') {
+        Some((hours, minutes)) => (hours, minutes.strip_suffix('m').unwrap_or(minutes)),
+        None => ("0", duration.strip_suffix('m').ok_or_else(error)?),
+    };
+    let hours = hours.parse::<u32>().map_err(|_| error())?;
+    let minutes = match minutes {
+        "" => 0,
+        minutes => minutes.parse::<u32>().map_err(|_| error())?,
+    };
+    hours
+        .checked_mul(60)
+        .and_then(|h| h.checked_add(minutes))
+        .ok_or_else(error)
+}
+
+pub fn format_duration(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {:02}m", hours, minutes),
+    }
+}
 
+impl Games {
     pub fn create_empy() -> Self {
         Self {
             games: HashMap::new(),
@@ -123,14 +615,49 @@ impl Games {
             games
                 .get_games()
                 .iter()
-                .map(|(id, game)| (id.clone(), game.clone())),
+                .map(|(id, game)| (*id, game.clone())),
         )
     }
 
+    pub fn contains_match(&self, game: &Game) -> bool {
+        self.games.values().any(|g| g.is_same_match(game))
+    }
+
     pub fn add_game(&mut self, game: Game) {
         self.games.insert(*game.get_id(), game);
     }
 
+    /// The games of `game_name`.
+    pub fn with_name(&self, game_name: &str) -> Games {
+        self.filter(|game| game.game_name == game_name)
+    }
+
+    /// The games that count in ratings and statistics, without the matches
+    /// still in progress.
+    pub fn finished(&self) -> Games {
+        self.filter(|game| !game.in_progress)
+    }
+
+    /// The games played up to and including `date`.
+    pub fn played_until(&self, date: NaiveDate) -> Games {
+        self.filter(|game| game.time <= date)
+    }
+
+    fn filter(&self, keep: impl Fn(&Game) -> bool) -> Games {
+        Games {
+            games: self
+                .games
+                .iter()
+                .filter(|(_, game)| keep(game))
+                .map(|(id, game)| (*id, game.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn get_mut(&mut self, game_id: &Uuid) -> Option<&mut Game> {
+        self.games.get_mut(game_id)
+    }
+
     pub fn delete(&mut self, game_id: Uuid) -> Result<Game, String> {
         if let Some(game) = self.games.remove(&game_id) {
             Ok(game)
@@ -139,19 +666,18 @@ impl Games {
         }
     }
 
+    /// Every game in the order it was played: by date and start time, then
+    /// in the order the games were recorded. The id settles the remaining
+    /// ties, so that the order is the same on every run.
     pub fn order_by_date(&self) -> Vec<Game> {
-        let mut games = self
-            .games
-            .iter()
-            .map(|(_id, game)| game.clone())
-            .collect::<Vec<_>>();
-        games.sort_by_key(|g| g.time);
+        let mut games = self.games.values().cloned().collect::<Vec<_>>();
+        games.sort_by_key(|g| (g.time, g.details.start, g.recorded_at, g.id));
         games
     }
 }
 
 pub fn from_vec_to_game_rows(games: Vec<Game>) -> Vec<GameRow> {
-    games.iter().map(|game| GameRow::from(game)).collect()
+    games.iter().map(GameRow::from).collect()
 }
 
 #[cfg(test)]
@@ -190,16 +716,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_same_match_ignores_ids() {
+        let scores = vec!["player1::10".to_string(), "player2::20".to_string()];
+        let first = Game::build("catan".to_string(), scores.clone(), Some("2025-01-01")).unwrap();
+        let second = Game::build("catan".to_string(), scores, Some("2025-01-01")).unwrap();
+        assert_ne!(first.get_id(), second.get_id());
+        assert!(first.is_same_match(&second));
+    }
+
+    #[test]
+    fn get_winners_from_scores() {
+        let scores = vec![
+            "alice::20".to_string(),
+            "bob::20".to_string(),
+            "carol::10".to_string(),
+        ];
+        let game = Game::build("catan".to_string(), scores, Some("2025-01-01")).unwrap();
+        assert_eq!(game.get_winners(), vec!["alice", "bob"]);
+    }
+
+    #[test]
+    fn get_winners_prefers_recorded_winners() {
+        let scores = HashMap::from([("alice".to_string(), 10), ("bob".to_string(), 20)]);
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let game = Game::new("golf".to_string(), scores, date, vec!["alice".to_string()]);
+        assert_eq!(game.get_winners(), vec!["alice"]);
+    }
+
+    #[test]
+    fn get_ranks_shares_ties() {
+        let scores = vec![
+            "alice::20".to_string(),
+            "bob::20".to_string(),
+            "carol::10".to_string(),
+        ];
+        let game = Game::build("catan".to_string(), scores, Some("2025-01-01")).unwrap();
+        let ranks = game.get_ranks();
+        assert_eq!(ranks["alice"], 1);
+        assert_eq!(ranks["bob"], 1);
+        assert_eq!(ranks["carol"], 3);
+    }
+
+    #[test]
+    fn compare_players_uses_scores() {
+        let scores = vec!["alice::10".to_string(), "bob::20".to_string()];
+        let game = Game::build("catan".to_string(), scores, Some("2025-01-01")).unwrap();
+        assert_eq!(game.compare_players("alice", "bob"), Some(Ordering::Less));
+        assert_eq!(
+            game.compare_players("bob", "alice"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(game.compare_players("alice", "carol"), None);
+    }
+
+    #[test]
+    fn compare_players_prefers_recorded_winners() {
+        let scores = HashMap::from([("alice".to_string(), 10), ("bob".to_string(), 20)]);
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let game = Game::new("golf".to_string(), scores, date, vec!["alice".to_string()]);
+        assert_eq!(
+            game.compare_players("alice", "bob"),
+            Some(Ordering::Greater)
+        );
+    }
+
     #[test]
     fn test_parse_scores_valid_input() {
         let input = vec!["alice::10".to_string(), "bob::20".to_string()];
         let result = Game::parse_scores(&input);
         assert!(result.is_ok());
-        let map = result.unwrap();
+        let (map, breakdown) = result.unwrap();
+        assert!(breakdown.is_empty());
         assert_eq!(map.get("alice"), Some(&10));
         assert_eq!(map.get("bob"), Some(&20));
     }
 
+    #[test]
+    fn test_parse_scores_negative_score() {
+        let input = vec!["alice::-5".to_string(), "bob::3".to_string()];
+        let (map, _) = Game::parse_scores(&input).unwrap();
+        assert_eq!(map.get("alice"), Some(&-5));
+    }
+
+    #[test]
+    fn test_parse_scores_with_guest() {
+        let input = vec!["alice::10".to_string(), "guest:Marta::12".to_string()];
+        let (map, _) = Game::parse_scores(&input).unwrap();
+        assert_eq!(map.get("guest:Marta"), Some(&12));
+
+        let input = vec!["guest: ::12".to_string()];
+        assert_eq!(
+            Game::parse_scores(&input).unwrap_err(),
+            "The guest name cannot be empty."
+        );
+    }
+
     #[test]
     fn test_parse_scores_invalid_format_single_colon() {
         let input = vec!["alice:10".to_string()];
@@ -227,12 +839,12 @@ mod tests {
         let input: Vec<String> = vec![];
         let result = Game::parse_scores(&input);
         assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(result.unwrap().0.is_empty());
     }
 
     #[test]
     fn test_parse_scores_too_many_colons() {
-        let input = vec!["alice::10::bonus".to_string()];
+        let input = vec!["alice::10::bonus=10::extra".to_string()];
         let result = Game::parse_scores(&input);
         assert!(result.is_err());
         assert_eq!(
@@ -240,4 +852,232 @@ mod tests {
             "Error parsing scores. The input format is player::score."
         );
     }
+
+    #[test]
+    fn test_parse_scores_with_breakdown() {
+        let input = vec![
+            "alice::45::military=10,science=20,coins=15".to_string(),
+            "bob::30".to_string(),
+        ];
+        let (map, breakdown) = Game::parse_scores(&input).unwrap();
+        assert_eq!(map.get("alice"), Some(&45));
+        assert_eq!(breakdown["alice"]["science"], 20);
+        assert!(!breakdown.contains_key("bob"));
+    }
+
+    #[test]
+    fn test_parse_scores_breakdown_must_add_up() {
+        let input = vec!["alice::45::military=10,science=20".to_string()];
+        assert_eq!(
+            Game::parse_scores(&input).unwrap_err(),
+            "The categories of alice add up to 30, not 45."
+        );
+
+        let input = vec!["alice::10::bonus".to_string()];
+        assert_eq!(
+            Game::parse_scores(&input).unwrap_err(),
+            "Error parsing the breakdown of alice. The input format is player::score::category=points,category=points."
+        );
+    }
+
+    #[test]
+    fn add_round_updates_totals() {
+        let scores = vec!["alice::7".to_string(), "bob::3".to_string()];
+        let mut game = Game::build("skull king".to_string(), scores, Some("2025-01-01")).unwrap();
+        let round = Game::parse_round(&["alice::20".to_string()]).unwrap();
+        assert_eq!(
+            game.add_round(round.clone()).unwrap_err(),
+            format!("Match {} is not scored by rounds.", game.id)
+        );
+
+        game.start_rounds();
+        game.add_round(round).unwrap();
+        game.add_round(
+            Game::parse_round(&["alice::-10".to_string(), "bob::30".to_string()]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(game.scores["alice"], 10);
+        assert_eq!(game.scores["bob"], 30);
+        assert_eq!(game.get_rounds().len(), 2);
+        assert_eq!(game.get_rounds()[0]["bob"], 0);
+        assert_eq!(game.get_winners(), vec!["bob"]);
+    }
+
+    #[test]
+    fn add_round_rejects_unknown_players() {
+        let scores = vec!["alice::0".to_string(), "bob::0".to_string()];
+        let mut game = Game::build("skull king".to_string(), scores, Some("2025-01-01")).unwrap();
+        game.start_rounds();
+
+        let round = Game::parse_round(&["carol::10".to_string()]).unwrap();
+        assert_eq!(
+            game.add_round(round).unwrap_err(),
+            format!("Player carol is not in match {}.", game.id)
+        );
+        assert!(game.get_rounds().is_empty());
+        assert!(Game::parse_round(&["alice:10".to_string()]).is_err());
+    }
+
+    #[test]
+    fn add_round_keeps_negative_totals() {
+        let scores = vec!["alice::0".to_string(), "bob::0".to_string()];
+        let mut game = Game::build("skull king".to_string(), scores, Some("2025-01-01")).unwrap();
+        game.start_rounds();
+
+        game.add_round(
+            Game::parse_round(&["alice::-30".to_string(), "bob::-10".to_string()]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(game.round_totals()["alice"], -30);
+        assert_eq!(game.round_totals()["bob"], -10);
+        assert_eq!(game.scores["alice"], -30);
+        assert_eq!(game.scores["bob"], -10);
+        assert_eq!(game.get_winners(), vec!["bob"]);
+
+        game.add_round(Game::parse_round(&["alice::50".to_string()]).unwrap())
+            .unwrap();
+        assert_eq!(game.scores["alice"], 20);
+        assert_eq!(game.scores["bob"], -10);
+        assert_eq!(game.get_winners(), vec!["alice"]);
+        let rows = game.to_round_rows();
+        assert_eq!(rows[0].total, 20);
+        assert_eq!(rows[1].total, -10);
+    }
+
+    #[test]
+    fn finished_match_takes_no_more_rounds() {
+        let scores = vec!["alice::0".to_string(), "bob::0".to_string()];
+        let mut game = Game::build("skull king".to_string(), scores, Some("2025-01-01")).unwrap();
+        assert_eq!(
+            game.finish_rounds().unwrap_err(),
+            format!("Match {} is not scored by rounds.", game.id)
+        );
+
+        game.start_rounds();
+        let mut games = Games::create_empy();
+        games.add_game(game.clone());
+        assert!(games.finished().get_games().is_empty());
+
+        game.finish_rounds().unwrap();
+        assert_eq!(
+            game.finish_rounds().unwrap_err(),
+            format!("Match {} is already finished.", game.id)
+        );
+        let round = Game::parse_round(&["alice::10".to_string()]).unwrap();
+        assert_eq!(
+            game.add_round(round).unwrap_err(),
+            format!("Match {} is already finished.", game.id)
+        );
+        games.add_game(game);
+        assert_eq!(games.finished().get_games().len(), 1);
+    }
+
+    #[test]
+    fn match_details_parse_start_and_tags() {
+        let details = MatchDetails::build(
+            Some("2025-08-07T20:30+02:00"),
+            Some(180),
+            Some("Marco's place".to_string()),
+            None,
+            Some("evening, rematch, evening"),
+        )
+        .unwrap();
+
+        assert_eq!(details.tags, vec!["evening", "rematch"]);
+        assert_eq!(
+            details.describe(),
+            "started 20:30 +02:00\ntook 3h\nat Marco's place\n#evening #rematch"
+        );
+    }
+
+    #[test]
+    fn set_details_rejects_start_on_other_day() {
+        let mut game = Game::build(
+            "catan".to_string(),
+            vec!["alice::10".to_string()],
+            Some("2025-08-08"),
+        )
+        .unwrap();
+        let details =
+            MatchDetails::build(Some("2025-08-07T20:30:00+02:00"), None, None, None, None).unwrap();
+
+        assert_eq!(
+            game.set_details(details).unwrap_err(),
+            "The match cannot start on 2025-08-07 when it is dated 2025-08-08."
+        );
+    }
+
+    #[test]
+    fn rename_player_moves_scores_and_breakdown() {
+        let mut game = Game::build(
+            "7 Wonders".to_string(),
+            vec![
+                "Gio::45::coins=10,military=35".to_string(),
+                "emma00::40".to_string(),
+            ],
+            Some("2025-08-07"),
+        )
+        .unwrap();
+
+        game.rename_player("Gio", "giovi98").unwrap();
+        assert_eq!(game.get_scores()["giovi98"], 45);
+        assert!(!game.get_scores().contains_key("Gio"));
+        assert_eq!(game.get_breakdown()["giovi98"]["military"], 35);
+        assert_eq!(
+            game.rename_player("giovi98", "emma00").unwrap_err(),
+            "Player emma00 is listed more than once."
+        );
+    }
+
+    #[test]
+    fn parse_and_format_durations() {
+        assert_eq!(parse_duration("90").unwrap(), 90);
+        assert_eq!(parse_duration("45m").unwrap(), 45);
+        assert_eq!(parse_duration("3h").unwrap(), 180);
+        assert_eq!(parse_duration("1h30m").unwrap(), 90);
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("h").is_err());
+
+        assert_eq!(format_duration(45), "45m");
+        assert_eq!(format_duration(180), "3h");
+        assert_eq!(format_duration(95), "1h 35m");
+    }
+
+    #[test]
+    fn order_by_date_is_stable_within_a_day() {
+        let mut games = Games::create_empy();
+        for (id, score, recorded_at) in [
+            (1, 3, Some("2025-01-01T21:00:00Z")),
+            (2, 1, Some("2025-01-01T19:00:00Z")),
+            (3, 2, Some("2025-01-01T20:00:00Z")),
+            (4, 0, None),
+        ] {
+            let mut game = serde_json::json!({
+                "id": format!("00000000-0000-0000-0000-00000000000{}", id),
+                "game_name": "catan",
+                "scores": {"alice": score},
+                "time": "2025-01-01",
+            });
+            if let Some(recorded_at) = recorded_at {
+                game["recorded_at"] = serde_json::json!(recorded_at);
+            }
+            games.add_game(serde_json::from_value(game).unwrap());
+        }
+
+        let scores = games
+            .order_by_date()
+            .iter()
+            .map(|game| game.scores["alice"])
+            .collect::<Vec<_>>();
+        assert_eq!(scores, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn games_without_details_still_load() {
+        let json = r#"{"id":"849cf74e-0e19-45ce-a630-99916cb3b648","game_name":"catan","scores":{"alice":10},"time":"2025-08-07"}"#;
+
+        let game: Game = serde_json::from_str(json).unwrap();
+        assert_eq!(game.details, MatchDetails::default());
+        assert_eq!(serde_json::to_string(&game).unwrap(), json);
+    }
 }
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const FILE_NAME_DATA: &str = "game_index.json";
+
+/// Longest slug, before a suffix is added to make it unique.
+const MAX_SLUG_LENGTH: usize = 64;
+/// Names Windows does not allow as file names, whatever the extension.
+const RESERVED_NAMES: [&str; 22] = [
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Maps the file of each game, under `games/`, to the game name. File names
+/// are slugs, unique regardless of case, so that any game name can be stored.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct GameIndex {
+    files: BTreeMap<String, String>,
+}
+
+/// A file name for `game_name` made of ASCII letters, digits, `-` and `_`.
+/// Case is kept. Runs of other characters become a single `-`.
+pub fn slugify(game_name: &str) -> String {
+    let mut slug = String::new();
+    for c in game_name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let mut slug = slug
+        .trim_matches('-')
+        .chars()
+        .take(MAX_SLUG_LENGTH)
+        .collect::<String>()
+        .trim_end_matches('-')
+        .to_string();
+
+    if slug.is_empty() {
+        slug = "game".to_string();
+    }
+    if RESERVED_NAMES.contains(&slug.to_lowercase().as_str()) {
+        slug.push_str("-game");
+    }
+    slug
+}
+
+/// Whether `stem` can be used as is as the file name of a game.
+pub fn is_slug(stem: &str) -> bool {
+    slugify(stem) == stem
+}
+
+impl GameIndex {
+    pub fn create_empty() -> GameIndex {
+        GameIndex::default()
+    }
+
+    pub fn get_slug(&self, game_name: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(_, name)| *name == game_name)
+            .map(|(slug, _)| slug.as_str())
+    }
+
+    pub fn get_name(&self, slug: &str) -> Option<&String> {
+        self.files.get(slug)
+    }
+
+    pub fn get_names(&self) -> impl Iterator<Item = &String> {
+        self.files.values()
+    }
+
+    pub fn contains_slug(&self, slug: &str) -> bool {
+        self.files.keys().any(|s| s.eq_ignore_ascii_case(slug))
+    }
+
+    /// Registers `slug` for `game_name`, as found on disk.
+    pub fn insert(&mut self, slug: String, game_name: String) {
+        self.files.insert(slug, game_name);
+    }
+
+    /// The slug of `game_name`, allocating a new one if needed. Returns
+    /// whether the index changed.
+    pub fn get_or_add(&mut self, game_name: &str) -> (String, bool) {
+        if let Some(slug) = self.get_slug(game_name) {
+            return (slug.to_string(), false);
+        }
+
+        let base = slugify(game_name);
+        let mut slug = base.clone();
+        let mut suffix = 2;
+        while self.contains_slug(&slug) {
+            slug = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        self.files.insert(slug.clone(), game_name.to_string());
+        (slug, true)
+    }
+
+    /// Takes the slugs of `other` for the game names this index does not
+    /// know about, when they are safe and still free. Returns whether the
+    /// index changed.
+    pub fn adopt(&mut self, other: &GameIndex) -> bool {
+        let mut changed = false;
+        for (slug, game_name) in &other.files {
+            if self.get_slug(game_name).is_none() && is_slug(slug) && !self.contains_slug(slug) {
+                self.files.insert(slug.clone(), game_name.clone());
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    pub fn remove(&mut self, game_name: &str) -> Option<String> {
+        let slug = self.get_slug(game_name)?.to_string();
+        self.files.remove(&slug);
+        Some(slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_replaces_unsafe_characters() {
+        assert_eq!(slugify("Catan"), "Catan");
+        assert_eq!(slugify("Ticket to Ride: Europe"), "Ticket-to-Ride-Europe");
+        assert_eq!(slugify("../../etc/passwd"), "etc-passwd");
+        assert_eq!(slugify("7 Wonders_Duel"), "7-Wonders_Duel");
+        assert_eq!(slugify("Café"), "Caf");
+        assert_eq!(slugify("将棋"), "game");
+        assert_eq!(slugify("  "), "game");
+        assert_eq!(slugify("CON"), "CON-game");
+    }
+
+    #[test]
+    fn slugify_limits_length() {
+        let slug = slugify(&"a".repeat(100));
+        assert_eq!(slug.len(), MAX_SLUG_LENGTH);
+    }
+
+    #[test]
+    fn adopt_skips_known_names_and_unsafe_slugs() {
+        let mut index = GameIndex::create_empty();
+        index.get_or_add("Catan");
+        let mut other = GameIndex::create_empty();
+        other.insert("catan-old".to_string(), "Catan".to_string());
+        other.insert("CATAN".to_string(), "Azul".to_string());
+        other.insert("../x".to_string(), "Root".to_string());
+        other.insert("Brass".to_string(), "Brass: Birmingham".to_string());
+
+        index.adopt(&other);
+        assert_eq!(index.get_slug("Catan"), Some("Catan"));
+        assert_eq!(index.get_slug("Azul"), None);
+        assert_eq!(index.get_slug("Root"), None);
+        assert_eq!(index.get_slug("Brass: Birmingham"), Some("Brass"));
+    }
+
+    #[test]
+    fn get_or_add_avoids_collisions_ignoring_case() {
+        let mut index = GameIndex::create_empty();
+
+        assert_eq!(index.get_or_add("Catan"), ("Catan".to_string(), true));
+        assert_eq!(index.get_or_add("catan"), ("catan-2".to_string(), true));
+        assert_eq!(index.get_or_add("catan?"), ("catan-3".to_string(), true));
+        assert_eq!(index.get_or_add("catan"), ("catan-2".to_string(), false));
+    }
+
+    #[test]
+    fn remove_frees_slug() {
+        let mut index = GameIndex::create_empty();
+        index.get_or_add("Catan");
+
+        assert_eq!(index.remove("Catan"), Some("Catan".to_string()));
+        assert!(!index.contains_slug("catan"));
+        assert_eq!(index.remove("Catan"), None);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::commands::models::game::Game;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use tabled::Tabled;
+use uuid::Uuid;
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct PlayerRecord {
+    name: String,
+    wins: usize,
+    average_margin: f64,
+    longest_streak: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Encounter {
+    id: Uuid,
+    game_name: String,
+    date: NaiveDate,
+    scores: BTreeMap<String, i64>,
+    /// `None` when the two players tied.
+    winner: Option<String>,
+}
+
+/// The record of two players in the matches they both played.
+#[derive(Serialize, Debug)]
+pub struct HeadToHead {
+    encounters: usize,
+    ties: usize,
+    players: [PlayerRecord; 2],
+    last_encounters: Vec<Encounter>,
+}
+
+#[derive(Tabled)]
+pub struct PlayerRecordRow {
+    player: String,
+    wins: usize,
+    losses: usize,
+    ties: usize,
+    average_margin: String,
+    longest_streak: usize,
+}
+
+#[derive(Tabled)]
+pub struct EncounterRow {
+    date: String,
+    game: String,
+    id: String,
+    scores: String,
+    winner: String,
+}
+
+impl HeadToHead {
+    /// Compares `player` and `opponent` over `games`, which must be ordered
+    /// by date. Only the `last` most recent encounters are kept.
+    pub fn compute(games: &[Game], player: &str, opponent: &str, last: usize) -> HeadToHead {
+        let encounters = games
+            .iter()
+            .filter_map(|game| {
+                game.compare_players(player, opponent)
+                    .map(|ordering| (game, ordering))
+            })
+            .collect::<Vec<_>>();
+
+        let record = |name: &str, other: &str, wanted: Ordering| {
+            let wins = encounters.iter().filter(|(_, o)| *o == wanted).count();
+            let total_margin = encounters
+                .iter()
+                .map(|(game, _)| game.get_scores()[name] as f64 - game.get_scores()[other] as f64)
+                .sum::<f64>();
+            let mut longest_streak = 0;
+            let mut streak = 0;
+            for (_, ordering) in &encounters {
+                streak = if *ordering == wanted { streak + 1 } else { 0 };
+                longest_streak = longest_streak.max(streak);
+            }
+
+            PlayerRecord {
+                name: name.to_string(),
+                wins,
+                average_margin: if encounters.is_empty() {
+                    0.0
+                } else {
+                    total_margin / encounters.len() as f64
+                },
+                longest_streak,
+            }
+        };
+
+        let last_encounters = encounters
+            .iter()
+            .rev()
+            .take(last)
+            .map(|(game, ordering)| Encounter {
+                id: *game.get_id(),
+                game_name: game.get_name().to_string(),
+                date: *game.get_datetime(),
+                scores: BTreeMap::from([
+                    (player.to_string(), game.get_scores()[player]),
+                    (opponent.to_string(), game.get_scores()[opponent]),
+                ]),
+                winner: match ordering {
+                    Ordering::Greater => Some(player.to_string()),
+                    Ordering::Less => Some(opponent.to_string()),
+                    Ordering::Equal => None,
+                },
+            })
+            .collect();
+
+        HeadToHead {
+            encounters: encounters.len(),
+            ties: encounters
+                .iter()
+                .filter(|(_, o)| *o == Ordering::Equal)
+                .count(),
+            players: [
+                record(player, opponent, Ordering::Greater),
+                record(opponent, player, Ordering::Less),
+            ],
+            last_encounters,
+        }
+    }
+
+    pub fn get_encounters(&self) -> usize {
+        self.encounters
+    }
+
+    pub fn to_player_rows(&self) -> Vec<PlayerRecordRow> {
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(index, record)| PlayerRecordRow {
+                player: record.name.clone(),
+                wins: record.wins,
+                losses: self.players[1 - index].wins,
+                ties: self.ties,
+                average_margin: format!("{:+.2}", record.average_margin),
+                longest_streak: record.longest_streak,
+            })
+            .collect()
+    }
+
+    pub fn to_encounter_rows(&self) -> Vec<EncounterRow> {
+        self.last_encounters
+            .iter()
+            .map(|encounter| EncounterRow {
+                date: encounter.date.to_string(),
+                game: encounter.game_name.clone(),
+                id: encounter.id.to_string(),
+                scores: encounter
+                    .scores
+                    .iter()
+                    .map(|(player, score)| format!("{} {}", player, score))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                winner: encounter
+                    .winner
+                    .clone()
+                    .unwrap_or_else(|| "tie".to_string()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(scores: &[&str], date: &str) -> Game {
+        Game::build(
+            "catan".to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn create_games() -> Vec<Game> {
+        vec![
+            create_game(&["alice::10", "bob::8", "carol::12"], "2025-01-01"),
+            create_game(&["alice::10", "bob::10"], "2025-01-02"),
+            create_game(&["alice::5", "bob::9"], "2025-01-03"),
+            create_game(&["alice::7", "bob::3"], "2025-01-04"),
+            create_game(&["alice::9", "bob::1"], "2025-01-05"),
+            create_game(&["alice::9", "carol::1"], "2025-01-06"),
+        ]
+    }
+
+    #[test]
+    fn compute_counts_results() {
+        let head_to_head = HeadToHead::compute(&create_games(), "alice", "bob", 5);
+
+        assert_eq!(head_to_head.encounters, 5);
+        assert_eq!(head_to_head.ties, 1);
+        assert_eq!(head_to_head.players[0].wins, 3);
+        assert_eq!(head_to_head.players[1].wins, 1);
+        assert_eq!(head_to_head.players[0].longest_streak, 2);
+        assert_eq!(head_to_head.players[1].longest_streak, 1);
+        assert_eq!(head_to_head.players[0].average_margin, 2.0);
+        assert_eq!(head_to_head.players[1].average_margin, -2.0);
+    }
+
+    #[test]
+    fn compute_keeps_last_encounters_most_recent_first() {
+        let head_to_head = HeadToHead::compute(&create_games(), "alice", "bob", 2);
+
+        let dates = head_to_head
+            .last_encounters
+            .iter()
+            .map(|e| e.date.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(dates, vec!["2025-01-05", "2025-01-04"]);
+        assert_eq!(
+            head_to_head.last_encounters[0].winner,
+            Some("alice".to_string())
+        );
+    }
+
+    #[test]
+    fn compute_without_encounters() {
+        let head_to_head = HeadToHead::compute(&create_games(), "bob", "dave", 5);
+
+        assert_eq!(head_to_head.encounters, 0);
+        assert_eq!(head_to_head.players[0].average_margin, 0.0);
+        assert!(head_to_head.last_encounters.is_empty());
+    }
+}
@@ -0,0 +1,362 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+use tabled::Tabled;
+use uuid::Uuid;
+
+pub const JOURNAL_FILE_NAME: &str = "journal.jsonl";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalAction {
+    Mutation,
+    Undo,
+    Redo,
+}
+
+/// A value of a data file, relative to the save dir, before and after a
+/// journal entry. `pointer` is the JSON pointer of the value in the file; the
+/// empty pointer is the whole file. `None` means the value, or the file, did
+/// not exist.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FileChange {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pointer: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JournalEntry {
+    id: Uuid,
+    timestamp: DateTime<Utc>,
+    action: JournalAction,
+    command: String,
+    description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target: Option<Uuid>,
+    changes: Vec<FileChange>,
+}
+
+#[derive(Tabled)]
+pub struct HistoryRow {
+    number: usize,
+    time: String,
+    command: String,
+    description: String,
+    status: String,
+}
+
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+impl FileChange {
+    /// The changes turning `before` into `after`, two contents of the file at
+    /// `path`. Objects are compared key by key, so that only the values that
+    /// changed are recorded rather than the whole file.
+    pub fn diff(path: &str, before: Option<&Value>, after: Option<&Value>) -> Vec<FileChange> {
+        let mut changes = Vec::new();
+        diff_values(path, String::new(), before, after, &mut changes);
+        changes
+    }
+
+    pub fn inverse(&self) -> FileChange {
+        FileChange {
+            path: self.path.clone(),
+            pointer: self.pointer.clone(),
+            before: self.after.clone(),
+            after: self.before.clone(),
+        }
+    }
+
+    /// Applies the change to `content`, the current content of its file.
+    /// Fails if the value it replaces is not its `before` side.
+    pub fn apply(&self, content: &mut Option<Value>) -> Result<(), String> {
+        let modified = || format!("File {} was modified outside of score-cli.", self.path);
+        let current = content
+            .as_ref()
+            .and_then(|content| content.pointer(&self.pointer));
+        if current != self.before.as_ref() {
+            return Err(modified());
+        }
+
+        let Some((parent, key)) = self.pointer.rsplit_once('/') else {
+            *content = self.after.clone();
+            return Ok(());
+        };
+        let key = key.replace("~1", "/").replace("~0", "~");
+        let parent = content
+            .as_mut()
+            .and_then(|content| content.pointer_mut(parent))
+            .and_then(Value::as_object_mut)
+            .ok_or_else(modified)?;
+        match &self.after {
+            Some(value) => parent.insert(key, value.clone()),
+            None => parent.remove(&key),
+        };
+        Ok(())
+    }
+}
+
+fn diff_values(
+    path: &str,
+    pointer: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<FileChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            for key in before.keys().chain(after.keys()).collect::<BTreeSet<_>>() {
+                diff_values(
+                    path,
+                    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1")),
+                    before.get(key),
+                    after.get(key),
+                    changes,
+                );
+            }
+        }
+        (before, after) if before != after => changes.push(FileChange {
+            path: path.to_string(),
+            pointer,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+impl JournalEntry {
+    pub fn mutation(command: &str, description: String, changes: Vec<FileChange>) -> JournalEntry {
+        JournalEntry {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            action: JournalAction::Mutation,
+            command: command.to_string(),
+            description,
+            target: None,
+            changes,
+        }
+    }
+
+    /// An entry reverting `target`, carrying the inverse of its changes.
+    pub fn undo(target: &JournalEntry) -> JournalEntry {
+        JournalEntry {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            action: JournalAction::Undo,
+            command: "undo".to_string(),
+            description: format!("Undid: {}", target.description),
+            target: Some(target.id),
+            changes: target.changes.iter().map(FileChange::inverse).collect(),
+        }
+    }
+
+    /// An entry applying `target` again after it was undone.
+    pub fn redo(target: &JournalEntry) -> JournalEntry {
+        JournalEntry {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            action: JournalAction::Redo,
+            command: "redo".to_string(),
+            description: format!("Redid: {}", target.description),
+            target: Some(target.id),
+            changes: target.changes.clone(),
+        }
+    }
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn get_changes(&self) -> &[FileChange] {
+        &self.changes
+    }
+}
+
+impl Journal {
+    pub fn from_entries(entries: Vec<JournalEntry>) -> Journal {
+        Journal { entries }
+    }
+
+    /// Replays the journal and returns the mutations currently applied and
+    /// the ones that can be redone, most recent last.
+    fn replay(&self) -> (Vec<&JournalEntry>, Vec<&JournalEntry>) {
+        let mut applied: Vec<&JournalEntry> = Vec::new();
+        let mut undone: Vec<&JournalEntry> = Vec::new();
+
+        for entry in &self.entries {
+            match entry.action {
+                JournalAction::Mutation => {
+                    applied.push(entry);
+                    undone.clear();
+                }
+                JournalAction::Undo => {
+                    if let Some(target) = applied.pop() {
+                        undone.push(target);
+                    }
+                }
+                JournalAction::Redo => {
+                    if let Some(target) = undone.pop() {
+                        applied.push(target);
+                    }
+                }
+            }
+        }
+
+        (applied, undone)
+    }
+
+    pub fn next_undo(&self) -> Option<&JournalEntry> {
+        self.replay().0.last().copied()
+    }
+
+    pub fn next_redo(&self) -> Option<&JournalEntry> {
+        self.replay().1.last().copied()
+    }
+
+    pub fn to_history_rows(&self) -> Vec<HistoryRow> {
+        let applied = self
+            .replay()
+            .0
+            .iter()
+            .map(|entry| entry.id)
+            .collect::<HashSet<_>>();
+
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| HistoryRow {
+                number: index + 1,
+                time: entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                command: entry.command.clone(),
+                description: entry.description.clone(),
+                status: match entry.action {
+                    JournalAction::Mutation if applied.contains(&entry.id) => "applied",
+                    JournalAction::Mutation => "undone",
+                    _ => "",
+                }
+                .to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mutation(description: &str) -> JournalEntry {
+        JournalEntry::mutation(
+            "add-player",
+            description.to_string(),
+            vec![FileChange {
+                path: "players.json".to_string(),
+                pointer: String::new(),
+                before: None,
+                after: Some(serde_json::json!({"players": {}})),
+            }],
+        )
+    }
+
+    #[test]
+    fn undo_entry_inverts_changes() {
+        let mutation = create_mutation("first");
+        let undo = JournalEntry::undo(&mutation);
+
+        assert_eq!(undo.target, Some(mutation.id));
+        assert_eq!(undo.changes[0].before, mutation.changes[0].after);
+        assert_eq!(undo.changes[0].after, None);
+    }
+
+    #[test]
+    fn diff_records_changed_values_only() {
+        let before = serde_json::json!({"games": {"a/1": {"scores": {"alice": 10}}, "b": {}}});
+        let after = serde_json::json!({"games": {"a/1": {"scores": {"alice": 12}}, "c": {}}});
+
+        let changes = FileChange::diff("games/catan.json", Some(&before), Some(&after));
+        let pointers = changes
+            .iter()
+            .map(|change| change.pointer.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pointers,
+            ["/games/a~11/scores/alice", "/games/b", "/games/c"]
+        );
+
+        let mut content = Some(before.clone());
+        for change in &changes {
+            change.apply(&mut content).unwrap();
+        }
+        assert_eq!(content, Some(after));
+        for change in changes.iter().rev() {
+            change.inverse().apply(&mut content).unwrap();
+        }
+        assert_eq!(content, Some(before));
+    }
+
+    #[test]
+    fn diff_of_created_file_is_whole_file() {
+        let after = serde_json::json!({"players": {}});
+
+        let changes = FileChange::diff("players.json", None, Some(&after));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].pointer, "");
+
+        let mut content = Some(after);
+        assert_eq!(
+            changes[0].apply(&mut content).unwrap_err(),
+            "File players.json was modified outside of score-cli."
+        );
+    }
+
+    #[test]
+    fn replay_undo_and_redo() {
+        let first = create_mutation("first");
+        let second = create_mutation("second");
+        let undo = JournalEntry::undo(&second);
+
+        let journal = Journal::from_entries(vec![first.clone(), second.clone(), undo]);
+        assert_eq!(journal.next_undo().unwrap().id, first.id);
+        assert_eq!(journal.next_redo().unwrap().id, second.id);
+
+        let redo = JournalEntry::redo(&second);
+        let journal = Journal::from_entries(vec![
+            first,
+            second.clone(),
+            JournalEntry::undo(&second),
+            redo,
+        ]);
+        assert_eq!(journal.next_undo().unwrap().id, second.id);
+        assert!(journal.next_redo().is_none());
+    }
+
+    #[test]
+    fn new_mutation_clears_redo() {
+        let first = create_mutation("first");
+        let undo = JournalEntry::undo(&first);
+        let second = create_mutation("second");
+
+        let journal = Journal::from_entries(vec![first, undo, second.clone()]);
+        assert!(journal.next_redo().is_none());
+        assert_eq!(journal.next_undo().unwrap().id, second.id);
+
+        let statuses = journal
+            .to_history_rows()
+            .into_iter()
+            .map(|row| row.status)
+            .collect::<Vec<_>>();
+        assert_eq!(statuses, vec!["undone", "", "applied"]);
+    }
+
+    #[test]
+    fn empty_journal() {
+        let journal = Journal::from_entries(vec![]);
+        assert!(journal.next_undo().is_none());
+        assert!(journal.next_redo().is_none());
+    }
+}
@@ -0,0 +1,309 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::player;
+use crate::commands::models::streak;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::HashMap;
+use tabled::Tabled;
+use uuid::Uuid;
+
+#[derive(Serialize, Debug)]
+pub struct Standing {
+    player: String,
+    plays: usize,
+    wins: usize,
+    win_rate: f64,
+    average_score: f64,
+    form: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Record {
+    player: String,
+    value: i64,
+    match_id: Uuid,
+    date: NaiveDate,
+}
+
+/// All-time records of a game. A record is only broken by a strictly
+/// better value, so ties keep the earliest match.
+#[derive(Serialize, Debug, Default)]
+pub struct Records {
+    highest_score: Option<Record>,
+    lowest_winning_score: Option<Record>,
+    largest_margin: Option<Record>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Leaderboard {
+    game_name: String,
+    matches: usize,
+    min_plays: usize,
+    standings: Vec<Standing>,
+    records: Records,
+}
+
+#[derive(Tabled)]
+pub struct StandingRow {
+    rank: usize,
+    player: String,
+    plays: usize,
+    wins: usize,
+    win_rate: String,
+    average_score: String,
+    form: String,
+}
+
+#[derive(Tabled)]
+pub struct RecordRow {
+    record: String,
+    player: String,
+    value: i64,
+    date: String,
+    id: String,
+}
+
+impl Record {
+    fn new(player: &str, value: i64, game: &Game) -> Record {
+        Record {
+            player: player.to_string(),
+            value,
+            match_id: *game.get_id(),
+            date: *game.get_datetime(),
+        }
+    }
+}
+
+impl Leaderboard {
+    /// Ranks the players of `games`, ordered by date, by wins, then win rate
+    /// and average score. Players with fewer than `min_plays` matches are
+    /// left out of the standings but still hold records.
+    pub fn compute(game_name: &str, games: &[Game], min_plays: usize) -> Leaderboard {
+        let mut totals: HashMap<&str, (usize, usize, i64)> = HashMap::new();
+        let mut records = Records::default();
+
+        for game in games {
+            let winners = game.get_winners();
+            let mut scores = game.get_scores().iter().collect::<Vec<_>>();
+            scores.sort();
+            for (player, score) in scores {
+                let (plays, wins, total_score) = totals.entry(player).or_default();
+                *plays += 1;
+                *total_score += score;
+                if winners.contains(player) {
+                    *wins += 1;
+                }
+
+                if records
+                    .highest_score
+                    .as_ref()
+                    .is_none_or(|record| *score > record.value)
+                {
+                    records.highest_score = Some(Record::new(player, *score, game));
+                }
+            }
+
+            for winner in &winners {
+                let Some(score) = game.get_scores().get(winner) else {
+                    continue;
+                };
+                if records
+                    .lowest_winning_score
+                    .as_ref()
+                    .is_none_or(|record| *score < record.value)
+                {
+                    records.lowest_winning_score = Some(Record::new(winner, *score, game));
+                }
+            }
+
+            if let Some((winner, margin)) = margin_of_victory(game, &winners)
+                && records
+                    .largest_margin
+                    .as_ref()
+                    .is_none_or(|record| margin > record.value)
+            {
+                records.largest_margin = Some(Record::new(winner, margin, game));
+            }
+        }
+
+        let streaks = streak::compute(games);
+        let mut standings = totals
+            .into_iter()
+            .filter(|(_, (plays, _, _))| *plays >= min_plays)
+            .map(|(player, (plays, wins, total_score))| Standing {
+                player: player.to_string(),
+                plays,
+                wins,
+                win_rate: wins as f64 / plays as f64,
+                average_score: total_score as f64 / plays as f64,
+                form: streaks[player].get_form().to_string(),
+            })
+            .collect::<Vec<_>>();
+        standings.sort_by(|a, b| {
+            b.wins
+                .cmp(&a.wins)
+                .then(b.win_rate.total_cmp(&a.win_rate))
+                .then(b.average_score.total_cmp(&a.average_score))
+                .then(a.player.cmp(&b.player))
+        });
+
+        Leaderboard {
+            game_name: game_name.to_string(),
+            matches: games.len(),
+            min_plays,
+            standings,
+            records,
+        }
+    }
+
+    /// Leaves guests out of the standings. The records they hold are kept,
+    /// as they still played the matches.
+    pub fn exclude_guests(&mut self) {
+        self.standings
+            .retain(|standing| !player::is_guest(&standing.player));
+    }
+
+    pub fn get_matches(&self) -> usize {
+        self.matches
+    }
+
+    pub fn to_standing_rows(&self) -> Vec<StandingRow> {
+        self.standings
+            .iter()
+            .enumerate()
+            .map(|(index, standing)| StandingRow {
+                rank: index + 1,
+                player: standing.player.clone(),
+                plays: standing.plays,
+                wins: standing.wins,
+                win_rate: format!("{:.1}%", standing.win_rate * 100.0),
+                average_score: format!("{:.2}", standing.average_score),
+                form: standing.form.clone(),
+            })
+            .collect()
+    }
+
+    pub fn to_record_rows(&self) -> Vec<RecordRow> {
+        [
+            ("highest score", &self.records.highest_score),
+            ("lowest winning score", &self.records.lowest_winning_score),
+            ("largest margin", &self.records.largest_margin),
+        ]
+        .into_iter()
+        .filter_map(|(name, record)| {
+            record.as_ref().map(|record| RecordRow {
+                record: name.to_string(),
+                player: record.player.clone(),
+                value: record.value,
+                date: record.date.to_string(),
+                id: record.match_id.to_string(),
+            })
+        })
+        .collect()
+    }
+}
+
+/// The distance between the sole winner and the closest other player, or
+/// `None` for shared wins and solo matches.
+fn margin_of_victory<'a>(game: &Game, winners: &'a [String]) -> Option<(&'a str, i64)> {
+    let [winner] = winners else {
+        return None;
+    };
+    let winner_score = game.get_scores().get(winner)?;
+    let margin = game
+        .get_scores()
+        .iter()
+        .filter(|(player, _)| *player != winner)
+        .map(|(_, score)| (winner_score - score).abs())
+        .min()?;
+
+    Some((winner, margin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(scores: &[&str], date: &str) -> Game {
+        Game::build(
+            "catan".to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn create_games() -> Vec<Game> {
+        vec![
+            create_game(&["alice::10", "bob::8", "carol::2"], "2025-01-01"),
+            create_game(&["alice::6", "bob::9"], "2025-01-02"),
+            create_game(&["alice::12", "bob::3"], "2025-01-03"),
+            create_game(&["alice::7", "bob::7"], "2025-01-04"),
+        ]
+    }
+
+    #[test]
+    fn compute_ranks_players() {
+        let leaderboard = Leaderboard::compute("catan", &create_games(), 1);
+
+        let players = leaderboard
+            .standings
+            .iter()
+            .map(|s| s.player.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(players, vec!["alice", "bob", "carol"]);
+        assert_eq!(leaderboard.standings[0].wins, 3);
+        assert_eq!(leaderboard.standings[0].win_rate, 0.75);
+        assert_eq!(leaderboard.standings[0].average_score, 8.75);
+        assert_eq!(leaderboard.standings[0].form, "W-L-W-W");
+    }
+
+    #[test]
+    fn compute_applies_min_plays() {
+        let leaderboard = Leaderboard::compute("catan", &create_games(), 2);
+
+        assert_eq!(leaderboard.standings.len(), 2);
+        assert!(leaderboard.standings.iter().all(|s| s.player != "carol"));
+    }
+
+    #[test]
+    fn exclude_guests_keeps_their_records() {
+        let games = vec![create_game(&["alice::10", "guest:Marta::14"], "2025-01-01")];
+        let mut leaderboard = Leaderboard::compute("catan", &games, 1);
+        leaderboard.exclude_guests();
+
+        assert_eq!(leaderboard.standings.len(), 1);
+        assert_eq!(leaderboard.standings[0].player, "alice");
+        assert_eq!(leaderboard.standings[0].wins, 0);
+        assert_eq!(
+            leaderboard.records.highest_score.unwrap().player,
+            "guest:Marta"
+        );
+    }
+
+    #[test]
+    fn compute_records() {
+        let games = create_games();
+        let leaderboard = Leaderboard::compute("catan", &games, 1);
+        let records = leaderboard.records;
+
+        let highest = records.highest_score.unwrap();
+        assert_eq!((highest.player.as_str(), highest.value), ("alice", 12));
+        assert_eq!(highest.match_id, *games[2].get_id());
+
+        let lowest = records.lowest_winning_score.unwrap();
+        assert_eq!((lowest.player.as_str(), lowest.value), ("alice", 7));
+
+        let margin = records.largest_margin.unwrap();
+        assert_eq!((margin.player.as_str(), margin.value), ("alice", 9));
+        assert_eq!(margin.date.to_string(), "2025-01-03");
+    }
+
+    #[test]
+    fn compute_without_games() {
+        let leaderboard = Leaderboard::compute("catan", &[], 1);
+
+        assert!(leaderboard.standings.is_empty());
+        assert!(leaderboard.to_record_rows().is_empty());
+    }
+}
@@ -0,0 +1,374 @@
+use crate::commands::models::game::{Game, Games};
+use crate::commands::models::player::Players;
+use serde::Serialize;
+use uuid::Uuid;
+
+pub const CONFLICTS_FILE_NAME: &str = "merge-conflicts.json";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConflictPolicy {
+    KeepOurs,
+    KeepTheirs,
+    Report,
+}
+
+impl ConflictPolicy {
+    pub fn parse(policy: &str) -> Result<ConflictPolicy, String> {
+        match policy {
+            "ours" => Ok(ConflictPolicy::KeepOurs),
+            "theirs" => Ok(ConflictPolicy::KeepTheirs),
+            "report" => Ok(ConflictPolicy::Report),
+            other => Err(format!(
+                "Unknown conflict policy {}. Supported policies are ours, theirs and report.",
+                other
+            )),
+        }
+    }
+}
+
+/// The same game id recorded with different content on the two sides.
+#[derive(Serialize, Debug)]
+pub struct GameConflict {
+    pub id: Uuid,
+    pub ours: Game,
+    pub theirs: Game,
+}
+
+/// A collection of named records, such as the catalog or the seasons, merged
+/// record by record.
+pub trait Records {
+    type Record: Clone + PartialEq;
+
+    fn records(&self) -> Vec<&Self::Record>;
+
+    /// Adds `record`, failing when its name is already taken.
+    fn add_record(&mut self, record: Self::Record) -> Result<(), String>;
+}
+
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    pub added_players: usize,
+    pub existing_players: Vec<String>,
+    pub added_records: usize,
+    /// Why each record of theirs that differs from ours was not added.
+    pub existing_records: Vec<String>,
+    pub added_games: usize,
+    pub unchanged_games: usize,
+    pub duplicate_matches: usize,
+    pub resolved_conflicts: usize,
+    pub conflicts: Vec<GameConflict>,
+}
+
+/// Their player names that resolve to one of our players under another
+/// spelling, such as a different case or an alias, with our name.
+pub fn player_renames(players: &Players, their_players: &Players) -> Vec<(String, String)> {
+    let mut renames = their_players
+        .get_players()
+        .keys()
+        .filter_map(|name| {
+            players
+                .resolve(name)
+                .filter(|ours| ours.get_name() != name)
+                .map(|ours| (name.clone(), ours.get_name().to_string()))
+        })
+        .collect::<Vec<_>>();
+    renames.sort();
+    renames
+}
+
+/// Merges `their_players` and `their_games` into ours. Their players are
+/// first resolved to ours, so that their games are recorded under our names.
+/// Players are then matched by name and games by id; a game with a new id
+/// describing a match we already recorded is skipped as a duplicate. Games
+/// with the same id but different content are resolved according to
+/// `policy`: with `Report` our version is kept and the conflict is returned
+/// in the report.
+pub fn merge(
+    players: &mut Players,
+    games: &mut Games,
+    their_players: &Players,
+    their_games: &Games,
+    policy: ConflictPolicy,
+) -> Result<MergeReport, String> {
+    let mut report = MergeReport::default();
+
+    let renames = player_renames(players, their_players);
+    let mut their_games = their_games.order_by_date();
+    for game in their_games.iter_mut() {
+        for (name, our_name) in &renames {
+            if game.get_scores().contains_key(name) {
+                game.rename_player(name, our_name)?;
+            }
+        }
+    }
+
+    let mut player_names = their_players.get_players().keys().collect::<Vec<_>>();
+    player_names.sort();
+    for name in player_names {
+        let player = &their_players.get_players()[name];
+        if players.add_player(player.clone()).is_ok() {
+            report.added_players += 1;
+        } else {
+            report.existing_players.push(name.clone());
+        }
+    }
+
+    for game in their_games {
+        match games.get_games().get(game.get_id()).cloned() {
+            Some(ours) if ours == game => report.unchanged_games += 1,
+            Some(ours) => match policy {
+                ConflictPolicy::KeepOurs => report.resolved_conflicts += 1,
+                ConflictPolicy::KeepTheirs => {
+                    games.add_game(game);
+                    report.resolved_conflicts += 1;
+                }
+                ConflictPolicy::Report => report.conflicts.push(GameConflict {
+                    id: *ours.get_id(),
+                    ours,
+                    theirs: game,
+                }),
+            },
+            None if games.contains_match(&game) => report.duplicate_matches += 1,
+            None => {
+                games.add_game(game);
+                report.added_games += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Adds the records of `theirs` whose name we do not use. A record we
+/// already have unchanged is skipped, any other is kept as ours and noted in
+/// the report. Returns whether any record was added.
+pub fn merge_records<R: Records>(ours: &mut R, theirs: &R, report: &mut MergeReport) -> bool {
+    let mut added = false;
+    for record in theirs.records() {
+        if ours.records().contains(&record) {
+            continue;
+        }
+        match ours.add_record(record.clone()) {
+            Ok(()) => {
+                report.added_records += 1;
+                added = true;
+            }
+            Err(err) => report.existing_records.push(err),
+        }
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::player::Player;
+    use crate::commands::models::season::{PointsScheme, Season, Seasons};
+
+    fn create_game(scores: Vec<&str>) -> Game {
+        Game::build(
+            "catan".to_string(),
+            scores.into_iter().map(|s| s.to_string()).collect(),
+            Some("2025-01-01"),
+        )
+        .unwrap()
+    }
+
+    fn edit_score(game: &Game, player: &str, score: usize) -> Game {
+        let mut edited = serde_json::to_value(game).unwrap();
+        edited["scores"][player] = serde_json::json!(score);
+        serde_json::from_value(edited).unwrap()
+    }
+
+    fn games_of(games: Vec<&Game>) -> Games {
+        let mut result = Games::create_empy();
+        for game in games {
+            result.add_game(game.clone());
+        }
+        result
+    }
+
+    #[test]
+    fn merge_unions_players_and_games() {
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        let mut their_players = Players::create_empty();
+        their_players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        their_players
+            .add_player(Player::new("bob".to_string()))
+            .unwrap();
+
+        let ours = create_game(vec!["alice::10"]);
+        let theirs = create_game(vec!["alice::10", "bob::3"]);
+        let mut games = games_of(vec![&ours]);
+
+        let report = merge(
+            &mut players,
+            &mut games,
+            &their_players,
+            &games_of(vec![&ours, &theirs]),
+            ConflictPolicy::Report,
+        )
+        .unwrap();
+        assert_eq!(report.added_players, 1);
+        assert_eq!(report.existing_players, vec!["alice"]);
+        assert_eq!(report.added_games, 1);
+        assert_eq!(report.unchanged_games, 1);
+        assert_eq!(games.get_games().len(), 2);
+    }
+
+    #[test]
+    fn merge_skips_duplicate_matches() {
+        let ours = create_game(vec!["alice::10", "bob::3"]);
+        let theirs = create_game(vec!["alice::10", "bob::3"]);
+        let mut games = games_of(vec![&ours]);
+
+        let report = merge(
+            &mut Players::create_empty(),
+            &mut games,
+            &Players::create_empty(),
+            &games_of(vec![&theirs]),
+            ConflictPolicy::Report,
+        )
+        .unwrap();
+        assert_eq!(report.duplicate_matches, 1);
+        assert_eq!(report.added_games, 0);
+        assert_eq!(games.get_games().len(), 1);
+    }
+
+    #[test]
+    fn merge_reports_conflicting_edits() {
+        let ours = create_game(vec!["alice::10", "bob::3"]);
+        let theirs = edit_score(&ours, "bob", 4);
+        let mut games = games_of(vec![&ours]);
+
+        let report = merge(
+            &mut Players::create_empty(),
+            &mut games,
+            &Players::create_empty(),
+            &games_of(vec![&theirs]),
+            ConflictPolicy::Report,
+        )
+        .unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].id, *ours.get_id());
+        assert_eq!(games.get_games()[ours.get_id()], ours);
+    }
+
+    #[test]
+    fn merge_resolves_conflicts_by_policy() {
+        let ours = create_game(vec!["alice::10", "bob::3"]);
+        let theirs = edit_score(&ours, "bob", 4);
+
+        let mut games = games_of(vec![&ours]);
+        let report = merge(
+            &mut Players::create_empty(),
+            &mut games,
+            &Players::create_empty(),
+            &games_of(vec![&theirs]),
+            ConflictPolicy::KeepTheirs,
+        )
+        .unwrap();
+        assert_eq!(report.resolved_conflicts, 1);
+        assert!(report.conflicts.is_empty());
+        assert_eq!(games.get_games()[ours.get_id()], theirs);
+
+        let mut games = games_of(vec![&ours]);
+        merge(
+            &mut Players::create_empty(),
+            &mut games,
+            &Players::create_empty(),
+            &games_of(vec![&theirs]),
+            ConflictPolicy::KeepOurs,
+        )
+        .unwrap();
+        assert_eq!(games.get_games()[ours.get_id()], ours);
+    }
+
+    #[test]
+    fn merge_records_their_players_under_our_names() {
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        let mut their_players = Players::create_empty();
+        their_players
+            .add_player(Player::new("Alice".to_string()))
+            .unwrap();
+        their_players
+            .add_player(Player::new("bob".to_string()))
+            .unwrap();
+        assert_eq!(
+            player_renames(&players, &their_players),
+            vec![("Alice".to_string(), "alice".to_string())]
+        );
+
+        let theirs = create_game(vec!["Alice::10", "bob::3"]);
+        let mut games = Games::create_empy();
+        let report = merge(
+            &mut players,
+            &mut games,
+            &their_players,
+            &games_of(vec![&theirs]),
+            ConflictPolicy::Report,
+        )
+        .unwrap();
+        assert_eq!(report.added_players, 1);
+        assert_eq!(report.existing_players, vec!["Alice"]);
+        assert!(!players.exists(Player::new("Alice".to_string())));
+
+        let merged = &games.get_games()[theirs.get_id()];
+        assert_eq!(merged.get_scores()["alice"], 10);
+        assert!(!merged.get_scores().contains_key("Alice"));
+    }
+
+    #[test]
+    fn merge_records_keeps_ours() {
+        let season = |name: &str, end: &str| {
+            Season::build(
+                name.to_string(),
+                "2025-01-01".parse().unwrap(),
+                end.parse().unwrap(),
+                Vec::new(),
+                PointsScheme::default(),
+            )
+            .unwrap()
+        };
+        let mut seasons = Seasons::create_empty();
+        seasons.add_season(season("spring", "2025-03-31")).unwrap();
+        seasons.add_season(season("summer", "2025-06-30")).unwrap();
+        let mut their_seasons = Seasons::create_empty();
+        their_seasons
+            .add_season(season("spring", "2025-03-31"))
+            .unwrap();
+        their_seasons
+            .add_season(season("summer", "2025-07-31"))
+            .unwrap();
+        their_seasons
+            .add_season(season("autumn", "2025-11-30"))
+            .unwrap();
+
+        let mut report = MergeReport::default();
+        merge_records(&mut seasons, &their_seasons, &mut report);
+        assert_eq!(report.added_records, 1);
+        assert_eq!(
+            report.existing_records,
+            vec!["Season summer already exists."]
+        );
+        assert!(seasons.get("autumn").is_ok());
+    }
+
+    #[test]
+    fn parse_unknown_policy() {
+        let result = ConflictPolicy::parse("newest");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown conflict policy newest. Supported policies are ours, theirs and report."
+        );
+    }
+}
@@ -1,13 +1,68 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tabled::Tabled;
 
 pub const PLAYER_FIELD_COUNT: usize = 1;
 
 pub const FILE_NAME_DATA: &str = "players.json";
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Prefix of the players recorded in a match without being registered,
+/// like `guest:Marta`.
+pub const GUEST_PREFIX: &str = "guest:";
+
+/// How similar an unknown name must be to a player, from 0 to 1, for the
+/// player to be suggested.
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+
+/// Colors a player can be shown in, with their ANSI codes.
+const COLORS: [(&str, &str); 7] = [
+    ("red", "31"),
+    ("green", "32"),
+    ("yellow", "33"),
+    ("blue", "34"),
+    ("magenta", "35"),
+    ("cyan", "36"),
+    ("white", "37"),
+];
+
+fn active_by_default() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Player {
     player_name: String,
+    /// Other names the player is entered with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    joined: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    favorite_games: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    emoji: Option<String>,
+    /// Inactive players are kept with their history but no longer play.
+    #[serde(default = "active_by_default")]
+    active: bool,
+}
+
+/// Changes to a profile. `Some("")` clears an optional field.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProfileChanges {
+    pub display_name: Option<String>,
+    pub nickname: Option<String>,
+    pub joined: Option<NaiveDate>,
+    pub favorite_games: Option<Vec<String>>,
+    pub color: Option<String>,
+    pub emoji: Option<String>,
+    pub active: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -15,35 +70,281 @@ pub struct Players {
     players: HashMap<String, Player>,
 }
 
+#[derive(Tabled)]
+pub struct PlayerRow {
+    name: String,
+    aliases: String,
+    display_name: String,
+    nickname: String,
+    joined: String,
+    favorite_games: String,
+    color: String,
+    emoji: String,
+    active: String,
+}
+
+/// Whether `name` is a guest rather than a registered player.
+pub fn is_guest(name: &str) -> bool {
+    name.starts_with(GUEST_PREFIX)
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 impl Player {
     pub fn new(player_name: String) -> Player {
         Player {
-            player_name: player_name,
+            player_name,
+            aliases: Vec::new(),
+            display_name: None,
+            nickname: None,
+            joined: None,
+            favorite_games: Vec::new(),
+            color: None,
+            emoji: None,
+            active: true,
         }
     }
 
     pub fn get_name(&self) -> &str {
         &self.player_name
     }
+
+    /// The name to show: the display name if set, otherwise the player name.
+    pub fn get_display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.player_name)
+    }
+
+    /// Whether `name` is the player name or an alias, ignoring case and
+    /// surrounding whitespace.
+    fn matches(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.player_name.to_lowercase() == name
+            || self.aliases.iter().any(|a| a.to_lowercase() == name)
+    }
+
+    /// How close `name` is to the player name or one of the aliases.
+    fn similarity(&self, name: &str) -> f64 {
+        let name = name.trim().to_lowercase();
+        std::iter::once(&self.player_name)
+            .chain(&self.aliases)
+            .map(|candidate| strsim::jaro_winkler(&candidate.to_lowercase(), &name))
+            .fold(0.0, f64::max)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_joined(&mut self, joined: NaiveDate) {
+        self.joined = Some(joined);
+    }
+
+    pub fn edit(&mut self, changes: ProfileChanges) -> Result<(), String> {
+        if let Some(color) = &changes.color
+            && !color.is_empty()
+            && !COLORS.iter().any(|(name, _)| name == color)
+        {
+            return Err(format!(
+                "Invalid color {}. Use one of {}.",
+                color,
+                COLORS.map(|(name, _)| name).join(", ")
+            ));
+        }
+
+        if let Some(display_name) = changes.display_name {
+            self.display_name = non_empty(display_name);
+        }
+        if let Some(nickname) = changes.nickname {
+            self.nickname = non_empty(nickname);
+        }
+        if let Some(joined) = changes.joined {
+            self.joined = Some(joined);
+        }
+        if let Some(favorite_games) = changes.favorite_games {
+            self.favorite_games = favorite_games.into_iter().filter_map(non_empty).collect();
+        }
+        if let Some(color) = changes.color {
+            self.color = non_empty(color);
+        }
+        if let Some(emoji) = changes.emoji {
+            self.emoji = non_empty(emoji);
+        }
+        if let Some(active) = changes.active {
+            self.active = active;
+        }
+
+        Ok(())
+    }
+
+    /// The display name with the player's emoji, in the player's color when
+    /// `colored` is set.
+    pub fn label(&self, colored: bool) -> String {
+        let name = match (&self.color, colored) {
+            (Some(color), true) => match COLORS.iter().find(|(name, _)| name == color) {
+                Some((_, code)) => format!("\x1b[{}m{}\x1b[0m", code, self.get_display_name()),
+                None => self.get_display_name().to_string(),
+            },
+            _ => self.get_display_name().to_string(),
+        };
+        match &self.emoji {
+            Some(emoji) => format!("{} {}", emoji, name),
+            None => name,
+        }
+    }
+
+    pub fn to_row(&self) -> PlayerRow {
+        PlayerRow {
+            name: self.player_name.clone(),
+            aliases: self.aliases.join(", "),
+            display_name: self.display_name.clone().unwrap_or_default(),
+            nickname: self.nickname.clone().unwrap_or_default(),
+            joined: self.joined.map(|d| d.to_string()).unwrap_or_default(),
+            favorite_games: self.favorite_games.join(", "),
+            color: self.color.clone().unwrap_or_default(),
+            emoji: self.emoji.clone().unwrap_or_default(),
+            active: if self.active { "yes" } else { "no" }.to_string(),
+        }
+    }
 }
 
 impl Players {
     pub fn from_players(players: HashMap<String, Player>) -> Players {
-        Players { players: players }
+        Players { players }
+    }
+
+    pub fn create_empty() -> Players {
+        Players {
+            players: HashMap::new(),
+        }
+    }
+
+    pub fn get_players(&self) -> &HashMap<String, Player> {
+        &self.players
+    }
+
+    /// The player `name` refers to, by player name or alias. An exact
+    /// player name wins over names that only differ in case.
+    pub fn resolve(&self, name: &str) -> Option<&Player> {
+        if let Some(player) = self.players.get(name) {
+            return Some(player);
+        }
+        let mut matching = self.players.values().filter(|player| player.matches(name));
+        match (matching.next(), matching.next()) {
+            (Some(player), None) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// The player whose name or alias is closest to `name`, if any is close
+    /// enough to be a likely typo.
+    pub fn suggest(&self, name: &str) -> Option<&Player> {
+        self.players
+            .values()
+            .map(|player| (player, player.similarity(name)))
+            .filter(|(_, similarity)| *similarity >= SUGGESTION_THRESHOLD)
+            .max_by(|(a, a_similarity), (b, b_similarity)| {
+                a_similarity
+                    .total_cmp(b_similarity)
+                    .then(b.get_name().cmp(a.get_name()))
+            })
+            .map(|(player, _)| player)
+    }
+
+    /// `message` about the unknown player `name`, followed by a suggestion
+    /// when there is one.
+    pub fn unknown_player(&self, name: &str, message: String) -> String {
+        match self.suggest(name) {
+            Some(player) => format!("{} Did you mean {}?", message, player.get_name()),
+            None => message,
+        }
+    }
+
+    pub fn get(&self, player_name: &str) -> Result<&Player, String> {
+        self.resolve(player_name).ok_or_else(|| {
+            self.unknown_player(player_name, format!("Player {} not found.", player_name))
+        })
     }
 
     pub fn add_player(&mut self, player: Player) -> Result<(), String> {
         if let Some(player) = self.players.get(player.get_name()) {
             return Err(format!("Player {} already exists.", player.get_name()));
         }
+        if let Some(existing) = self.find_alias(player.get_name()) {
+            return Err(format!(
+                "{} is already an alias of player {}.",
+                player.get_name(),
+                existing.get_name()
+            ));
+        }
+        // Names are resolved ignoring case, so they must differ by more.
+        if let Some(existing) = self
+            .players
+            .values()
+            .find(|existing| existing.matches(player.get_name()))
+        {
+            return Err(format!(
+                "{} is already used by player {}.",
+                player.get_name(),
+                existing.get_name()
+            ));
+        }
 
         self.players
             .insert(player.get_name().to_string(), player.clone());
         Ok(())
     }
 
+    /// Inserts `player` as recorded, without checking its name against the
+    /// other players, as when replaying the event log.
+    pub fn restore_player(&mut self, player: Player) {
+        self.players.insert(player.get_name().to_string(), player);
+    }
+
+    /// Replaces the profile of an existing player.
+    pub fn update_player(&mut self, player: Player) -> Result<(), String> {
+        match self.players.get_mut(player.get_name()) {
+            Some(existing) => {
+                *existing = player;
+                Ok(())
+            }
+            None => Err(format!("Player {} not found.", player.get_name())),
+        }
+    }
+
+    fn find_alias(&self, name: &str) -> Option<&Player> {
+        let name = name.trim().to_lowercase();
+        self.players
+            .values()
+            .find(|player| player.aliases.iter().any(|a| a.to_lowercase() == name))
+    }
+
+    /// Adds `alias` to the player `name`. An alias cannot be the name or the
+    /// alias of any player, ignoring case.
+    pub fn add_alias(&mut self, name: &str, alias: &str) -> Result<(), String> {
+        let alias = alias.trim();
+        if alias.is_empty() {
+            return Err("The alias cannot be empty.".to_string());
+        }
+        if let Some(player) = self.players.values().find(|player| player.matches(alias)) {
+            return Err(format!(
+                "{} is already used by player {}.",
+                alias,
+                player.get_name()
+            ));
+        }
+
+        let player_name = self.get(name)?.get_name().to_string();
+        if let Some(player) = self.players.get_mut(&player_name) {
+            player.aliases.push(alias.to_string());
+        }
+        Ok(())
+    }
+
     pub fn remove_player(&mut self, player: Player) -> Result<(), String> {
-        if let None = self.players.remove(player.get_name()) {
+        if self.players.remove(player.get_name()).is_none() {
             return Err(format!("Player {} not found.", player.get_name()));
         }
 
@@ -51,10 +352,123 @@ impl Players {
     }
 
     pub fn exists(&self, player: Player) -> bool {
-        if let Some(_) = self.players.get(player.get_name()) {
-            true
-        } else {
-            false
+        self.players.contains_key(player.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_sets_and_clears_fields() {
+        let mut player = Player::new("giovi98".to_string());
+        player
+            .edit(ProfileChanges {
+                display_name: Some("Giovanni".to_string()),
+                nickname: Some("Gio".to_string()),
+                favorite_games: Some(vec!["Catan".to_string(), " ".to_string()]),
+                color: Some("green".to_string()),
+                emoji: Some("🎲".to_string()),
+                active: Some(false),
+                ..ProfileChanges::default()
+            })
+            .unwrap();
+        assert_eq!(player.get_display_name(), "Giovanni");
+        assert_eq!(player.favorite_games, vec!["Catan"]);
+        assert!(!player.active);
+        assert_eq!(player.label(false), "🎲 Giovanni");
+        assert_eq!(player.label(true), "🎲 \x1b[32mGiovanni\x1b[0m");
+
+        player
+            .edit(ProfileChanges {
+                display_name: Some(String::new()),
+                ..ProfileChanges::default()
+            })
+            .unwrap();
+        assert_eq!(player.get_display_name(), "giovi98");
+        assert_eq!(player.nickname.as_deref(), Some("Gio"));
+    }
+
+    #[test]
+    fn edit_rejects_unknown_color() {
+        let mut player = Player::new("giovi98".to_string());
+        let result = player.edit(ProfileChanges {
+            color: Some("purple".to_string()),
+            ..ProfileChanges::default()
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid color purple. Use one of red, green, yellow, blue, magenta, cyan, white."
+        );
+    }
+
+    fn create_players() -> Players {
+        let mut players = Players::create_empty();
+        for name in ["giovi98", "emma00", "Emma"] {
+            players.add_player(Player::new(name.to_string())).unwrap();
         }
+        players.add_alias("giovi98", "Gio").unwrap();
+        players
+    }
+
+    #[test]
+    fn resolve_by_alias_ignoring_case() {
+        let players = create_players();
+        assert_eq!(players.resolve(" gio ").unwrap().get_name(), "giovi98");
+        assert_eq!(players.resolve("GIOVI98").unwrap().get_name(), "giovi98");
+        assert_eq!(players.resolve("Emma").unwrap().get_name(), "Emma");
+        assert_eq!(players.resolve("EMMA").unwrap().get_name(), "Emma");
+        assert!(players.resolve("giovanni").is_none());
+    }
+
+    #[test]
+    fn add_alias_rejects_names_in_use() {
+        let mut players = create_players();
+        assert_eq!(
+            players.add_alias("emma00", "GIO").unwrap_err(),
+            "GIO is already used by player giovi98."
+        );
+        assert_eq!(
+            players.add_alias("emma00", "giovi98").unwrap_err(),
+            "giovi98 is already used by player giovi98."
+        );
+        assert_eq!(
+            players
+                .add_player(Player::new("gio".to_string()))
+                .unwrap_err(),
+            "gio is already an alias of player giovi98."
+        );
+        assert_eq!(
+            players
+                .add_player(Player::new("GIOVI98".to_string()))
+                .unwrap_err(),
+            "GIOVI98 is already used by player giovi98."
+        );
+        assert_eq!(
+            players.add_alias("nobody", "nobo").unwrap_err(),
+            "Player nobody not found."
+        );
+    }
+
+    #[test]
+    fn suggest_close_names() {
+        let players = create_players();
+        assert_eq!(players.suggest("giovi").unwrap().get_name(), "giovi98");
+        assert_eq!(players.suggest("emma0").unwrap().get_name(), "emma00");
+        assert!(players.suggest("zoe").is_none());
+        assert_eq!(
+            players.get("giovi9").unwrap_err(),
+            "Player giovi9 not found. Did you mean giovi98?"
+        );
+    }
+
+    #[test]
+    fn players_without_profile_still_load() {
+        let players: Players =
+            serde_json::from_str(r#"{"players":{"alice":{"player_name":"alice"}}}"#).unwrap();
+        let alice = players.get("alice").unwrap();
+        assert_eq!(alice, &Player::new("alice".to_string()));
+        assert!(alice.active);
     }
 }
@@ -0,0 +1,381 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::player::{Player, Players};
+use crate::commands::models::rating::{self, Rating, RatingAlgorithm};
+use crate::commands::models::streak::{self, Streaks};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tabled::Tabled;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayerSort {
+    Name,
+    GamesPlayed,
+    LastPlayed,
+}
+
+/// A registered player with how much they play.
+#[derive(Serialize, Debug)]
+pub struct PlayerActivity {
+    #[serde(flatten)]
+    player: Player,
+    games_played: usize,
+    last_played: Option<NaiveDate>,
+}
+
+#[derive(Tabled)]
+pub struct PlayerActivityRow {
+    name: String,
+    display_name: String,
+    games_played: usize,
+    last_played: String,
+    active: String,
+}
+
+/// The record of a player in one game.
+#[derive(Serialize, Debug)]
+pub struct GameRecord {
+    game_name: String,
+    plays: usize,
+    wins: usize,
+    best_score: i64,
+    average_score: f64,
+    last_played: NaiveDate,
+    form: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct HistoryEntry {
+    id: Uuid,
+    date: NaiveDate,
+    game_name: String,
+    score: i64,
+    /// Finishing position, starting from 1. Tied players share it.
+    position: usize,
+    players: usize,
+    won: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlayerRank {
+    algorithm: RatingAlgorithm,
+    #[serde(flatten)]
+    rating: Rating,
+    rank: usize,
+    rated_players: usize,
+}
+
+/// Everything recorded about a player.
+#[derive(Serialize, Debug)]
+pub struct PlayerReport {
+    player: Player,
+    streaks: Option<Streaks>,
+    rating: Option<PlayerRank>,
+    games: Vec<GameRecord>,
+    /// Every match of the player, newest first.
+    history: Vec<HistoryEntry>,
+}
+
+#[derive(Tabled)]
+pub struct GameRecordRow {
+    game: String,
+    plays: usize,
+    wins: usize,
+    win_rate: String,
+    best_score: i64,
+    average_score: String,
+    last_played: String,
+    form: String,
+}
+
+#[derive(Tabled)]
+pub struct HistoryRow {
+    date: String,
+    game: String,
+    score: i64,
+    position: String,
+    result: String,
+    id: String,
+}
+
+impl PlayerSort {
+    pub fn parse(sort: &str) -> Result<PlayerSort, String> {
+        match sort {
+            "name" => Ok(PlayerSort::Name),
+            "games" => Ok(PlayerSort::GamesPlayed),
+            "last-played" => Ok(PlayerSort::LastPlayed),
+            other => Err(format!(
+                "Unknown sort {}. Use name, games or last-played.",
+                other
+            )),
+        }
+    }
+}
+
+/// The activity of every registered player in `games`, sorted by `sort`.
+/// Most played and most recently played come first.
+pub fn activity(players: &Players, games: &[Game], sort: PlayerSort) -> Vec<PlayerActivity> {
+    let mut activity = players
+        .get_players()
+        .values()
+        .map(|player| {
+            let played = games
+                .iter()
+                .filter(|game| game.get_scores().contains_key(player.get_name()));
+            PlayerActivity {
+                player: player.clone(),
+                games_played: played.clone().count(),
+                last_played: played.map(|game| *game.get_datetime()).max(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    activity.sort_by(|a, b| {
+        let by_name = a.player.get_name().cmp(b.player.get_name());
+        match sort {
+            PlayerSort::Name => by_name,
+            PlayerSort::GamesPlayed => b.games_played.cmp(&a.games_played).then(by_name),
+            PlayerSort::LastPlayed => b.last_played.cmp(&a.last_played).then(by_name),
+        }
+    });
+    activity
+}
+
+pub fn to_activity_rows(activity: &[PlayerActivity]) -> Vec<PlayerActivityRow> {
+    activity
+        .iter()
+        .map(|entry| PlayerActivityRow {
+            name: entry.player.get_name().to_string(),
+            display_name: entry.player.get_display_name().to_string(),
+            games_played: entry.games_played,
+            last_played: entry
+                .last_played
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            active: if entry.player.is_active() {
+                "yes"
+            } else {
+                "no"
+            }
+            .to_string(),
+        })
+        .collect()
+}
+
+impl PlayerReport {
+    /// Builds the report of `player` from `games`, which must be ordered by
+    /// date. Ratings are computed over every game with `algorithm`.
+    pub fn compute(player: &Player, games: &[Game], algorithm: RatingAlgorithm) -> PlayerReport {
+        let name = player.get_name();
+        let played = games
+            .iter()
+            .filter(|game| game.get_scores().contains_key(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut by_game: BTreeMap<&str, Vec<&Game>> = BTreeMap::new();
+        for game in &played {
+            by_game.entry(game.get_name()).or_default().push(game);
+        }
+        let records = by_game
+            .into_iter()
+            .map(|(game_name, matches)| {
+                let scores = matches
+                    .iter()
+                    .map(|game| game.get_scores()[name])
+                    .collect::<Vec<_>>();
+                let matches = matches.into_iter().cloned().collect::<Vec<_>>();
+                let streaks = streak::compute(&matches).remove(name).unwrap_or_default();
+                GameRecord {
+                    game_name: game_name.to_string(),
+                    plays: matches.len(),
+                    wins: streaks.get_wins(),
+                    best_score: scores.iter().copied().max().unwrap_or(0),
+                    average_score: scores.iter().sum::<i64>() as f64 / scores.len() as f64,
+                    last_played: *matches[matches.len() - 1].get_datetime(),
+                    form: streaks.get_form().to_string(),
+                }
+            })
+            .collect();
+
+        let mut ratings = rating::rate(games, algorithm);
+        rating::exclude_guests(&mut ratings);
+        let ranked = rating::rank(&ratings);
+        let rating = ranked
+            .iter()
+            .position(|entry| entry.get_player() == name)
+            .map(|index| PlayerRank {
+                algorithm,
+                rating: *ranked[index].get_rating(),
+                rank: index + 1,
+                rated_players: ranked.len(),
+            });
+
+        let history = played
+            .iter()
+            .rev()
+            .map(|game| HistoryEntry {
+                id: *game.get_id(),
+                date: *game.get_datetime(),
+                game_name: game.get_name().to_string(),
+                score: game.get_scores()[name],
+                position: game.get_ranks()[name],
+                players: game.get_scores().len(),
+                won: game.get_winners().iter().any(|winner| winner == name),
+            })
+            .collect();
+
+        PlayerReport {
+            player: player.clone(),
+            streaks: streak::compute(&played).remove(name),
+            rating,
+            games: records,
+            history,
+        }
+    }
+
+    pub fn summary(&self, colored: bool) -> String {
+        let mut lines = vec![self.player.label(colored)];
+        match &self.streaks {
+            Some(streaks) => lines.push(format!(
+                "{} matches, {} wins. Form: {}, current win streak {}, longest {}.",
+                streaks.get_plays(),
+                streaks.get_wins(),
+                streaks.get_form(),
+                streaks.get_current_win_streak(),
+                streaks.get_longest_win_streak()
+            )),
+            None => lines.push("No matches played.".to_string()),
+        }
+        if let Some(rating) = &self.rating {
+            lines.push(format!(
+                "Rating ({}): {:.1} ±{:.1}, rank {} of {}.",
+                rating.algorithm.get_name(),
+                rating.rating.get_rating(),
+                2.0 * rating.rating.get_deviation(),
+                rating.rank,
+                rating.rated_players
+            ));
+        }
+        lines.join("\n")
+    }
+
+    pub fn get_player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn to_game_rows(&self) -> Vec<GameRecordRow> {
+        self.games
+            .iter()
+            .map(|record| GameRecordRow {
+                game: record.game_name.clone(),
+                plays: record.plays,
+                wins: record.wins,
+                win_rate: format!("{:.0}%", 100.0 * record.wins as f64 / record.plays as f64),
+                best_score: record.best_score,
+                average_score: format!("{:.1}", record.average_score),
+                last_played: record.last_played.to_string(),
+                form: record.form.clone(),
+            })
+            .collect()
+    }
+
+    pub fn to_history_rows(&self) -> Vec<HistoryRow> {
+        self.history
+            .iter()
+            .map(|entry| HistoryRow {
+                date: entry.date.to_string(),
+                game: entry.game_name.clone(),
+                score: entry.score,
+                position: format!("{} of {}", entry.position, entry.players),
+                result: if entry.won { "W" } else { "L" }.to_string(),
+                id: entry.id.to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(game_name: &str, scores: &[&str], date: &str) -> Game {
+        Game::build(
+            game_name.to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn create_games() -> Vec<Game> {
+        vec![
+            create_game("catan", &["alice::10", "bob::8"], "2025-01-01"),
+            create_game("azul", &["alice::40", "bob::50", "carol::45"], "2025-01-02"),
+            create_game("catan", &["alice::12", "carol::8"], "2025-01-03"),
+        ]
+    }
+
+    fn create_players() -> Players {
+        let mut players = Players::create_empty();
+        for name in ["alice", "bob", "carol", "dave"] {
+            players.add_player(Player::new(name.to_string())).unwrap();
+        }
+        players
+    }
+
+    #[test]
+    fn activity_sorts_players() {
+        let players = create_players();
+        let games = create_games();
+
+        let by_games = activity(&players, &games, PlayerSort::GamesPlayed);
+        let names = by_games
+            .iter()
+            .map(|entry| entry.player.get_name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "bob", "carol", "dave"]);
+        assert_eq!(by_games[0].games_played, 3);
+        assert_eq!(by_games[3].last_played, None);
+
+        let by_last_played = activity(&players, &games, PlayerSort::LastPlayed);
+        assert_eq!(by_last_played[0].player.get_name(), "alice");
+        assert_eq!(by_last_played[1].player.get_name(), "carol");
+        assert_eq!(by_last_played[3].player.get_name(), "dave");
+        assert!(PlayerSort::parse("wins").is_err());
+    }
+
+    #[test]
+    fn report_collects_history_and_records() {
+        let report = PlayerReport::compute(
+            &Player::new("alice".to_string()),
+            &create_games(),
+            RatingAlgorithm::TrueSkill,
+        );
+
+        assert_eq!(report.history.len(), 3);
+        assert_eq!(report.history[0].game_name, "catan");
+        assert_eq!(report.history[1].position, 3);
+        assert!(!report.history[1].won);
+        assert_eq!(report.games[1].game_name, "catan");
+        assert_eq!(report.games[1].wins, 2);
+        assert_eq!(report.games[1].best_score, 12);
+        assert_eq!(report.games[1].form, "W-W");
+        assert_eq!(report.streaks.as_ref().unwrap().get_form(), "W-L-W");
+        assert_eq!(report.rating.as_ref().unwrap().rated_players, 3);
+    }
+
+    #[test]
+    fn report_without_matches() {
+        let report = PlayerReport::compute(
+            &Player::new("dave".to_string()),
+            &create_games(),
+            RatingAlgorithm::TrueSkill,
+        );
+
+        assert!(report.history.is_empty());
+        assert!(report.rating.is_none());
+        assert!(report.summary(false).contains("No matches played."));
+    }
+}
@@ -0,0 +1,377 @@
+use crate::commands::models::rating::Rating;
+use serde::Serialize;
+use tabled::Tabled;
+
+/// Largest lineup `balance` searches exhaustively.
+pub const MAX_BALANCED_PLAYERS: usize = 16;
+
+#[derive(Serialize, Debug)]
+pub struct WinProbability {
+    player: String,
+    rating: f64,
+    deviation: f64,
+    probability: f64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Team {
+    players: Vec<String>,
+    strength: f64,
+}
+
+#[derive(Tabled)]
+pub struct WinProbabilityRow {
+    player: String,
+    rating: String,
+    win_probability: String,
+}
+
+#[derive(Tabled)]
+pub struct TeamRow {
+    team: usize,
+    players: String,
+    strength: String,
+}
+
+/// Plackett-Luce probabilities of each player finishing first. The more
+/// uncertain the ratings, the closer the probabilities are to each other.
+pub fn win_probabilities(lineup: &[(String, Rating)], spread: f64) -> Vec<WinProbability> {
+    let c = lineup
+        .iter()
+        .map(|(_, rating)| rating.get_deviation().powi(2) + spread.powi(2))
+        .sum::<f64>()
+        .sqrt();
+    let max_rating = lineup
+        .iter()
+        .map(|(_, rating)| rating.get_rating())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let weights = lineup
+        .iter()
+        .map(|(_, rating)| ((rating.get_rating() - max_rating) / c).exp())
+        .collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+
+    let mut probabilities = lineup
+        .iter()
+        .zip(weights)
+        .map(|((player, rating), weight)| WinProbability {
+            player: player.clone(),
+            rating: rating.get_rating(),
+            deviation: rating.get_deviation(),
+            probability: weight / total,
+        })
+        .collect::<Vec<_>>();
+    probabilities.sort_by(|a, b| {
+        b.probability
+            .total_cmp(&a.probability)
+            .then(a.player.cmp(&b.player))
+    });
+    probabilities
+}
+
+/// Splits `players` into `teams` teams whose sizes differ by at most one,
+/// minimizing the gap between the strongest and weakest team. The strength
+/// of a team is the mean of its players' ratings, so that teams of different
+/// sizes compare fairly.
+pub fn balance_teams(players: &[(String, f64)], teams: usize) -> Vec<Team> {
+    // Placing the strongest players first tightens the bound early.
+    let mut order = (0..players.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| players[*b].1.total_cmp(&players[*a].1));
+    let ratings = order.iter().map(|i| players[*i].1).collect::<Vec<_>>();
+    let mut prefix_sums = vec![0.0];
+    for rating in &ratings {
+        prefix_sums.push(prefix_sums[prefix_sums.len() - 1] + rating);
+    }
+    let mut search = Search {
+        ratings,
+        prefix_sums,
+        teams,
+        min_size: players.len() / teams,
+        max_size: players.len().div_ceil(teams),
+        assignment: Vec::with_capacity(players.len()),
+        sizes: vec![0; teams],
+        totals: vec![0.0; teams],
+        best: None,
+    };
+    search.assign();
+
+    let mut assignment = vec![0; players.len()];
+    if let Some((_, best)) = search.best {
+        for (position, team) in best.into_iter().enumerate() {
+            assignment[order[position]] = team;
+        }
+    }
+    let mut result = (0..teams)
+        .map(|team| {
+            let mut names = players
+                .iter()
+                .zip(&assignment)
+                .filter(|(_, assigned)| **assigned == team)
+                .map(|((player, _), _)| player.clone())
+                .collect::<Vec<_>>();
+            names.sort();
+            Team {
+                strength: strength(players, &assignment, team),
+                players: names,
+            }
+        })
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+    result
+}
+
+/// The gap between the strongest and the weakest team.
+pub fn strength_gap(teams: &[Team]) -> f64 {
+    gap(teams.iter().map(|team| team.strength))
+}
+
+fn gap(strengths: impl Iterator<Item = f64> + Clone) -> f64 {
+    strengths.clone().fold(f64::NEG_INFINITY, f64::max) - strengths.fold(f64::INFINITY, f64::min)
+}
+
+fn strength(players: &[(String, f64)], assignment: &[usize], team: usize) -> f64 {
+    let ratings = players
+        .iter()
+        .zip(assignment)
+        .filter(|(_, assigned)| **assigned == team)
+        .map(|((_, rating), _)| *rating)
+        .collect::<Vec<_>>();
+    ratings.iter().sum::<f64>() / ratings.len() as f64
+}
+
+/// Branch and bound over the assignments of players, strongest first, to
+/// teams.
+struct Search {
+    ratings: Vec<f64>,
+    /// `prefix_sums[i]` is the sum of the first `i` ratings.
+    prefix_sums: Vec<f64>,
+    teams: usize,
+    min_size: usize,
+    max_size: usize,
+    assignment: Vec<usize>,
+    sizes: Vec<usize>,
+    /// The sum of the ratings assigned to each team.
+    totals: Vec<f64>,
+    best: Option<(f64, Vec<usize>)>,
+}
+
+impl Search {
+    /// Assigns the next player to every team with room left. A player only
+    /// opens the first empty team, so that relabelled splits are skipped, and
+    /// branches that cannot beat the best split found so far are cut.
+    fn assign(&mut self) {
+        let bound = self.lower_bound();
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|(best, _)| *best <= 0.0 || bound >= *best)
+        {
+            return;
+        }
+        if self.assignment.len() == self.ratings.len() {
+            if bound.is_finite() {
+                self.best = Some((bound, self.assignment.clone()));
+            }
+            return;
+        }
+
+        let rating = self.ratings[self.assignment.len()];
+        let opened = self.sizes.iter().filter(|size| **size > 0).count();
+        for team in 0..self.teams.min(opened + 1) {
+            if self.sizes[team] < self.max_size {
+                self.assignment.push(team);
+                self.sizes[team] += 1;
+                self.totals[team] += rating;
+                self.assign();
+                self.totals[team] -= rating;
+                self.sizes[team] -= 1;
+                self.assignment.pop();
+            }
+        }
+    }
+
+    /// A lower bound on the gap of any completion of the current assignment,
+    /// infinite when no completion respects the team sizes. Once every player
+    /// is assigned, it is the gap itself.
+    fn lower_bound(&self) -> f64 {
+        let next = self.assignment.len();
+        let count = self.ratings.len();
+        let remaining = count - next;
+        // Remaining players are sorted by rating, strongest first.
+        let strongest = |k: usize| self.prefix_sums[next + k] - self.prefix_sums[next];
+        let weakest = |k: usize| self.prefix_sums[count] - self.prefix_sums[count - k];
+
+        // The mean of a team ends between the lowest and highest mean it can
+        // reach with any number of remaining players it has room for.
+        let mut highest_floor = f64::NEG_INFINITY;
+        let mut lowest_ceiling = f64::INFINITY;
+        for team in 0..self.teams {
+            let size = self.sizes[team];
+            let fewest = self.min_size.saturating_sub(size);
+            let most = (self.max_size - size).min(remaining);
+            if fewest > most {
+                return f64::INFINITY;
+            }
+            let mean = |total: f64, k: usize| total / (size + k) as f64;
+            let counts = (fewest..=most).filter(|k| size + k > 0);
+            let floor = counts
+                .clone()
+                .map(|k| mean(self.totals[team] + weakest(k), k))
+                .fold(f64::INFINITY, f64::min);
+            let ceiling = counts
+                .map(|k| mean(self.totals[team] + strongest(k), k))
+                .fold(f64::NEG_INFINITY, f64::max);
+            highest_floor = highest_floor.max(floor);
+            lowest_ceiling = lowest_ceiling.min(ceiling);
+        }
+        highest_floor - lowest_ceiling
+    }
+}
+
+pub fn to_win_probability_rows(probabilities: &[WinProbability]) -> Vec<WinProbabilityRow> {
+    probabilities
+        .iter()
+        .map(|probability| WinProbabilityRow {
+            player: probability.player.clone(),
+            rating: format!(
+                "{:.1} ±{:.1}",
+                probability.rating,
+                2.0 * probability.deviation
+            ),
+            win_probability: format!("{:.1}%", probability.probability * 100.0),
+        })
+        .collect()
+}
+
+pub fn to_team_rows(teams: &[Team]) -> Vec<TeamRow> {
+    teams
+        .iter()
+        .enumerate()
+        .map(|(index, team)| TeamRow {
+            team: index + 1,
+            players: team.players.join(", "),
+            strength: format!("{:.1}", team.strength),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::rating::RatingAlgorithm;
+
+    #[test]
+    fn win_probabilities_sum_to_one() {
+        let algorithm = RatingAlgorithm::TrueSkill;
+        let lineup = vec![
+            ("alice".to_string(), algorithm.initial_rating()),
+            ("bob".to_string(), algorithm.initial_rating()),
+            ("carol".to_string(), algorithm.initial_rating()),
+        ];
+
+        let probabilities = win_probabilities(&lineup, algorithm.performance_spread());
+        assert_eq!(probabilities.len(), 3);
+        for probability in &probabilities {
+            assert!((probability.probability - 1.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn balance_teams_minimizes_gap() {
+        let players = [("a", 10.0), ("b", 9.0), ("c", 5.0), ("d", 4.0), ("e", 2.0)]
+            .iter()
+            .map(|(name, rating)| (name.to_string(), *rating))
+            .collect::<Vec<_>>();
+
+        let teams = balance_teams(&players, 2);
+        assert_eq!(teams.len(), 2);
+        assert_eq!(strength_gap(&teams), 0.0);
+        assert_eq!(teams[0].players.len() + teams[1].players.len(), 5);
+        assert!(teams.iter().all(|t| t.players.len() >= 2));
+    }
+
+    #[test]
+    fn balance_teams_compares_mean_ratings_of_uneven_teams() {
+        let players = [("a", 9.0), ("b", 6.0), ("c", 6.0), ("d", 6.0), ("e", 3.0)]
+            .iter()
+            .map(|(name, rating)| (name.to_string(), *rating))
+            .collect::<Vec<_>>();
+
+        // Summing ratings would pair a with a 6 against the other three,
+        // a team of 7.5 on average against one of 5.
+        let teams = balance_teams(&players, 2);
+        assert_eq!(strength_gap(&teams), 0.0);
+        let pair = teams.iter().find(|t| t.players.len() == 2).unwrap();
+        assert!(!pair.players.contains(&"a".to_string()));
+        assert!(!pair.players.contains(&"e".to_string()));
+        assert_eq!(pair.strength, 6.0);
+    }
+
+    #[test]
+    fn balance_teams_puts_strongest_player_in_larger_team() {
+        let players = [("a", 100.0), ("b", 1.0), ("c", 1.0), ("d", 1.0), ("e", 1.0)]
+            .iter()
+            .map(|(name, rating)| (name.to_string(), *rating))
+            .collect::<Vec<_>>();
+
+        let teams = balance_teams(&players, 2);
+        assert_eq!(teams[0].players.len(), 3);
+        assert!(teams[0].players.contains(&"a".to_string()));
+        assert_eq!(teams[1].players.len(), 2);
+    }
+
+    #[test]
+    fn balance_teams_respects_team_sizes() {
+        let players = [("a", 100.0), ("b", 1.0), ("c", 1.0), ("d", 1.0)]
+            .iter()
+            .map(|(name, rating)| (name.to_string(), *rating))
+            .collect::<Vec<_>>();
+
+        let teams = balance_teams(&players, 2);
+        assert!(teams.iter().all(|t| t.players.len() == 2));
+        assert_eq!(teams[0].players, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn balance_teams_finds_optimum_for_full_lineup() {
+        let players = (1..=MAX_BALANCED_PLAYERS)
+            .map(|i| (format!("p{:02}", i), (i * i) as f64 + 0.5 * i as f64))
+            .collect::<Vec<_>>();
+
+        for teams in 2..=4 {
+            let result = balance_teams(&players, teams);
+            assert_eq!(result.len(), teams);
+            assert!(
+                result
+                    .iter()
+                    .all(|t| t.players.len() == MAX_BALANCED_PLAYERS / teams
+                        || t.players.len() == MAX_BALANCED_PLAYERS.div_ceil(teams))
+            );
+        }
+
+        let small = players[..8].to_vec();
+        assert_eq!(
+            strength_gap(&balance_teams(&small, 2)),
+            brute_force_gap(&small, 2)
+        );
+    }
+
+    fn brute_force_gap(players: &[(String, f64)], teams: usize) -> f64 {
+        let mut best = f64::INFINITY;
+        for code in 0..teams.pow(players.len() as u32) {
+            let assignment = (0..players.len())
+                .map(|i| code / teams.pow(i as u32) % teams)
+                .collect::<Vec<_>>();
+            let sizes = (0..teams)
+                .map(|team| assignment.iter().filter(|t| **t == team).count())
+                .collect::<Vec<_>>();
+            if sizes.iter().max().unwrap() - sizes.iter().min().unwrap() > 1 {
+                continue;
+            }
+            best = best.min(gap(
+                (0..teams).map(|team| strength(players, &assignment, team))
+            ));
+        }
+        best
+    }
+}
@@ -0,0 +1,213 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::player;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tabled::Tabled;
+
+mod glicko2;
+mod trueskill;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RatingAlgorithm {
+    /// Glicko-2, with every match treated as a rating period in which each
+    /// player faced all the others.
+    Glicko2,
+    /// A TrueSkill-style Bayesian model over the full ranking of a match,
+    /// using the Weng-Lin Bradley-Terry approximation.
+    #[default]
+    TrueSkill,
+}
+
+/// A skill estimate and its uncertainty, both on the scale of the algorithm
+/// that produced them.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    rating: f64,
+    deviation: f64,
+    matches: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlayerRating {
+    player: String,
+    #[serde(flatten)]
+    rating: Rating,
+}
+
+#[derive(Tabled)]
+pub struct RatingRow {
+    rank: usize,
+    player: String,
+    rating: String,
+    uncertainty: String,
+    matches: usize,
+}
+
+impl RatingAlgorithm {
+    pub fn parse(algorithm: &str) -> Result<RatingAlgorithm, String> {
+        match algorithm {
+            "glicko2" => Ok(RatingAlgorithm::Glicko2),
+            "trueskill" => Ok(RatingAlgorithm::TrueSkill),
+            other => Err(format!(
+                "Unknown rating algorithm {}. Supported algorithms are glicko2 and trueskill.",
+                other
+            )),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self {
+            RatingAlgorithm::Glicko2 => "glicko2",
+            RatingAlgorithm::TrueSkill => "trueskill",
+        }
+    }
+
+    /// The rating of a player without matches.
+    pub fn initial_rating(&self) -> Rating {
+        match self {
+            RatingAlgorithm::Glicko2 => glicko2::initial_rating(),
+            RatingAlgorithm::TrueSkill => trueskill::initial_rating(),
+        }
+    }
+
+    /// How much the performance of a player varies from match to match, on
+    /// the rating scale.
+    pub fn performance_spread(&self) -> f64 {
+        match self {
+            RatingAlgorithm::Glicko2 => glicko2::PERFORMANCE_SPREAD,
+            RatingAlgorithm::TrueSkill => trueskill::BETA,
+        }
+    }
+}
+
+impl Rating {
+    pub fn get_rating(&self) -> f64 {
+        self.rating
+    }
+
+    pub fn get_deviation(&self) -> f64 {
+        self.deviation
+    }
+
+    /// The lower end of the 95% interval, so that players with few matches
+    /// do not top the ranking on luck.
+    pub fn conservative(&self) -> f64 {
+        self.rating - 2.0 * self.deviation
+    }
+}
+
+impl PlayerRating {
+    pub fn get_player(&self) -> &str {
+        &self.player
+    }
+
+    pub fn get_rating(&self) -> &Rating {
+        &self.rating
+    }
+}
+
+/// Rates the players of `games`, which must be ordered by date. Games with
+/// a single player do not change any rating.
+pub fn rate(games: &[Game], algorithm: RatingAlgorithm) -> BTreeMap<String, Rating> {
+    match algorithm {
+        RatingAlgorithm::Glicko2 => glicko2::rate(games),
+        RatingAlgorithm::TrueSkill => trueskill::rate(games),
+    }
+}
+
+/// Removes the ratings of guests, who take part in the matches but not in
+/// the rankings.
+pub fn exclude_guests(ratings: &mut BTreeMap<String, Rating>) {
+    ratings.retain(|player, _| !player::is_guest(player));
+}
+
+/// Orders the ratings by their conservative estimate, best first.
+pub fn rank(ratings: &BTreeMap<String, Rating>) -> Vec<PlayerRating> {
+    let mut ranked = ratings
+        .iter()
+        .map(|(player, rating)| PlayerRating {
+            player: player.clone(),
+            rating: *rating,
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|a, b| {
+        b.rating
+            .conservative()
+            .total_cmp(&a.rating.conservative())
+            .then(a.player.cmp(&b.player))
+    });
+    ranked
+}
+
+pub fn to_rating_rows(ranked: &[PlayerRating]) -> Vec<RatingRow> {
+    ranked
+        .iter()
+        .enumerate()
+        .map(|(index, player_rating)| RatingRow {
+            rank: index + 1,
+            player: player_rating.player.clone(),
+            rating: format!("{:.1}", player_rating.rating.rating),
+            uncertainty: format!("±{:.1}", 2.0 * player_rating.rating.deviation),
+            matches: player_rating.rating.matches,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn create_game(scores: &[&str], date: &str) -> Game {
+        Game::build(
+            "catan".to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn create_games() -> Vec<Game> {
+        vec![
+            create_game(&["alice::10", "bob::8", "carol::2"], "2025-01-01"),
+            create_game(&["alice::12", "bob::9", "carol::4"], "2025-01-02"),
+            create_game(&["alice::11", "bob::3", "carol::7"], "2025-01-03"),
+            create_game(&["alice::5"], "2025-01-04"),
+        ]
+    }
+
+    #[test]
+    fn parse_algorithm() {
+        assert_eq!(
+            RatingAlgorithm::parse("glicko2").unwrap(),
+            RatingAlgorithm::Glicko2
+        );
+        assert_eq!(
+            RatingAlgorithm::parse("elo").unwrap_err(),
+            "Unknown rating algorithm elo. Supported algorithms are glicko2 and trueskill."
+        );
+    }
+
+    #[test]
+    fn rank_orders_players_for_both_algorithms() {
+        for algorithm in [RatingAlgorithm::Glicko2, RatingAlgorithm::TrueSkill] {
+            let ratings = rate(&create_games(), algorithm);
+            let players = rank(&ratings)
+                .into_iter()
+                .map(|r| r.player)
+                .collect::<Vec<_>>();
+            assert_eq!(players, vec!["alice", "bob", "carol"]);
+            assert_eq!(ratings["alice"].matches, 3);
+        }
+    }
+
+    #[test]
+    fn rating_uncertainty_shrinks_with_matches() {
+        for algorithm in [RatingAlgorithm::Glicko2, RatingAlgorithm::TrueSkill] {
+            let games = create_games();
+            let after_one = rate(&games[..1], algorithm);
+            let after_three = rate(&games, algorithm);
+            assert!(after_three["bob"].deviation < after_one["bob"].deviation);
+        }
+    }
+}
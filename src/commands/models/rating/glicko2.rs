@@ -0,0 +1,189 @@
+use super::Rating;
+use crate::commands::models::game::Game;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+const SCALE: f64 = 173.7178;
+const DEFAULT_RATING: f64 = 1500.0;
+const DEFAULT_DEVIATION: f64 = 350.0;
+const DEFAULT_VOLATILITY: f64 = 0.06;
+/// Constrains the change in volatility over time.
+const TAU: f64 = 0.5;
+const CONVERGENCE_TOLERANCE: f64 = 0.000001;
+/// Chosen so that two certain players get the Glicko-2 expected score.
+pub const PERFORMANCE_SPREAD: f64 = SCALE / std::f64::consts::SQRT_2;
+
+/// A player on the internal Glicko-2 scale.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    mu: f64,
+    phi: f64,
+    sigma: f64,
+    matches: usize,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            mu: 0.0,
+            phi: DEFAULT_DEVIATION / SCALE,
+            sigma: DEFAULT_VOLATILITY,
+            matches: 0,
+        }
+    }
+}
+
+impl State {
+    fn to_rating(self) -> Rating {
+        Rating {
+            rating: self.mu * SCALE + DEFAULT_RATING,
+            deviation: self.phi * SCALE,
+            matches: self.matches,
+        }
+    }
+}
+
+pub fn initial_rating() -> Rating {
+    State::default().to_rating()
+}
+
+pub fn rate(games: &[Game]) -> BTreeMap<String, Rating> {
+    let mut states: HashMap<String, State> = HashMap::new();
+
+    for game in games {
+        let ranks = game.get_ranks();
+        if ranks.len() < 2 {
+            continue;
+        }
+
+        let before = ranks
+            .keys()
+            .map(|player| (player, states.get(player).copied().unwrap_or_default()))
+            .collect::<HashMap<_, _>>();
+        for (player, rank) in &ranks {
+            let results = ranks
+                .iter()
+                .filter(|(other, _)| *other != player)
+                .map(|(other, other_rank)| {
+                    let score = match rank.cmp(other_rank) {
+                        std::cmp::Ordering::Less => 1.0,
+                        std::cmp::Ordering::Equal => 0.5,
+                        std::cmp::Ordering::Greater => 0.0,
+                    };
+                    (before[other], score)
+                })
+                .collect::<Vec<_>>();
+            states.insert(player.clone(), update(before[player], &results));
+        }
+    }
+
+    states
+        .into_iter()
+        .map(|(player, state)| (player, state.to_rating()))
+        .collect()
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi.powi(2) / PI.powi(2)).sqrt()
+}
+
+fn expected_score(mu: f64, opponent: &State) -> f64 {
+    1.0 / (1.0 + (-g(opponent.phi) * (mu - opponent.mu)).exp())
+}
+
+/// Applies one rating period with the given opponents and scores.
+fn update(state: State, results: &[(State, f64)]) -> State {
+    let variance = 1.0
+        / results
+            .iter()
+            .map(|(opponent, _)| {
+                let expected = expected_score(state.mu, opponent);
+                g(opponent.phi).powi(2) * expected * (1.0 - expected)
+            })
+            .sum::<f64>();
+    let improvement = results
+        .iter()
+        .map(|(opponent, score)| g(opponent.phi) * (score - expected_score(state.mu, opponent)))
+        .sum::<f64>();
+    let delta = variance * improvement;
+
+    let sigma = volatility(state, variance, delta);
+    let pre_period_phi = (state.phi.powi(2) + sigma.powi(2)).sqrt();
+    let phi = 1.0 / (1.0 / pre_period_phi.powi(2) + 1.0 / variance).sqrt();
+
+    State {
+        mu: state.mu + phi.powi(2) * improvement,
+        phi,
+        sigma,
+        matches: state.matches + 1,
+    }
+}
+
+/// Finds the new volatility with the Illinois algorithm.
+fn volatility(state: State, variance: f64, delta: f64) -> f64 {
+    let a = state.sigma.powi(2).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        ex * (delta.powi(2) - state.phi.powi(2) - variance - ex)
+            / (2.0 * (state.phi.powi(2) + variance + ex).powi(2))
+            - (x - a) / TAU.powi(2)
+    };
+
+    let mut lower = a;
+    let mut upper = if delta.powi(2) > state.phi.powi(2) + variance {
+        (delta.powi(2) - state.phi.powi(2) - variance).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+
+    let mut f_lower = f(lower);
+    let mut f_upper = f(upper);
+    while (upper - lower).abs() > CONVERGENCE_TOLERANCE {
+        let next = lower + (lower - upper) * f_lower / (f_upper - f_lower);
+        let f_next = f(next);
+        if f_next * f_upper <= 0.0 {
+            lower = upper;
+            f_lower = f_upper;
+        } else {
+            f_lower /= 2.0;
+        }
+        upper = next;
+        f_upper = f_next;
+    }
+
+    (lower / 2.0).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The worked example from Glickman's description of Glicko-2.
+    #[test]
+    fn update_matches_reference_example() {
+        let player = State {
+            mu: 0.0,
+            phi: 200.0 / SCALE,
+            sigma: 0.06,
+            matches: 0,
+        };
+        let opponent = |rating: f64, deviation: f64| State {
+            mu: (rating - DEFAULT_RATING) / SCALE,
+            phi: deviation / SCALE,
+            ..State::default()
+        };
+        let results = [
+            (opponent(1400.0, 30.0), 1.0),
+            (opponent(1550.0, 100.0), 0.0),
+            (opponent(1700.0, 300.0), 0.0),
+        ];
+
+        let rating = update(player, &results).to_rating();
+        assert!((rating.rating - 1464.06).abs() < 0.01);
+        assert!((rating.deviation - 151.52).abs() < 0.01);
+    }
+}
@@ -0,0 +1,118 @@
+use super::Rating;
+use crate::commands::models::game::Game;
+use std::collections::{BTreeMap, HashMap};
+
+const DEFAULT_MU: f64 = 25.0;
+const DEFAULT_SIGMA: f64 = DEFAULT_MU / 3.0;
+/// The performance variability within a single match.
+pub const BETA: f64 = DEFAULT_SIGMA / 2.0;
+/// Keeps the variance from collapsing to zero.
+const KAPPA: f64 = 0.0001;
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    mu: f64,
+    sigma: f64,
+    matches: usize,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            mu: DEFAULT_MU,
+            sigma: DEFAULT_SIGMA,
+            matches: 0,
+        }
+    }
+}
+
+pub fn initial_rating() -> Rating {
+    let state = State::default();
+    Rating {
+        rating: state.mu,
+        deviation: state.sigma,
+        matches: 0,
+    }
+}
+
+/// Weng-Lin Bradley-Terry model with full pairing: every player of a match
+/// is compared with every other player of its ranking.
+pub fn rate(games: &[Game]) -> BTreeMap<String, Rating> {
+    let mut states: HashMap<String, State> = HashMap::new();
+
+    for game in games {
+        let ranks = game.get_ranks();
+        if ranks.len() < 2 {
+            continue;
+        }
+
+        let before = ranks
+            .keys()
+            .map(|player| (player, states.get(player).copied().unwrap_or_default()))
+            .collect::<HashMap<_, _>>();
+        for (player, rank) in &ranks {
+            let state = before[player];
+            let mut omega = 0.0;
+            let mut delta = 0.0;
+            for (other, other_rank) in ranks.iter().filter(|(other, _)| *other != player) {
+                let opponent = before[other];
+                let c = (state.sigma.powi(2) + opponent.sigma.powi(2) + 2.0 * BETA.powi(2)).sqrt();
+                let p = 1.0 / (1.0 + ((opponent.mu - state.mu) / c).exp());
+                let score = match rank.cmp(other_rank) {
+                    std::cmp::Ordering::Less => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Greater => 0.0,
+                };
+                let gamma = state.sigma / c;
+
+                omega += state.sigma.powi(2) / c * (score - p);
+                delta += gamma * state.sigma.powi(2) / c.powi(2) * p * (1.0 - p);
+            }
+
+            states.insert(
+                player.clone(),
+                State {
+                    mu: state.mu + omega,
+                    sigma: state.sigma * (1.0 - delta).max(KAPPA).sqrt(),
+                    matches: state.matches + 1,
+                },
+            );
+        }
+    }
+
+    states
+        .into_iter()
+        .map(|(player, state)| {
+            (
+                player,
+                Rating {
+                    rating: state.mu,
+                    deviation: state.sigma,
+                    matches: state.matches,
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::rating::tests::create_game;
+
+    #[test]
+    fn rate_moves_winner_up_and_loser_down() {
+        let ratings = rate(&[create_game(&["alice::10", "bob::5"], "2025-01-01")]);
+
+        assert!(ratings["alice"].rating > DEFAULT_MU);
+        assert!(ratings["bob"].rating < DEFAULT_MU);
+        assert!(ratings["alice"].deviation < DEFAULT_SIGMA);
+    }
+
+    #[test]
+    fn rate_tie_keeps_ratings() {
+        let ratings = rate(&[create_game(&["alice::10", "bob::10"], "2025-01-01")]);
+
+        assert!((ratings["alice"].rating - DEFAULT_MU).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,495 @@
+use crate::commands::models::catalog::Catalog;
+use crate::commands::models::game::Game;
+use crate::commands::models::merge::Records;
+use crate::commands::models::player;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tabled::Tabled;
+
+pub const FILE_NAME_DATA: &str = "seasons.json";
+
+const F1_POINTS: [u32; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
+
+/// How a match turns into points.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PointsScheme {
+    /// A sole winner gets `win`, a shared win counts as a draw and everyone
+    /// else gets `loss`.
+    WinDrawLoss { win: u32, draw: u32, loss: u32 },
+    /// Points by finishing position, starting from first. Tied players all
+    /// get the points of their shared position; positions past the end of
+    /// the list get nothing.
+    Placement { points: Vec<u32> },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Season {
+    name: String,
+    start: NaiveDate,
+    end: NaiveDate,
+    /// The games counted for the season. Empty means every game.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    games: Vec<String>,
+    scheme: PointsScheme,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Seasons {
+    seasons: HashMap<String, Season>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Standing {
+    player: String,
+    matches: usize,
+    wins: usize,
+    points: u32,
+}
+
+#[derive(Tabled)]
+pub struct SeasonRow {
+    name: String,
+    start: String,
+    end: String,
+    games: String,
+    scheme: String,
+}
+
+#[derive(Tabled)]
+pub struct StandingRow {
+    rank: usize,
+    player: String,
+    matches: usize,
+    wins: usize,
+    points: u32,
+}
+
+impl Standing {
+    pub fn get_player(&self) -> &str {
+        &self.player
+    }
+
+    pub fn get_matches(&self) -> usize {
+        self.matches
+    }
+
+    pub fn get_wins(&self) -> usize {
+        self.wins
+    }
+
+    pub fn get_points(&self) -> u32 {
+        self.points
+    }
+}
+
+impl PointsScheme {
+    /// Parses `W-D-L` points such as `3-1-0`, `f1`, or `placement:P1,P2,...`.
+    pub fn parse(scheme: &str) -> Result<PointsScheme, String> {
+        let error = || {
+            format!(
+                "Invalid points scheme {}. Use win-draw-loss points like 3-1-0, f1 or placement:10,6,4.",
+                scheme
+            )
+        };
+
+        if scheme == "f1" {
+            return Ok(PointsScheme::Placement {
+                points: F1_POINTS.to_vec(),
+            });
+        }
+        if let Some(points) = scheme.strip_prefix("placement:") {
+            let points = points
+                .split(',')
+                .map(|p| p.trim().parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| error())?;
+            return Ok(PointsScheme::Placement { points });
+        }
+
+        match scheme
+            .split('-')
+            .map(|p| p.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error())?
+            .as_slice()
+        {
+            [win, draw, loss] => Ok(PointsScheme::WinDrawLoss {
+                win: *win,
+                draw: *draw,
+                loss: *loss,
+            }),
+            _ => Err(error()),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            PointsScheme::WinDrawLoss { win, draw, loss } => format!("{}-{}-{}", win, draw, loss),
+            PointsScheme::Placement { points } if points == &F1_POINTS => "f1".to_string(),
+            PointsScheme::Placement { points } => format!(
+                "placement:{}",
+                points
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+        }
+    }
+
+    /// The points every player of `game` earns.
+    pub fn points(&self, game: &Game) -> HashMap<String, u32> {
+        match self {
+            PointsScheme::WinDrawLoss { win, draw, loss } => {
+                let winners = game.get_winners();
+                game.get_scores()
+                    .keys()
+                    .map(|player| {
+                        let points = match (winners.contains(player), winners.len()) {
+                            (true, 1) => *win,
+                            (true, _) => *draw,
+                            (false, _) => *loss,
+                        };
+                        (player.clone(), points)
+                    })
+                    .collect()
+            }
+            PointsScheme::Placement { points } => game
+                .get_ranks()
+                .into_iter()
+                .map(|(player, rank)| (player, points.get(rank - 1).copied().unwrap_or(0)))
+                .collect(),
+        }
+    }
+
+    /// Totals the points of `games` per player, best first.
+    pub fn standings<'a>(&self, games: impl Iterator<Item = &'a Game>) -> Vec<Standing> {
+        let mut standings: BTreeMap<String, Standing> = BTreeMap::new();
+        for game in games {
+            let winners = game.get_winners();
+            for (player, points) in self.points(game) {
+                let standing = standings.entry(player.clone()).or_insert_with(|| Standing {
+                    player: player.clone(),
+                    ..Standing::default()
+                });
+                standing.matches += 1;
+                standing.points += points;
+                if winners.contains(&player) {
+                    standing.wins += 1;
+                }
+            }
+        }
+
+        let mut standings = standings.into_values().collect::<Vec<_>>();
+        standings.sort_by(|a, b| {
+            b.points
+                .cmp(&a.points)
+                .then(b.wins.cmp(&a.wins))
+                .then(a.player.cmp(&b.player))
+        });
+        standings
+    }
+}
+
+impl Default for PointsScheme {
+    fn default() -> Self {
+        PointsScheme::WinDrawLoss {
+            win: 3,
+            draw: 1,
+            loss: 0,
+        }
+    }
+}
+
+impl Season {
+    pub fn build(
+        name: String,
+        start: NaiveDate,
+        end: NaiveDate,
+        games: Vec<String>,
+        scheme: PointsScheme,
+    ) -> Result<Season, String> {
+        if end < start {
+            return Err("The season cannot end before it starts.".to_string());
+        }
+
+        Ok(Season {
+            name,
+            start,
+            end,
+            games,
+            scheme,
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Records the games of the season under their catalog name, so that
+    /// an alias selects the matches of the game it refers to.
+    pub fn resolve_games(&mut self, catalog: &Catalog) {
+        let mut games = Vec::new();
+        for game in &self.games {
+            let game = catalog
+                .resolve(game)
+                .map_or(game.as_str(), |entry| entry.get_name());
+            if !games.iter().any(|g| g == game) {
+                games.push(game.to_string());
+            }
+        }
+        self.games = games;
+    }
+
+    pub fn includes(&self, game: &Game) -> bool {
+        (self.start..=self.end).contains(game.get_datetime())
+            && (self.games.is_empty() || self.games.iter().any(|g| g == game.get_name()))
+    }
+
+    /// Standings from the matches of `games` that belong to the season.
+    pub fn standings(&self, games: &[Game]) -> Vec<Standing> {
+        self.scheme
+            .standings(games.iter().filter(|game| self.includes(game)))
+    }
+}
+
+impl Seasons {
+    pub fn create_empty() -> Seasons {
+        Seasons {
+            seasons: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Season, String> {
+        self.seasons
+            .get(name)
+            .ok_or_else(|| format!("Season {} not found.", name))
+    }
+
+    pub fn add_season(&mut self, season: Season) -> Result<(), String> {
+        if self.seasons.contains_key(season.get_name()) {
+            return Err(format!("Season {} already exists.", season.get_name()));
+        }
+
+        self.seasons.insert(season.get_name().to_string(), season);
+        Ok(())
+    }
+
+    /// Replaces `old_name` with `new_name` in the games of every season.
+    /// Returns whether any season changed.
+    pub fn rename_game(&mut self, old_name: &str, new_name: &str) -> bool {
+        let mut changed = false;
+        for season in self.seasons.values_mut() {
+            if season.games.iter().any(|game| game == old_name) {
+                season
+                    .games
+                    .retain(|game| game != old_name && game != new_name);
+                season.games.push(new_name.to_string());
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    pub fn to_season_rows(&self) -> Vec<SeasonRow> {
+        let mut seasons = self.seasons.values().collect::<Vec<_>>();
+        seasons.sort_by_key(|season| (season.start, season.name.clone()));
+        seasons
+            .into_iter()
+            .map(|season| SeasonRow {
+                name: season.name.clone(),
+                start: season.start.to_string(),
+                end: season.end.to_string(),
+                games: if season.games.is_empty() {
+                    "all".to_string()
+                } else {
+                    season.games.join(", ")
+                },
+                scheme: season.scheme.describe(),
+            })
+            .collect()
+    }
+}
+
+impl Records for Seasons {
+    type Record = Season;
+
+    fn records(&self) -> Vec<&Season> {
+        let mut seasons = self.seasons.values().collect::<Vec<_>>();
+        seasons.sort_by_key(|season| season.get_name());
+        seasons
+    }
+
+    fn add_record(&mut self, season: Season) -> Result<(), String> {
+        self.add_season(season)
+    }
+}
+
+/// Leaves guests out of `standings`. The points of the other players are
+/// kept, as the guests still played the matches.
+pub fn exclude_guests(standings: &mut Vec<Standing>) {
+    standings.retain(|standing| !player::is_guest(&standing.player));
+}
+
+pub fn to_standing_rows(standings: &[Standing]) -> Vec<StandingRow> {
+    standings
+        .iter()
+        .enumerate()
+        .map(|(index, standing)| StandingRow {
+            rank: index + 1,
+            player: standing.player.clone(),
+            matches: standing.matches,
+            wins: standing.wins,
+            points: standing.points,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::catalog::CatalogEntry;
+
+    fn create_game(game_name: &str, scores: &[&str], date: &str) -> Game {
+        Game::build(
+            game_name.to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn parse_schemes() {
+        assert_eq!(
+            PointsScheme::parse("3-1-0").unwrap(),
+            PointsScheme::default()
+        );
+        assert_eq!(
+            PointsScheme::parse("f1").unwrap(),
+            PointsScheme::Placement {
+                points: F1_POINTS.to_vec()
+            }
+        );
+        assert_eq!(
+            PointsScheme::parse("placement:5,3,1").unwrap(),
+            PointsScheme::Placement {
+                points: vec![5, 3, 1]
+            }
+        );
+        assert!(PointsScheme::parse("3-1").is_err());
+    }
+
+    #[test]
+    fn win_draw_loss_points() {
+        let scheme = PointsScheme::default();
+        let sole = create_game("catan", &["alice::10", "bob::5"], "2025-01-01");
+        let shared = create_game("catan", &["alice::10", "bob::10", "carol::1"], "2025-01-01");
+
+        assert_eq!(scheme.points(&sole)["alice"], 3);
+        assert_eq!(scheme.points(&sole)["bob"], 0);
+        assert_eq!(scheme.points(&shared)["bob"], 1);
+        assert_eq!(scheme.points(&shared)["carol"], 0);
+    }
+
+    #[test]
+    fn placement_points() {
+        let scheme = PointsScheme::parse("placement:5,3").unwrap();
+        let game = create_game("catan", &["alice::10", "bob::10", "carol::1"], "2025-01-01");
+
+        let points = scheme.points(&game);
+        assert_eq!(points["alice"], 5);
+        assert_eq!(points["bob"], 5);
+        assert_eq!(points["carol"], 0);
+    }
+
+    #[test]
+    fn standings_only_count_matches_in_season() {
+        let season = Season::build(
+            "q1".to_string(),
+            date("2025-01-01"),
+            date("2025-03-31"),
+            vec!["catan".to_string()],
+            PointsScheme::default(),
+        )
+        .unwrap();
+        let games = vec![
+            create_game("catan", &["alice::10", "bob::5"], "2025-01-10"),
+            create_game("catan", &["alice::1", "bob::5"], "2025-02-10"),
+            create_game("catan", &["alice::1", "bob::5"], "2025-04-01"),
+            create_game("azul", &["alice::1", "bob::5"], "2025-02-11"),
+            create_game("catan", &["alice::10", "carol::5"], "2025-03-31"),
+        ];
+
+        let standings = season.standings(&games);
+        assert_eq!(standings[0].player, "alice");
+        assert_eq!(standings[0].points, 6);
+        assert_eq!(standings[0].matches, 3);
+        assert_eq!(standings[1].player, "bob");
+        assert_eq!(standings[1].points, 3);
+    }
+
+    #[test]
+    fn rename_game_merges_duplicates() {
+        let mut seasons = Seasons::create_empty();
+        let season = Season::build(
+            "q1".to_string(),
+            date("2025-01-01"),
+            date("2025-03-31"),
+            vec!["catna".to_string(), "Catan".to_string(), "azul".to_string()],
+            PointsScheme::default(),
+        )
+        .unwrap();
+        seasons.add_season(season).unwrap();
+
+        assert!(seasons.rename_game("catna", "Catan"));
+        assert_eq!(seasons.get("q1").unwrap().games, vec!["azul", "Catan"]);
+        assert!(!seasons.rename_game("chess", "Chess"));
+    }
+
+    #[test]
+    fn resolve_games_uses_catalog_names() {
+        let mut catalog = Catalog::create_empty();
+        catalog
+            .add_game(CatalogEntry::build("Catan", None, None, None, None).unwrap())
+            .unwrap();
+        catalog.add_alias("Catan", "Settlers").unwrap();
+        let mut season = Season::build(
+            "q1".to_string(),
+            date("2025-01-01"),
+            date("2025-03-31"),
+            vec![
+                "settlers".to_string(),
+                "Catan".to_string(),
+                "azul".to_string(),
+            ],
+            PointsScheme::default(),
+        )
+        .unwrap();
+
+        season.resolve_games(&catalog);
+        assert_eq!(season.games, vec!["Catan", "azul"]);
+        assert!(season.includes(&create_game("Catan", &["alice::1"], "2025-01-10")));
+    }
+
+    #[test]
+    fn build_rejects_reversed_range() {
+        let season = Season::build(
+            "q1".to_string(),
+            date("2025-03-31"),
+            date("2025-01-01"),
+            vec![],
+            PointsScheme::default(),
+        );
+        assert_eq!(
+            season.unwrap_err(),
+            "The season cannot end before it starts."
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use crate::commands::models::game::{self, Game, GameRow};
+use crate::commands::models::merge::Records;
+use crate::commands::models::player;
+use crate::commands::models::season::{PointsScheme, Standing};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tabled::Tabled;
+
+pub const FILE_NAME_DATA: &str = "sessions.json";
+
+/// A gaming evening: the matches recorded under it and who attended.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Session {
+    id: String,
+    date: NaiveDate,
+    /// Everyone who showed up, sorted by name, whether they played or not.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attendees: Vec<String>,
+    scheme: PointsScheme,
+    #[serde(default)]
+    ended: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Sessions {
+    sessions: BTreeMap<String, Session>,
+}
+
+/// What happened in a session, built from its matches.
+#[derive(Serialize, Debug)]
+pub struct SessionReport {
+    session: String,
+    date: NaiveDate,
+    ended: bool,
+    attendees: Vec<String>,
+    matches: Vec<Game>,
+    standings: Vec<Standing>,
+    /// The games each player played, with how many times.
+    played: BTreeMap<String, BTreeMap<String, usize>>,
+    winners: Vec<String>,
+    /// Total length in minutes of the matches with a duration.
+    play_time: u32,
+    untimed_matches: usize,
+}
+
+#[derive(Tabled)]
+pub struct SessionRow {
+    id: String,
+    date: String,
+    attendees: String,
+    scheme: String,
+    status: String,
+}
+
+#[derive(Tabled)]
+pub struct SessionStandingRow {
+    rank: usize,
+    player: String,
+    matches: usize,
+    wins: usize,
+    points: u32,
+    games: String,
+}
+
+impl Session {
+    pub fn build(
+        id: String,
+        date: NaiveDate,
+        attendees: Vec<String>,
+        scheme: PointsScheme,
+    ) -> Result<Session, String> {
+        if id.trim().is_empty() {
+            return Err("The session id cannot be empty.".to_string());
+        }
+
+        let mut session = Session {
+            id: id.trim().to_string(),
+            date,
+            attendees: Vec::new(),
+            scheme,
+            ended: false,
+        };
+        session.add_attendees(attendees);
+        Ok(session)
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_attendees(&self) -> &[String] {
+        &self.attendees
+    }
+
+    pub fn get_scheme(&self) -> &PointsScheme {
+        &self.scheme
+    }
+
+    pub fn add_attendees(&mut self, players: impl IntoIterator<Item = String>) {
+        for player in players {
+            if !self.attendees.contains(&player) {
+                self.attendees.push(player);
+            }
+        }
+        self.attendees.sort();
+    }
+
+    pub fn check_open(&self) -> Result<(), String> {
+        if self.ended {
+            return Err(format!("Session {} has already ended.", self.id));
+        }
+        Ok(())
+    }
+
+    pub fn end(&mut self) -> Result<(), String> {
+        self.check_open()?;
+        self.ended = true;
+        Ok(())
+    }
+
+    /// Summarizes the matches of `games` recorded under the session, scored
+    /// with `scheme`.
+    pub fn report(&self, games: &[Game], scheme: &PointsScheme) -> SessionReport {
+        let matches = games
+            .iter()
+            .filter(|game| game.get_session() == Some(self.id.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let standings = scheme.standings(matches.iter());
+        let winners = winners(&standings);
+
+        let mut played: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+        for game in &matches {
+            for player in game.get_scores().keys() {
+                *played
+                    .entry(player.clone())
+                    .or_default()
+                    .entry(game.get_name().to_string())
+                    .or_default() += 1;
+            }
+        }
+
+        let durations = matches
+            .iter()
+            .filter_map(|game| game.get_details().get_duration())
+            .collect::<Vec<_>>();
+
+        SessionReport {
+            session: self.id.clone(),
+            date: self.date,
+            ended: self.ended,
+            attendees: self.attendees.clone(),
+            untimed_matches: matches.len() - durations.len(),
+            play_time: durations.iter().sum(),
+            matches,
+            standings,
+            played,
+            winners,
+        }
+    }
+}
+
+/// The players with the most points of `standings`, which are sorted best
+/// first.
+fn winners(standings: &[Standing]) -> Vec<String> {
+    match standings.first() {
+        Some(best) => standings
+            .iter()
+            .filter(|standing| standing.get_points() == best.get_points())
+            .map(|standing| standing.get_player().to_string())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+impl SessionReport {
+    /// Leaves guests out of the standings and the winners. They are still
+    /// attendees and their matches are still listed.
+    pub fn exclude_guests(&mut self) {
+        self.standings
+            .retain(|standing| !player::is_guest(standing.get_player()));
+        self.winners = winners(&self.standings);
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "Session {} on {}, {}.\nAttendees: {}.",
+            self.session,
+            self.date,
+            if self.ended { "ended" } else { "in progress" },
+            if self.attendees.is_empty() {
+                "none".to_string()
+            } else {
+                self.attendees.join(", ")
+            }
+        )
+    }
+
+    pub fn to_match_rows(&self) -> Vec<GameRow> {
+        game::from_vec_to_game_rows(self.matches.clone())
+    }
+
+    pub fn to_standing_rows(&self) -> Vec<SessionStandingRow> {
+        self.standings
+            .iter()
+            .enumerate()
+            .map(|(index, standing)| SessionStandingRow {
+                rank: index + 1,
+                player: standing.get_player().to_string(),
+                matches: standing.get_matches(),
+                wins: standing.get_wins(),
+                points: standing.get_points(),
+                games: self
+                    .played
+                    .get(standing.get_player())
+                    .map(|games| {
+                        games
+                            .iter()
+                            .map(|(game, count)| match count {
+                                1 => game.clone(),
+                                _ => format!("{} x{}", game, count),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    pub fn outcome(&self) -> String {
+        let winner = match self.standings.first() {
+            None => return format!("No matches recorded in session {}.", self.session),
+            Some(best) if self.winners.len() == 1 => {
+                format!(
+                    "Won by {} with {} points.",
+                    self.winners[0],
+                    best.get_points()
+                )
+            }
+            Some(best) => format!(
+                "Shared by {} with {} points each.",
+                self.winners.join(", "),
+                best.get_points()
+            ),
+        };
+        let play_time = match self.untimed_matches {
+            0 => format!(
+                "Total play time: {}.",
+                game::format_duration(self.play_time)
+            ),
+            untimed => format!(
+                "Total play time: {} ({} of {} matches without a duration).",
+                game::format_duration(self.play_time),
+                untimed,
+                self.matches.len()
+            ),
+        };
+        format!("{}\n{}", winner, play_time)
+    }
+}
+
+impl Sessions {
+    pub fn create_empty() -> Sessions {
+        Sessions {
+            sessions: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Result<&Session, String> {
+        self.sessions
+            .get(id)
+            .ok_or_else(|| format!("Session {} not found.", id))
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Result<&mut Session, String> {
+        self.sessions
+            .get_mut(id)
+            .ok_or_else(|| format!("Session {} not found.", id))
+    }
+
+    pub fn add_session(&mut self, session: Session) -> Result<(), String> {
+        if self.sessions.contains_key(session.get_id()) {
+            return Err(format!("Session {} already exists.", session.get_id()));
+        }
+
+        self.sessions.insert(session.get_id().to_string(), session);
+        Ok(())
+    }
+
+    /// Lists `player` as `new_name` among the attendees of every session.
+    pub fn rename_player(&mut self, player: &str, new_name: &str) {
+        for session in self.sessions.values_mut() {
+            if session.attendees.iter().any(|attendee| attendee == player) {
+                session.attendees.retain(|attendee| attendee != player);
+                session.add_attendees([new_name.to_string()]);
+            }
+        }
+    }
+
+    pub fn to_session_rows(&self) -> Vec<SessionRow> {
+        let mut sessions = self.sessions.values().collect::<Vec<_>>();
+        sessions.sort_by_key(|session| (session.date, session.id.clone()));
+        sessions
+            .into_iter()
+            .map(|session| SessionRow {
+                id: session.id.clone(),
+                date: session.date.to_string(),
+                attendees: session.attendees.join(", "),
+                scheme: session.scheme.describe(),
+                status: if session.ended {
+                    "ended"
+                } else {
+                    "in progress"
+                }
+                .to_string(),
+            })
+            .collect()
+    }
+}
+
+impl Records for Sessions {
+    type Record = Session;
+
+    fn records(&self) -> Vec<&Session> {
+        self.sessions.values().collect()
+    }
+
+    fn add_record(&mut self, session: Session) -> Result<(), String> {
+        self.add_session(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::game::MatchDetails;
+
+    fn create_game(game_name: &str, scores: &[&str], session: Option<&str>) -> Game {
+        let mut game = Game::build(
+            game_name.to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some("2025-08-07"),
+        )
+        .unwrap();
+        if let Some(session) = session {
+            game.set_session(session.to_string());
+        }
+        game
+    }
+
+    fn create_session() -> Session {
+        Session::build(
+            "friday".to_string(),
+            NaiveDate::from_ymd_opt(2025, 8, 7).unwrap(),
+            vec!["bob".to_string(), "alice".to_string(), "bob".to_string()],
+            PointsScheme::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn build_sorts_attendees() {
+        let session = create_session();
+        assert_eq!(session.get_attendees(), ["alice", "bob"]);
+        assert!(
+            Session::build(
+                " ".to_string(),
+                NaiveDate::from_ymd_opt(2025, 8, 7).unwrap(),
+                Vec::new(),
+                PointsScheme::default()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn end_only_once() {
+        let mut session = create_session();
+
+        assert!(session.end().is_ok());
+        assert_eq!(
+            session.end().unwrap_err(),
+            "Session friday has already ended."
+        );
+    }
+
+    #[test]
+    fn report_counts_only_session_matches() {
+        let session = create_session();
+        let mut timed = create_game("catan", &["alice::10", "bob::5"], Some("friday"));
+        timed
+            .set_details(MatchDetails::build(None, Some(90), None, None, None).unwrap())
+            .unwrap();
+        let games = vec![
+            timed,
+            create_game(
+                "azul",
+                &["alice::10", "bob::15", "carol::1"],
+                Some("friday"),
+            ),
+            create_game("catan", &["alice::10", "carol::5"], Some("friday")),
+            create_game("catan", &["bob::10", "carol::5"], None),
+        ];
+
+        let report = session.report(&games, session.get_scheme());
+        assert_eq!(report.matches.len(), 3);
+        assert_eq!(report.winners, vec!["alice"]);
+        assert_eq!(report.play_time, 90);
+        assert_eq!(report.untimed_matches, 2);
+        assert_eq!(report.played["alice"]["catan"], 2);
+        assert_eq!(report.played["carol"]["azul"], 1);
+    }
+
+    #[test]
+    fn report_shares_tied_win() {
+        let session = create_session();
+        let games = vec![
+            create_game("catan", &["alice::10", "bob::5"], Some("friday")),
+            create_game("azul", &["alice::1", "bob::5"], Some("friday")),
+        ];
+
+        let report = session.report(&games, session.get_scheme());
+        assert_eq!(report.winners, vec!["alice", "bob"]);
+        assert!(
+            report
+                .outcome()
+                .starts_with("Shared by alice, bob with 3 points each.")
+        );
+    }
+}
@@ -0,0 +1,474 @@
+use crate::commands::models::game::{self, Game};
+use crate::commands::models::game_index::GameIndex;
+use crate::commands::models::leaderboard::Leaderboard;
+use crate::commands::models::player::{self, Players};
+use crate::commands::models::player_report::{self, PlayerReport, PlayerSort};
+use crate::commands::models::rating::{self, RatingAlgorithm};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tabled::Tabled;
+
+/// Number of matches listed on the front page.
+const RECENT_MATCHES: usize = 20;
+
+/// Number of months shown in the activity chart.
+const CHART_MONTHS: usize = 12;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:960px;margin:2em auto;padding:0 1em;color:#222}\
+nav a{margin-right:1em}\
+table{border-collapse:collapse;margin:1em 0}\
+th,td{border:1px solid #ccc;padding:4px 8px;text-align:left;vertical-align:top}\
+th{background:#f2f2f2}\
+svg{display:block;margin:1em 0}\
+footer{margin-top:3em;color:#777;font-size:small}";
+
+/// A page of the site, with its path relative to the site root.
+pub struct Page {
+    path: PathBuf,
+    html: String,
+}
+
+/// A self-contained static website: an overall leaderboard with the recent
+/// matches, a page per game and a page per registered player. Pages only
+/// link to each other, with relative paths, so the site can be opened from
+/// disk. Guests are left out of rankings, as on the command line.
+pub struct Site {
+    pages: Vec<Page>,
+}
+
+#[derive(Tabled)]
+struct GameSummaryRow {
+    game: String,
+    matches: usize,
+    last_played: String,
+}
+
+/// Relative links to the pages of games and registered players.
+struct Links {
+    games: GameIndex,
+    players: GameIndex,
+}
+
+impl Page {
+    pub fn get_path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn get_html(&self) -> &str {
+        &self.html
+    }
+}
+
+impl Links {
+    fn new(players: &Players, games: &[Game]) -> Links {
+        let mut links = Links {
+            games: GameIndex::create_empty(),
+            players: GameIndex::create_empty(),
+        };
+        for game in games {
+            links.games.get_or_add(game.get_name());
+        }
+        let mut names = players.get_players().keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            links.players.get_or_add(name);
+        }
+        links
+    }
+
+    fn game_path(&self, game_name: &str) -> Option<PathBuf> {
+        self.games
+            .get_slug(game_name)
+            .map(|slug| PathBuf::from("games").join(format!("{}.html", slug)))
+    }
+
+    fn player_path(&self, player_name: &str) -> Option<PathBuf> {
+        self.players
+            .get_slug(player_name)
+            .map(|slug| PathBuf::from("players").join(format!("{}.html", slug)))
+    }
+
+    /// Links the cells of the `player` column to player pages and those of
+    /// the `game` and `name` columns to game pages.
+    fn cell(&self, root: &str, header: &str, cell: &str) -> Option<String> {
+        let path = match header {
+            "player" => self.player_path(cell),
+            "game" | "name" => self.game_path(cell),
+            _ => None,
+        }?;
+        Some(format!("{}{}", root, path.display()))
+    }
+}
+
+impl Site {
+    /// Builds every page from `games`, which must be ordered by date.
+    pub fn build(
+        players: &Players,
+        games: &[Game],
+        algorithm: RatingAlgorithm,
+        generated: NaiveDate,
+    ) -> Site {
+        let links = Links::new(players, games);
+        let layout = |root: &str, title: &str, body: String| {
+            format!(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{title} - score-cli</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<nav><a href=\"{root}index.html\">Leaderboard</a></nav>\n<h1>{title}</h1>\n{body}<footer>Generated on {generated} by score-cli.</footer>\n</body>\n</html>\n",
+                title = escape(title),
+            )
+        };
+
+        let mut by_game: BTreeMap<&str, Vec<Game>> = BTreeMap::new();
+        for game in games {
+            by_game
+                .entry(game.get_name())
+                .or_default()
+                .push(game.clone());
+        }
+
+        let mut pages = vec![Page {
+            path: PathBuf::from("index.html"),
+            html: layout(
+                "",
+                "Leaderboard",
+                index_body(&links, players, games, &by_game, algorithm),
+            ),
+        }];
+
+        for (game_name, matches) in &by_game {
+            let mut leaderboard = Leaderboard::compute(game_name, matches, 1);
+            leaderboard.exclude_guests();
+            let mut wins: BTreeMap<String, f64> = BTreeMap::new();
+            for game in matches {
+                for winner in game.get_winners() {
+                    if !player::is_guest(&winner) {
+                        *wins.entry(winner).or_default() += 1.0;
+                    }
+                }
+            }
+
+            let body = [
+                format!("<p>{} matches.</p>\n", matches.len()),
+                bar_chart("Wins", &wins, |value| format!("{}", value)),
+                "<h2>Standings</h2>\n".to_string(),
+                html_table(&leaderboard.to_standing_rows(), |h, c| {
+                    links.cell("../", h, c)
+                }),
+                "<h2>Records</h2>\n".to_string(),
+                html_table(&leaderboard.to_record_rows(), |h, c| {
+                    links.cell("../", h, c)
+                }),
+                "<h2>Matches</h2>\n".to_string(),
+                html_table(
+                    &game::from_vec_to_game_rows(matches.iter().rev().cloned().collect()),
+                    |_, _| None,
+                ),
+            ]
+            .concat();
+            if let Some(path) = links.game_path(game_name) {
+                pages.push(Page {
+                    path,
+                    html: layout("../", game_name, body),
+                });
+            }
+        }
+
+        let mut names = players.get_players().keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let player = &players.get_players()[name];
+            let report = PlayerReport::compute(player, games, algorithm);
+            let mut win_rates = BTreeMap::new();
+            for (game_name, matches) in &by_game {
+                let played = matches
+                    .iter()
+                    .filter(|game| game.get_scores().contains_key(name))
+                    .collect::<Vec<_>>();
+                if !played.is_empty() {
+                    let wins = played
+                        .iter()
+                        .filter(|game| game.get_winners().contains(name))
+                        .count();
+                    win_rates.insert(
+                        game_name.to_string(),
+                        100.0 * wins as f64 / played.len() as f64,
+                    );
+                }
+            }
+
+            let summary = report
+                .summary(false)
+                .lines()
+                .skip(1)
+                .map(|line| format!("<p>{}</p>\n", escape(line)))
+                .collect::<String>();
+            let body = [
+                summary,
+                html_table(&[player.to_row()], |_, _| None),
+                bar_chart("Win rate by game", &win_rates, |value| {
+                    format!("{:.0}%", value)
+                }),
+                "<h2>Games</h2>\n".to_string(),
+                html_table(&report.to_game_rows(), |h, c| links.cell("../", h, c)),
+                "<h2>Matches</h2>\n".to_string(),
+                html_table(&report.to_history_rows(), |h, c| links.cell("../", h, c)),
+            ]
+            .concat();
+            if let Some(path) = links.player_path(name) {
+                pages.push(Page {
+                    path,
+                    html: layout("../", &player.label(false), body),
+                });
+            }
+        }
+
+        Site { pages }
+    }
+
+    pub fn get_pages(&self) -> &[Page] {
+        &self.pages
+    }
+}
+
+fn index_body(
+    links: &Links,
+    players: &Players,
+    games: &[Game],
+    by_game: &BTreeMap<&str, Vec<Game>>,
+    algorithm: RatingAlgorithm,
+) -> String {
+    let mut ratings = rating::rate(games, algorithm);
+    rating::exclude_guests(&mut ratings);
+    let ranked = rating::rank(&ratings);
+
+    let mut months: BTreeMap<String, f64> = BTreeMap::new();
+    for game in games {
+        *months
+            .entry(game.get_datetime().format("%Y-%m").to_string())
+            .or_default() += 1.0;
+    }
+    let months = months
+        .into_iter()
+        .rev()
+        .take(CHART_MONTHS)
+        .collect::<BTreeMap<_, _>>();
+
+    let summaries = by_game
+        .iter()
+        .map(|(game_name, matches)| GameSummaryRow {
+            game: game_name.to_string(),
+            matches: matches.len(),
+            last_played: matches
+                .last()
+                .map(|game| game.get_datetime().to_string())
+                .unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+    let activity = player_report::activity(players, games, PlayerSort::GamesPlayed);
+    let recent = games
+        .iter()
+        .rev()
+        .take(RECENT_MATCHES)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    [
+        format!(
+            "<p>{} matches of {} games between {} players.</p>\n",
+            games.len(),
+            by_game.len(),
+            players.get_players().len()
+        ),
+        format!("<h2>Ratings ({})</h2>\n", algorithm.get_name()),
+        html_table(&rating::to_rating_rows(&ranked), |h, c| {
+            links.cell("", h, c)
+        }),
+        bar_chart("Matches per month", &months, |value| format!("{}", value)),
+        "<h2>Recent matches</h2>\n".to_string(),
+        html_table(&game::from_vec_to_game_rows(recent), |h, c| {
+            links.cell("", h, c)
+        }),
+        "<h2>Games</h2>\n".to_string(),
+        html_table(&summaries, |h, c| links.cell("", h, c)),
+        "<h2>Players</h2>\n".to_string(),
+        html_table(
+            &player_report::to_activity_rows(&activity),
+            |h, c| match h {
+                "name" => links.cell("", "player", c),
+                _ => None,
+            },
+        ),
+    ]
+    .concat()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Renders the rows shown on the command line as an HTML table. `link`
+/// gives the target of a cell from its column header and content.
+fn html_table<T: Tabled>(rows: &[T], link: impl Fn(&str, &str) -> Option<String>) -> String {
+    if rows.is_empty() {
+        return "<p>Nothing recorded yet.</p>\n".to_string();
+    }
+
+    let headers = T::headers();
+    let mut html = String::from("<table>\n<tr>");
+    for header in &headers {
+        html.push_str(&format!("<th>{}</th>", escape(header)));
+    }
+    html.push_str("</tr>\n");
+    for row in rows {
+        html.push_str("<tr>");
+        for (header, cell) in headers.iter().zip(row.fields()) {
+            let text = escape(&cell).replace('\n', "<br>");
+            match link(header, &cell) {
+                Some(href) => html.push_str(&format!(
+                    "<td><a href=\"{}\">{}</a></td>",
+                    escape(&href),
+                    text
+                )),
+                None => html.push_str(&format!("<td>{}</td>", text)),
+            }
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+/// A horizontal bar chart as inline SVG, with the bars in the order of
+/// `values` and their length relative to the largest one.
+fn bar_chart(title: &str, values: &BTreeMap<String, f64>, label: impl Fn(f64) -> String) -> String {
+    const WIDTH: f64 = 640.0;
+    const LABEL_WIDTH: f64 = 160.0;
+    const BAR_WIDTH: f64 = 400.0;
+    const ROW_HEIGHT: f64 = 24.0;
+
+    if values.is_empty() {
+        return String::new();
+    }
+
+    let max = values.values().copied().fold(0.0, f64::max);
+    let height = ROW_HEIGHT * (values.len() + 1) as f64;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" role=\"img\" aria-label=\"{title}\">\n<text x=\"0\" y=\"16\" font-weight=\"bold\">{title}</text>\n",
+        title = escape(title)
+    );
+    for (index, (name, value)) in values.iter().enumerate() {
+        let y = ROW_HEIGHT * (index + 1) as f64;
+        let length = if max > 0.0 {
+            BAR_WIDTH * value / max
+        } else {
+            0.0
+        };
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{text_y}\">{name}</text>\n<rect x=\"{LABEL_WIDTH}\" y=\"{bar_y}\" width=\"{length:.1}\" height=\"{bar_height}\" fill=\"#4e79a7\"/>\n<text x=\"{value_x:.1}\" y=\"{text_y}\">{value}</text>\n",
+            text_y = y + 16.0,
+            name = escape(name),
+            bar_y = y + 4.0,
+            bar_height = ROW_HEIGHT - 8.0,
+            value_x = LABEL_WIDTH + length + 6.0,
+            value = escape(&label(*value)),
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::player::Player;
+
+    fn create_game(game_name: &str, scores: &[&str], date: &str) -> Game {
+        Game::build(
+            game_name.to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    fn build_site() -> Site {
+        let mut players = Players::create_empty();
+        for name in ["alice", "bob"] {
+            players.add_player(Player::new(name.to_string())).unwrap();
+        }
+        let games = vec![
+            create_game("Ticket to Ride", &["alice::80", "bob::95"], "2025-01-01"),
+            create_game("catan", &["alice::10", "guest:<Marta>::12"], "2025-02-01"),
+        ];
+        Site::build(
+            &players,
+            &games,
+            RatingAlgorithm::TrueSkill,
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        )
+    }
+
+    fn page<'a>(site: &'a Site, path: &str) -> &'a str {
+        site.get_pages()
+            .iter()
+            .find(|page| page.get_path() == &PathBuf::from(path))
+            .unwrap()
+            .get_html()
+    }
+
+    #[test]
+    fn build_creates_linked_pages() {
+        let site = build_site();
+
+        let paths = site
+            .get_pages()
+            .iter()
+            .map(|page| page.get_path().display().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "index.html",
+                "games/Ticket-to-Ride.html",
+                "games/catan.html",
+                "players/alice.html",
+                "players/bob.html",
+            ]
+        );
+
+        let index = page(&site, "index.html");
+        assert!(index.contains("<a href=\"players/bob.html\">bob</a>"));
+        assert!(index.contains("<a href=\"games/catan.html\">catan</a>"));
+        assert!(index.contains("<svg"));
+        assert!(index.contains("2025-02"));
+
+        let alice = page(&site, "players/alice.html");
+        assert!(alice.contains("<a href=\"../games/Ticket-to-Ride.html\">Ticket to Ride</a>"));
+        assert!(alice.contains("Win rate by game"));
+    }
+
+    #[test]
+    fn build_escapes_and_leaves_out_guests() {
+        let site = build_site();
+
+        let catan = page(&site, "games/catan.html");
+        assert!(catan.contains("guest:&lt;Marta&gt; 12"));
+        assert!(!catan.contains("<Marta>"));
+        let (standings, _) = catan.split_once("<h2>Records</h2>").unwrap();
+        assert!(!standings.contains("Marta"));
+        assert!(!page(&site, "index.html").contains("<td>guest:&lt;Marta&gt;</td>"));
+    }
+
+    #[test]
+    fn bar_chart_scales_to_largest_value() {
+        let values = BTreeMap::from([("a".to_string(), 2.0), ("b".to_string(), 1.0)]);
+        let svg = bar_chart("Wins", &values, |value| format!("{}", value));
+
+        assert!(svg.contains("width=\"400.0\""));
+        assert!(svg.contains("width=\"200.0\""));
+        assert!(bar_chart("Wins", &BTreeMap::new(), |value| format!("{}", value)).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::player;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tabled::Tabled;
+
+/// Number of results shown in the recent form.
+pub const FORM_LENGTH: usize = 5;
+
+/// Win streaks and losing droughts of a player. A shared win counts as a
+/// win, any other result as a loss.
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct Streaks {
+    plays: usize,
+    wins: usize,
+    current_win_streak: usize,
+    longest_win_streak: usize,
+    current_drought: usize,
+    longest_drought: usize,
+    /// The last results, oldest first, like `W-L-W-W-L`.
+    form: String,
+}
+
+#[derive(Tabled)]
+pub struct StreakRow {
+    player: String,
+    plays: usize,
+    wins: usize,
+    current_streak: usize,
+    longest_streak: usize,
+    longest_drought: usize,
+    form: String,
+}
+
+impl Streaks {
+    pub fn get_plays(&self) -> usize {
+        self.plays
+    }
+
+    pub fn get_wins(&self) -> usize {
+        self.wins
+    }
+
+    pub fn get_current_win_streak(&self) -> usize {
+        self.current_win_streak
+    }
+
+    pub fn get_longest_win_streak(&self) -> usize {
+        self.longest_win_streak
+    }
+
+    pub fn get_form(&self) -> &str {
+        &self.form
+    }
+}
+
+/// Computes the streaks of every player of `games`, which must be ordered by
+/// date.
+pub fn compute(games: &[Game]) -> BTreeMap<String, Streaks> {
+    let mut results: BTreeMap<String, Vec<bool>> = BTreeMap::new();
+    for game in games {
+        let winners = game.get_winners();
+        for player in game.get_scores().keys() {
+            results
+                .entry(player.clone())
+                .or_default()
+                .push(winners.contains(player));
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|(player, results)| (player, from_results(&results)))
+        .collect()
+}
+
+fn from_results(results: &[bool]) -> Streaks {
+    let mut streaks = Streaks {
+        plays: results.len(),
+        ..Streaks::default()
+    };
+
+    for won in results {
+        if *won {
+            streaks.wins += 1;
+            streaks.current_win_streak += 1;
+            streaks.current_drought = 0;
+        } else {
+            streaks.current_win_streak = 0;
+            streaks.current_drought += 1;
+        }
+        streaks.longest_win_streak = streaks.longest_win_streak.max(streaks.current_win_streak);
+        streaks.longest_drought = streaks.longest_drought.max(streaks.current_drought);
+    }
+
+    streaks.form = results[results.len().saturating_sub(FORM_LENGTH)..]
+        .iter()
+        .map(|won| if *won { "W" } else { "L" })
+        .collect::<Vec<_>>()
+        .join("-");
+    streaks
+}
+
+/// Removes the streaks of guests, who take part in the matches but not in
+/// the statistics.
+pub fn exclude_guests(streaks: &mut BTreeMap<String, Streaks>) {
+    streaks.retain(|player, _| !player::is_guest(player));
+}
+
+pub fn to_streak_rows(streaks: &BTreeMap<String, Streaks>) -> Vec<StreakRow> {
+    streaks
+        .iter()
+        .map(|(player, streaks)| StreakRow {
+            player: player.clone(),
+            plays: streaks.plays,
+            wins: streaks.wins,
+            current_streak: streaks.current_win_streak,
+            longest_streak: streaks.longest_win_streak,
+            longest_drought: streaks.longest_drought,
+            form: streaks.form.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(scores: &[&str], date: &str) -> Game {
+        Game::build(
+            "catan".to_string(),
+            scores.iter().map(|s| s.to_string()).collect(),
+            Some(date),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn from_results_tracks_streaks_and_droughts() {
+        let streaks = from_results(&[true, true, false, false, false, true, false, true, true]);
+
+        assert_eq!(streaks.plays, 9);
+        assert_eq!(streaks.wins, 5);
+        assert_eq!(streaks.current_win_streak, 2);
+        assert_eq!(streaks.longest_win_streak, 2);
+        assert_eq!(streaks.current_drought, 0);
+        assert_eq!(streaks.longest_drought, 3);
+        assert_eq!(streaks.form, "L-W-L-W-W");
+    }
+
+    #[test]
+    fn from_results_with_few_plays() {
+        let streaks = from_results(&[false, true]);
+
+        assert_eq!(streaks.form, "L-W");
+        assert_eq!(streaks.current_win_streak, 1);
+        assert_eq!(streaks.longest_drought, 1);
+    }
+
+    #[test]
+    fn compute_counts_shared_wins() {
+        let games = vec![
+            create_game(&["alice::10", "bob::10"], "2025-01-01"),
+            create_game(&["alice::5", "bob::10", "carol::1"], "2025-01-02"),
+        ];
+
+        let streaks = compute(&games);
+        assert_eq!(streaks["alice"].form, "W-L");
+        assert_eq!(streaks["bob"].form, "W-W");
+        assert_eq!(streaks["bob"].current_win_streak, 2);
+        assert_eq!(streaks["carol"].longest_drought, 1);
+    }
+}
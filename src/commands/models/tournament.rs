@@ -0,0 +1,854 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::merge::Records;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tabled::Tabled;
+use uuid::Uuid;
+
+pub const FILE_NAME_DATA: &str = "tournaments.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentFormat {
+    SingleElimination,
+    DoubleElimination,
+    RoundRobin,
+    Swiss,
+}
+
+/// The part of a double elimination bracket a match belongs to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Bracket {
+    Winners,
+    Losers,
+    Final,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Outcome {
+    Bye,
+    Win { winner: String },
+    Draw,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Pairing {
+    players: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bracket: Option<Bracket>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    game_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    outcome: Option<Outcome>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Tournament {
+    id: Uuid,
+    name: String,
+    game_name: String,
+    format: TournamentFormat,
+    /// The players in seeding order.
+    players: Vec<String>,
+    rounds: Vec<Vec<Pairing>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    champion: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Tournaments {
+    tournaments: HashMap<Uuid, Tournament>,
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    wins: usize,
+    draws: usize,
+    losses: usize,
+    byes: usize,
+    opponents: HashSet<String>,
+}
+
+#[derive(Tabled)]
+pub struct TournamentRow {
+    name: String,
+    game: String,
+    format: String,
+    players: usize,
+    status: String,
+}
+
+#[derive(Tabled)]
+pub struct PairingRow {
+    round: usize,
+    bracket: String,
+    players: String,
+    result: String,
+    game_id: String,
+}
+
+#[derive(Tabled)]
+pub struct TournamentStandingRow {
+    rank: usize,
+    player: String,
+    points: f64,
+    wins: usize,
+    draws: usize,
+    losses: usize,
+}
+
+impl TournamentFormat {
+    pub fn parse(format: &str) -> Result<TournamentFormat, String> {
+        match format {
+            "single-elimination" => Ok(TournamentFormat::SingleElimination),
+            "double-elimination" => Ok(TournamentFormat::DoubleElimination),
+            "round-robin" => Ok(TournamentFormat::RoundRobin),
+            "swiss" => Ok(TournamentFormat::Swiss),
+            other => Err(format!(
+                "Unknown tournament format {}. Supported formats are single-elimination, double-elimination, round-robin and swiss.",
+                other
+            )),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self {
+            TournamentFormat::SingleElimination => "single-elimination",
+            TournamentFormat::DoubleElimination => "double-elimination",
+            TournamentFormat::RoundRobin => "round-robin",
+            TournamentFormat::Swiss => "swiss",
+        }
+    }
+
+    /// Losses after which a player is out, for elimination formats.
+    fn max_losses(&self) -> Option<usize> {
+        match self {
+            TournamentFormat::SingleElimination => Some(1),
+            TournamentFormat::DoubleElimination => Some(2),
+            TournamentFormat::RoundRobin | TournamentFormat::Swiss => None,
+        }
+    }
+}
+
+impl Bracket {
+    fn get_name(&self) -> &str {
+        match self {
+            Bracket::Winners => "winners",
+            Bracket::Losers => "losers",
+            Bracket::Final => "final",
+        }
+    }
+}
+
+impl Pairing {
+    fn new(players: Vec<String>, bracket: Option<Bracket>) -> Pairing {
+        let outcome = (players.len() == 1).then_some(Outcome::Bye);
+        Pairing {
+            players,
+            bracket,
+            game_id: None,
+            outcome,
+        }
+    }
+
+    fn loser(&self) -> Option<&String> {
+        match &self.outcome {
+            Some(Outcome::Win { winner }) => self.players.iter().find(|p| *p != winner),
+            _ => None,
+        }
+    }
+}
+
+/// Seed positions of a bracket of `size` players, a power of two, so that
+/// the top seeds can only meet in the last rounds.
+fn bracket_order(size: usize) -> Vec<usize> {
+    let mut order = vec![0];
+    while order.len() < size {
+        let count = order.len() * 2;
+        order = order
+            .iter()
+            .flat_map(|seed| [*seed, count - 1 - seed])
+            .collect();
+    }
+    order
+}
+
+/// Pairs consecutive players of `group`. An odd player out gets a bye.
+fn pair_in_order(group: &[String], bracket: Option<Bracket>) -> Vec<Pairing> {
+    group
+        .chunks(2)
+        .map(|players| Pairing::new(players.to_vec(), bracket))
+        .collect()
+}
+
+/// Pairs `players`, ordered by ranking, avoiding rematches when possible.
+fn pair_without_rematches(
+    players: &[String],
+    tallies: &HashMap<String, Tally>,
+) -> Option<Vec<(String, String)>> {
+    let Some((first, rest)) = players.split_first() else {
+        return Some(Vec::new());
+    };
+
+    for (index, opponent) in rest.iter().enumerate() {
+        if tallies[first].opponents.contains(opponent) {
+            continue;
+        }
+        let mut remaining = rest.to_vec();
+        remaining.remove(index);
+        if let Some(mut pairs) = pair_without_rematches(&remaining, tallies) {
+            pairs.insert(0, (first.clone(), opponent.clone()));
+            return Some(pairs);
+        }
+    }
+
+    None
+}
+
+impl Tournament {
+    pub fn build(
+        name: String,
+        game_name: String,
+        format: TournamentFormat,
+        players: Vec<String>,
+    ) -> Result<Tournament, String> {
+        if players.len() < 2 {
+            return Err("A tournament needs at least 2 players.".to_string());
+        }
+
+        let mut tournament = Tournament {
+            id: Uuid::new_v4(),
+            name,
+            game_name,
+            format,
+            players,
+            rounds: Vec::new(),
+            champion: None,
+        };
+        tournament.advance();
+        Ok(tournament)
+    }
+
+    pub fn get_id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_game_name(&self) -> &str {
+        &self.game_name
+    }
+
+    /// The number of the round being played.
+    pub fn get_round(&self) -> usize {
+        self.rounds.len()
+    }
+
+    pub fn get_champion(&self) -> Option<&String> {
+        self.champion.as_ref()
+    }
+
+    pub fn is_elimination(&self) -> bool {
+        self.format.max_losses().is_some()
+    }
+
+    /// Records `game` as the result of the pending match between its
+    /// players and starts the next round once the current one is complete.
+    pub fn record(&mut self, game: &Game) -> Result<(), String> {
+        if self.champion.is_some() {
+            return Err(format!("Tournament {} is already finished.", self.name));
+        }
+
+        let elimination = self.is_elimination();
+        let game_players = game.get_scores().keys().collect::<HashSet<_>>();
+        let pairing = self
+            .rounds
+            .last_mut()
+            .into_iter()
+            .flatten()
+            .find(|pairing| {
+                pairing.outcome.is_none()
+                    && pairing.players.iter().collect::<HashSet<_>>() == game_players
+            })
+            .ok_or_else(|| {
+                let mut names = game_players.into_iter().cloned().collect::<Vec<_>>();
+                names.sort();
+                format!(
+                    "No pending match between {} in the current round.",
+                    names.join(" and ")
+                )
+            })?;
+
+        let outcome = match game.compare_players(&pairing.players[0], &pairing.players[1]) {
+            Some(Ordering::Greater) => Outcome::Win {
+                winner: pairing.players[0].clone(),
+            },
+            Some(Ordering::Less) => Outcome::Win {
+                winner: pairing.players[1].clone(),
+            },
+            _ if elimination => {
+                return Err("Elimination matches cannot end in a tie.".to_string());
+            }
+            _ => Outcome::Draw,
+        };
+        pairing.outcome = Some(outcome);
+        pairing.game_id = Some(*game.get_id());
+
+        self.advance();
+        Ok(())
+    }
+
+    /// Generates rounds until one has a match to play, or crowns the
+    /// champion when no round is left.
+    fn advance(&mut self) {
+        while self
+            .rounds
+            .last()
+            .is_none_or(|round| round.iter().all(|p| p.outcome.is_some()))
+        {
+            match self.next_round() {
+                Some(round) => self.rounds.push(round),
+                None => {
+                    self.champion = self.find_champion();
+                    return;
+                }
+            }
+        }
+    }
+
+    fn next_round(&self) -> Option<Vec<Pairing>> {
+        match self.format.max_losses() {
+            Some(max_losses) => self.next_elimination_round(max_losses),
+            None if self.format == TournamentFormat::RoundRobin => self.next_round_robin_round(),
+            None => self.next_swiss_round(),
+        }
+    }
+
+    fn first_elimination_round(&self) -> Vec<Pairing> {
+        let bracket =
+            (self.format == TournamentFormat::DoubleElimination).then_some(Bracket::Winners);
+        let order = bracket_order(self.players.len().next_power_of_two());
+        order
+            .chunks(2)
+            .filter_map(|seeds| {
+                let players = seeds
+                    .iter()
+                    .filter_map(|seed| self.players.get(*seed).cloned())
+                    .collect::<Vec<_>>();
+                (!players.is_empty()).then(|| Pairing::new(players, bracket))
+            })
+            .collect()
+    }
+
+    /// Pairs the players still in, grouped by their number of losses, in
+    /// the order of the previous round so that the bracket is kept. When
+    /// every group is down to one player, they meet in the final, which is
+    /// played again if the winners bracket finalist loses it.
+    fn next_elimination_round(&self, max_losses: usize) -> Option<Vec<Pairing>> {
+        let Some(last_round) = self.rounds.last() else {
+            return Some(self.first_elimination_round());
+        };
+
+        let tallies = self.tallies();
+        let mut groups: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for pairing in last_round {
+            let winner_first = match &pairing.outcome {
+                Some(Outcome::Win { winner }) => {
+                    vec![winner.clone(), pairing.loser().cloned().unwrap_or_default()]
+                }
+                _ => pairing.players.clone(),
+            };
+            for player in winner_first {
+                let losses = tallies[&player].losses;
+                if losses < max_losses {
+                    groups.entry(losses).or_default().push(player);
+                }
+            }
+        }
+
+        let alive = groups.values().map(|group| group.len()).sum::<usize>();
+        if alive < 2 {
+            return None;
+        }
+
+        let double = self.format == TournamentFormat::DoubleElimination;
+        // When the winners bracket finalist loses the final, both finalists
+        // have one loss and play it again.
+        let reset = last_round
+            .iter()
+            .any(|pairing| pairing.bracket == Some(Bracket::Final));
+        if reset || groups.values().all(|group| group.len() == 1) {
+            let finalists = groups.into_values().flatten().collect::<Vec<_>>();
+            return Some(vec![Pairing::new(
+                finalists,
+                double.then_some(Bracket::Final),
+            )]);
+        }
+
+        Some(
+            groups
+                .iter()
+                .flat_map(|(losses, group)| {
+                    let bracket = match losses {
+                        0 => Bracket::Winners,
+                        _ => Bracket::Losers,
+                    };
+                    pair_in_order(group, double.then_some(bracket))
+                })
+                .collect(),
+        )
+    }
+
+    /// Circle method: the first player stays, the others rotate.
+    fn next_round_robin_round(&self) -> Option<Vec<Pairing>> {
+        let mut seats = self.players.iter().cloned().map(Some).collect::<Vec<_>>();
+        if seats.len() % 2 == 1 {
+            seats.push(None);
+        }
+        let round = self.rounds.len();
+        if round == seats.len() - 1 {
+            return None;
+        }
+
+        seats[1..].rotate_right(round);
+        let count = seats.len();
+        Some(
+            (0..count / 2)
+                .map(|index| {
+                    let players = [&seats[index], &seats[count - 1 - index]]
+                        .into_iter()
+                        .flatten()
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    Pairing::new(players, None)
+                })
+                .collect(),
+        )
+    }
+
+    /// Pairs players with similar points who have not met yet, for as many
+    /// rounds as it takes to single out a winner.
+    fn next_swiss_round(&self) -> Option<Vec<Pairing>> {
+        let total_rounds = self.players.len().next_power_of_two().trailing_zeros() as usize;
+        if self.rounds.len() == total_rounds {
+            return None;
+        }
+
+        let tallies = self.tallies();
+        let mut ranking = self.ranking(&tallies);
+        let mut round = Vec::new();
+        if ranking.len() % 2 == 1 {
+            let index = ranking
+                .iter()
+                .rposition(|player| tallies[player].byes == 0)
+                .unwrap_or(ranking.len() - 1);
+            round.push(Pairing::new(vec![ranking.remove(index)], None));
+        }
+
+        let pairs = pair_without_rematches(&ranking, &tallies).unwrap_or_else(|| {
+            ranking
+                .chunks(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect()
+        });
+        round.splice(
+            0..0,
+            pairs
+                .into_iter()
+                .map(|(first, second)| Pairing::new(vec![first, second], None)),
+        );
+        Some(round)
+    }
+
+    fn tallies(&self) -> HashMap<String, Tally> {
+        let mut tallies = self
+            .players
+            .iter()
+            .map(|player| (player.clone(), Tally::default()))
+            .collect::<HashMap<_, _>>();
+
+        for pairing in self.rounds.iter().flatten() {
+            for player in &pairing.players {
+                let tally = tallies.entry(player.clone()).or_default();
+                match &pairing.outcome {
+                    Some(Outcome::Bye) => tally.byes += 1,
+                    Some(Outcome::Win { winner }) if winner == player => tally.wins += 1,
+                    Some(Outcome::Win { .. }) => tally.losses += 1,
+                    Some(Outcome::Draw) => tally.draws += 1,
+                    None => {}
+                }
+                tally.opponents.extend(
+                    pairing
+                        .players
+                        .iter()
+                        .filter(|other| *other != player)
+                        .cloned(),
+                );
+            }
+        }
+
+        tallies
+    }
+
+    /// A win is worth one point and a draw half. In Swiss tournaments a bye
+    /// counts as a win.
+    fn points(&self, tally: &Tally) -> f64 {
+        let byes = if self.format == TournamentFormat::Swiss {
+            tally.byes
+        } else {
+            0
+        };
+        (tally.wins + byes) as f64 + tally.draws as f64 / 2.0
+    }
+
+    /// The players by points, then by seed.
+    fn ranking(&self, tallies: &HashMap<String, Tally>) -> Vec<String> {
+        let mut ranking = self.players.clone();
+        ranking.sort_by(|a, b| {
+            self.points(&tallies[b])
+                .total_cmp(&self.points(&tallies[a]))
+                .then(tallies[b].wins.cmp(&tallies[a].wins))
+        });
+        ranking
+    }
+
+    fn find_champion(&self) -> Option<String> {
+        let tallies = self.tallies();
+        match self.format.max_losses() {
+            Some(max_losses) => self
+                .players
+                .iter()
+                .find(|player| tallies[*player].losses < max_losses)
+                .cloned(),
+            None => self.ranking(&tallies).into_iter().next(),
+        }
+    }
+
+    pub fn to_pairing_rows(&self) -> Vec<PairingRow> {
+        self.rounds
+            .iter()
+            .enumerate()
+            .flat_map(|(index, round)| {
+                round.iter().map(move |pairing| PairingRow {
+                    round: index + 1,
+                    bracket: pairing
+                        .bracket
+                        .map(|bracket| bracket.get_name().to_string())
+                        .unwrap_or_default(),
+                    players: pairing.players.join(" vs "),
+                    result: match &pairing.outcome {
+                        Some(Outcome::Bye) => "bye".to_string(),
+                        Some(Outcome::Win { winner }) => format!("{} won", winner),
+                        Some(Outcome::Draw) => "draw".to_string(),
+                        None => "pending".to_string(),
+                    },
+                    game_id: pairing.game_id.map(|id| id.to_string()).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn to_standing_rows(&self) -> Vec<TournamentStandingRow> {
+        let tallies = self.tallies();
+        self.ranking(&tallies)
+            .into_iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let tally = &tallies[&player];
+                TournamentStandingRow {
+                    rank: index + 1,
+                    points: self.points(tally),
+                    wins: tally.wins,
+                    draws: tally.draws,
+                    losses: tally.losses,
+                    player,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Tournaments {
+    pub fn create_empty() -> Tournaments {
+        Tournaments {
+            tournaments: HashMap::new(),
+        }
+    }
+
+    pub fn add_tournament(&mut self, tournament: Tournament) -> Result<(), String> {
+        if self.find(tournament.get_name()).is_ok() {
+            return Err(format!(
+                "Tournament {} already exists.",
+                tournament.get_name()
+            ));
+        }
+
+        self.tournaments.insert(*tournament.get_id(), tournament);
+        Ok(())
+    }
+
+    pub fn find(&self, name: &str) -> Result<&Tournament, String> {
+        self.tournaments
+            .values()
+            .find(|t| t.name == name)
+            .ok_or_else(|| format!("Tournament {} not found.", name))
+    }
+
+    pub fn find_mut(&mut self, name: &str) -> Result<&mut Tournament, String> {
+        self.tournaments
+            .values_mut()
+            .find(|t| t.name == name)
+            .ok_or_else(|| format!("Tournament {} not found.", name))
+    }
+
+    /// Moves the tournaments of `old_name` to `new_name`. Returns whether
+    /// any tournament changed.
+    pub fn rename_game(&mut self, old_name: &str, new_name: &str) -> bool {
+        let mut changed = false;
+        for tournament in self
+            .tournaments
+            .values_mut()
+            .filter(|t| t.game_name == old_name)
+        {
+            tournament.game_name = new_name.to_string();
+            changed = true;
+        }
+        changed
+    }
+
+    /// Replaces `player` with `new_name` in the players, pairings, results
+    /// and champion of every tournament.
+    pub fn rename_player(&mut self, player: &str, new_name: &str) {
+        let rename = |name: &mut String| {
+            if name == player {
+                *name = new_name.to_string();
+            }
+        };
+        for tournament in self.tournaments.values_mut() {
+            tournament.players.iter_mut().for_each(rename);
+            for pairing in tournament.rounds.iter_mut().flatten() {
+                pairing.players.iter_mut().for_each(rename);
+                if let Some(Outcome::Win { winner }) = &mut pairing.outcome {
+                    rename(winner);
+                }
+            }
+            if let Some(champion) = &mut tournament.champion {
+                rename(champion);
+            }
+        }
+    }
+
+    pub fn to_tournament_rows(&self) -> Vec<TournamentRow> {
+        let mut tournaments = self.tournaments.values().collect::<Vec<_>>();
+        tournaments.sort_by_key(|t| t.name.clone());
+        tournaments
+            .into_iter()
+            .map(|tournament| TournamentRow {
+                name: tournament.name.clone(),
+                game: tournament.game_name.clone(),
+                format: tournament.format.get_name().to_string(),
+                players: tournament.players.len(),
+                status: match &tournament.champion {
+                    Some(champion) => format!("won by {}", champion),
+                    None => format!("round {}", tournament.rounds.len()),
+                },
+            })
+            .collect()
+    }
+}
+
+impl Records for Tournaments {
+    type Record = Tournament;
+
+    fn records(&self) -> Vec<&Tournament> {
+        let mut tournaments = self.tournaments.values().collect::<Vec<_>>();
+        tournaments.sort_by_key(|tournament| tournament.get_name());
+        tournaments
+    }
+
+    fn add_record(&mut self, tournament: Tournament) -> Result<(), String> {
+        self.add_tournament(tournament)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_players(count: usize) -> Vec<String> {
+        (1..=count).map(|i| format!("p{}", i)).collect()
+    }
+
+    fn create_tournament(format: TournamentFormat, players: usize) -> Tournament {
+        Tournament::build(
+            "cup".to_string(),
+            "chess".to_string(),
+            format,
+            create_players(players),
+        )
+        .unwrap()
+    }
+
+    fn pending(tournament: &Tournament) -> Vec<Vec<String>> {
+        tournament
+            .rounds
+            .last()
+            .unwrap()
+            .iter()
+            .filter(|p| p.outcome.is_none())
+            .map(|p| p.players.clone())
+            .collect()
+    }
+
+    /// Plays every pending match, the player with the lower seed winning.
+    fn play_round(tournament: &mut Tournament) {
+        for players in pending(tournament) {
+            let (first, second) = (&players[0], &players[1]);
+            let first_wins = tournament.players.iter().position(|p| p == first)
+                < tournament.players.iter().position(|p| p == second);
+            let scores = if first_wins { ["1", "0"] } else { ["0", "1"] };
+            let game = Game::build(
+                "chess".to_string(),
+                vec![
+                    format!("{}::{}", first, scores[0]),
+                    format!("{}::{}", second, scores[1]),
+                ],
+                Some("2025-01-01"),
+            )
+            .unwrap();
+            tournament.record(&game).unwrap();
+        }
+    }
+
+    fn play_all(tournament: &mut Tournament) {
+        while tournament.champion.is_none() {
+            play_round(tournament);
+        }
+    }
+
+    #[test]
+    fn bracket_order_keeps_top_seeds_apart() {
+        assert_eq!(bracket_order(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    fn single_elimination_gives_byes_to_top_seeds() {
+        let tournament = create_tournament(TournamentFormat::SingleElimination, 6);
+
+        let round = &tournament.rounds[0];
+        assert_eq!(round.len(), 4);
+        assert_eq!(round[0].players, vec!["p1"]);
+        assert_eq!(round[0].outcome, Some(Outcome::Bye));
+        assert_eq!(pending(&tournament).len(), 2);
+    }
+
+    #[test]
+    fn single_elimination_crowns_champion() {
+        let mut tournament = create_tournament(TournamentFormat::SingleElimination, 6);
+        play_all(&mut tournament);
+
+        assert_eq!(tournament.champion, Some("p1".to_string()));
+        assert_eq!(tournament.rounds.len(), 3);
+    }
+
+    #[test]
+    fn double_elimination_needs_two_losses() {
+        let mut tournament = create_tournament(TournamentFormat::DoubleElimination, 4);
+        play_all(&mut tournament);
+
+        let tallies = tournament.tallies();
+        assert_eq!(tournament.champion, Some("p1".to_string()));
+        assert_eq!(tallies["p1"].losses, 0);
+        assert!(["p2", "p3", "p4"].iter().all(|p| tallies[*p].losses == 2));
+        let last = tournament.rounds.last().unwrap();
+        assert_eq!(last[0].bracket, Some(Bracket::Final));
+    }
+
+    #[test]
+    fn double_elimination_final_reset_is_final() {
+        let mut tournament = create_tournament(TournamentFormat::DoubleElimination, 4);
+        while tournament.rounds.last().unwrap()[0].bracket != Some(Bracket::Final) {
+            play_round(&mut tournament);
+        }
+
+        // The losers bracket finalist wins the final.
+        let finalists = pending(&tournament).remove(0);
+        let upset = finalists.iter().find(|p| *p != "p1").unwrap();
+        let game = Game::build(
+            "chess".to_string(),
+            vec!["p1::0".to_string(), format!("{}::1", upset)],
+            Some("2025-01-01"),
+        )
+        .unwrap();
+        tournament.record(&game).unwrap();
+
+        assert!(tournament.champion.is_none());
+        let reset = tournament.rounds.last().unwrap();
+        assert_eq!(reset.len(), 1);
+        assert_eq!(reset[0].bracket, Some(Bracket::Final));
+        assert_eq!(reset[0].players.len(), 2);
+
+        play_round(&mut tournament);
+        assert_eq!(tournament.champion, Some("p1".to_string()));
+    }
+
+    #[test]
+    fn round_robin_pairs_everyone_once() {
+        let mut tournament = create_tournament(TournamentFormat::RoundRobin, 5);
+        play_all(&mut tournament);
+
+        assert_eq!(tournament.rounds.len(), 5);
+        let tallies = tournament.tallies();
+        assert!(tallies.values().all(|tally| tally.opponents.len() == 4));
+        assert!(tallies.values().all(|tally| tally.byes == 1));
+        assert_eq!(tournament.champion, Some("p1".to_string()));
+    }
+
+    #[test]
+    fn swiss_avoids_rematches() {
+        let mut tournament = create_tournament(TournamentFormat::Swiss, 8);
+        play_all(&mut tournament);
+
+        assert_eq!(tournament.rounds.len(), 3);
+        let tallies = tournament.tallies();
+        assert!(tallies.values().all(|tally| tally.opponents.len() == 3));
+        assert_eq!(tournament.champion, Some("p1".to_string()));
+    }
+
+    #[test]
+    fn record_rejects_ties_in_elimination() {
+        let mut tournament = create_tournament(TournamentFormat::SingleElimination, 2);
+        let game = Game::build(
+            "chess".to_string(),
+            vec!["p1::1".to_string(), "p2::1".to_string()],
+            Some("2025-01-01"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tournament.record(&game).unwrap_err(),
+            "Elimination matches cannot end in a tie."
+        );
+    }
+
+    #[test]
+    fn record_requires_pending_match() {
+        let mut tournament = create_tournament(TournamentFormat::RoundRobin, 4);
+        let game = Game::build(
+            "chess".to_string(),
+            vec!["p1::1".to_string(), "p2::0".to_string()],
+            Some("2025-01-01"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tournament.record(&game).unwrap_err(),
+            "No pending match between p1 and p2 in the current round."
+        );
+    }
+}
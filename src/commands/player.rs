@@ -0,0 +1,215 @@
+use crate::commands::models::player::ProfileChanges;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    ACTIVE_OPTIONAL_ARGUMENT, COLOR_OPTIONAL_ARGUMENT, DISPLAY_NAME_OPTIONAL_ARGUMENT,
+    EMOJI_OPTIONAL_ARGUMENT, FAVORITE_GAMES_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT,
+    JOINED_OPTIONAL_ARGUMENT, NICKNAME_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 9] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    DISPLAY_NAME_OPTIONAL_ARGUMENT,
+    NICKNAME_OPTIONAL_ARGUMENT,
+    JOINED_OPTIONAL_ARGUMENT,
+    FAVORITE_GAMES_OPTIONAL_ARGUMENT,
+    COLOR_OPTIONAL_ARGUMENT,
+    EMOJI_OPTIONAL_ARGUMENT,
+    ACTIVE_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum PlayerAction {
+    Edit(String, ProfileChanges),
+    Show(String),
+    Alias(String, String),
+}
+
+#[derive(Debug)]
+pub struct PlayerCommand {
+    action: PlayerAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+fn parse_changes(optional_args: &HashMap<String, String>) -> Result<ProfileChanges, String> {
+    let changes = ProfileChanges {
+        display_name: optional_args.get(DISPLAY_NAME_OPTIONAL_ARGUMENT).cloned(),
+        nickname: optional_args.get(NICKNAME_OPTIONAL_ARGUMENT).cloned(),
+        joined: optional_args
+            .get(JOINED_OPTIONAL_ARGUMENT)
+            .map(|joined| {
+                NaiveDate::parse_from_str(joined, "%Y-%m-%d")
+                    .map_err(|_| "Error parsing date. The input format is YYYY-MM-DD.".to_string())
+            })
+            .transpose()?,
+        favorite_games: optional_args
+            .get(FAVORITE_GAMES_OPTIONAL_ARGUMENT)
+            .map(|games| games.split(',').map(|g| g.to_string()).collect()),
+        color: optional_args.get(COLOR_OPTIONAL_ARGUMENT).cloned(),
+        emoji: optional_args.get(EMOJI_OPTIONAL_ARGUMENT).cloned(),
+        active: optional_args
+            .get(ACTIVE_OPTIONAL_ARGUMENT)
+            .map(|active| match active.as_str() {
+                "true" | "yes" => Ok(true),
+                "false" | "no" => Ok(false),
+                _ => Err(format!(
+                    "Invalid value {} for --active. Use true or false.",
+                    active
+                )),
+            })
+            .transpose()?,
+    };
+
+    if changes == ProfileChanges::default() {
+        return Err("Nothing to change. Use --display-name, --nickname, --joined, --favorite-games, --color, --emoji or --active.".to_string());
+    }
+    Ok(changes)
+}
+
+impl PlayerCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<PlayerCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for player {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("edit") if args.len() == 2 => {
+                PlayerAction::Edit(args[1].clone(), parse_changes(optional_args)?)
+            }
+            Some("show") if args.len() == 2 => PlayerAction::Show(args[1].clone()),
+            Some("alias") if args.len() == 3 => {
+                PlayerAction::Alias(args[1].clone(), args[2].clone())
+            }
+            _ => {
+                return Err(
+                    "Invalid arguments for player. Use player edit <name>, player show <name> or player alias <name> <alias>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(PlayerCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            PlayerAction::Edit(name, changes) => {
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut players = data::load_players(data_file_path)?;
+                let mut player = players.get(name)?.clone();
+                player.edit(changes.clone())?;
+                players.update_player(player)?;
+                data::save_players(data_file_path, &players)?;
+                transaction.commit("player", format!("Updated the profile of {}.", name))?;
+                println!("Updated the profile of {}.", name);
+            }
+            PlayerAction::Alias(name, alias) => {
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut players = data::load_players(data_file_path)?;
+                players.add_alias(name, alias)?;
+                data::save_players(data_file_path, &players)?;
+                let message = format!("Added alias {} to player {}.", alias.trim(), name);
+                transaction.commit("player", message.clone())?;
+                println!("{}", message);
+            }
+            PlayerAction::Show(name) => {
+                let players = data::load_players(data_file_path)?;
+                let player = players.get(name)?;
+                match self.format {
+                    OutputFormat::Json => output::print_json(player)?,
+                    OutputFormat::Table => {
+                        println!("{}", player.label(io::stdout().is_terminal()));
+                        println!("{}", Table::new([player.to_row()]));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_edit() {
+        let args = vec!["edit".to_string(), "giovi98".to_string()];
+        let optional_args = HashMap::from([
+            (NICKNAME_OPTIONAL_ARGUMENT.to_string(), "Gio".to_string()),
+            (
+                FAVORITE_GAMES_OPTIONAL_ARGUMENT.to_string(),
+                "Catan,Azul".to_string(),
+            ),
+            (ACTIVE_OPTIONAL_ARGUMENT.to_string(), "no".to_string()),
+        ]);
+
+        let command = PlayerCommand::create(&args, &optional_args).unwrap();
+        assert_eq!(
+            command.action,
+            PlayerAction::Edit(
+                "giovi98".to_string(),
+                ProfileChanges {
+                    nickname: Some("Gio".to_string()),
+                    favorite_games: Some(vec!["Catan".to_string(), "Azul".to_string()]),
+                    active: Some(false),
+                    ..ProfileChanges::default()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_alias() {
+        let args = vec![
+            "alias".to_string(),
+            "giovi98".to_string(),
+            "gio".to_string(),
+        ];
+
+        let command = PlayerCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(
+            command.action,
+            PlayerAction::Alias("giovi98".to_string(), "gio".to_string())
+        );
+    }
+
+    #[test]
+    fn test_create_edit_without_changes() {
+        let args = vec!["edit".to_string(), "giovi98".to_string()];
+
+        let result = PlayerCommand::create(&args, &HashMap::new());
+        assert!(result.unwrap_err().starts_with("Nothing to change."));
+    }
+
+    #[test]
+    fn test_create_edit_with_invalid_active() {
+        let args = vec!["edit".to_string(), "giovi98".to_string()];
+        let optional_args =
+            HashMap::from([(ACTIVE_OPTIONAL_ARGUMENT.to_string(), "maybe".to_string())]);
+
+        let result = PlayerCommand::create(&args, &optional_args);
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid value maybe for --active. Use true or false."
+        );
+    }
+}
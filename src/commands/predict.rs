@@ -0,0 +1,160 @@
+use crate::commands::models::player::{self, Players};
+use crate::commands::models::prediction;
+use crate::commands::models::rating;
+use crate::commands::ratings::select_algorithm;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    ALGORITHM_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::{HashMap, HashSet};
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 3] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct Predict {
+    game_name: String,
+    players: Vec<String>,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+/// Fails if a player appears more than once in `players`.
+pub fn check_duplicates(players: &[String]) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for player in players {
+        if !seen.insert(player) {
+            return Err(format!("Player {} is listed more than once.", player));
+        }
+    }
+    Ok(())
+}
+
+/// The player names of `names`, entered by player name or alias. Guests are
+/// kept as entered. Fails on a name that matches no player, or on two names
+/// of the same player.
+pub fn resolve_lineup(players: &Players, names: &[String]) -> Result<Vec<String>, String> {
+    let lineup = names
+        .iter()
+        .map(|name| {
+            if player::is_guest(name) {
+                return Ok(name.clone());
+            }
+            players
+                .resolve(name)
+                .map(|p| p.get_name().to_string())
+                .ok_or_else(|| {
+                    players.unknown_player(name, format!("Player {} does not exist.", name))
+                })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    check_duplicates(&lineup)?;
+    Ok(lineup)
+}
+
+impl Predict {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Predict, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for predict {}.", key));
+            }
+        }
+
+        if args.len() < 3 {
+            return Err("Invalid number of arguments for predict.".to_string());
+        }
+        check_duplicates(&args[1..])?;
+
+        Ok(Predict {
+            game_name: args[0].clone(),
+            players: args[1..].to_vec(),
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let algorithm = select_algorithm(&self.optional_args)?;
+        let players = resolve_lineup(&data::load_players(data_file_path)?, &self.players)?;
+        let games = data::load_games(data_file_path, &self.game_name)?;
+        let ratings = rating::rate(&games.finished().order_by_date(), algorithm);
+        let lineup = players
+            .iter()
+            .map(|player| {
+                let rating = ratings
+                    .get(player)
+                    .copied()
+                    .unwrap_or_else(|| algorithm.initial_rating());
+                (player.clone(), rating)
+            })
+            .collect::<Vec<_>>();
+        let probabilities = prediction::win_probabilities(&lineup, algorithm.performance_spread());
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&probabilities)?,
+            OutputFormat::Table => {
+                println!(
+                    "Win probabilities for {} ({}):",
+                    self.game_name,
+                    algorithm.get_name()
+                );
+                println!(
+                    "{}",
+                    Table::new(prediction::to_win_probability_rows(&probabilities))
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["catan".to_string(), "alice".to_string(), "bob".to_string()];
+
+        let predict = Predict::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(predict.game_name, "catan");
+        assert_eq!(predict.players, vec!["alice", "bob"]);
+    }
+
+    #[test]
+    fn test_create_single_player() {
+        let args = vec!["catan".to_string(), "alice".to_string()];
+
+        let result = Predict::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for predict."
+        );
+    }
+
+    #[test]
+    fn test_create_duplicate_player() {
+        let args = vec![
+            "catan".to_string(),
+            "alice".to_string(),
+            "alice".to_string(),
+        ];
+
+        let result = Predict::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Player alice is listed more than once."
+        );
+    }
+}
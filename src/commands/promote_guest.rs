@@ -0,0 +1,145 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::models::player::{self, Player};
+use crate::commands::utils::{data, transaction::Transaction};
+use std::collections::{BTreeSet, HashMap};
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct PromoteGuest {
+    guest: String,
+    player_name: String,
+    optional_args: HashMap<String, String>,
+}
+
+impl PromoteGuest {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<PromoteGuest, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!(
+                    "Unknown optional command for promote-guest {}.",
+                    key
+                ));
+            }
+        }
+
+        if args.is_empty() || args.len() > 2 {
+            return Err("Invalid number of arguments for promote-guest.".to_string());
+        }
+
+        // The guest can be given with or without its prefix.
+        let name = args[0]
+            .strip_prefix(player::GUEST_PREFIX)
+            .unwrap_or(&args[0]);
+        let player_name = args.get(1).map_or(name, |name| name.as_str()).trim();
+        if player_name.is_empty() || player::is_guest(player_name) {
+            return Err(format!("Invalid player name {}.", player_name));
+        }
+
+        Ok(PromoteGuest {
+            guest: format!("{}{}", player::GUEST_PREFIX, name),
+            player_name: player_name.to_string(),
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    /// Records every match of the guest under the player, registering the
+    /// player if needed. A new player joins on the day of their first match.
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let all_games = data::load_all_games(data_file_path)?.order_by_date();
+        let played = all_games
+            .iter()
+            .filter(|game| game.get_scores().contains_key(&self.guest))
+            .collect::<Vec<_>>();
+        let Some(first) = played.first() else {
+            return Err(format!("No matches found for {}.", self.guest));
+        };
+
+        let mut players = data::load_players(data_file_path)?;
+        // The guest no longer needs its own entry once it is a player.
+        let unregistered = match players.get_players().get(&self.guest) {
+            Some(guest) => players.remove_player(guest.clone()).is_ok(),
+            None => false,
+        };
+        let registered = players.resolve(&self.player_name).is_none();
+        if registered {
+            let mut player = Player::new(self.player_name.clone());
+            player.set_joined(*first.get_datetime());
+            players.add_player(player)?;
+        }
+        let player_name = players.get(&self.player_name)?.get_name().to_string();
+
+        // Every match is renamed before anything is written, so that a match
+        // the player already played in leaves the data untouched.
+        let game_names = played
+            .iter()
+            .map(|game| game.get_name())
+            .collect::<BTreeSet<_>>();
+        let mut renamed = Vec::new();
+        for game_name in game_names {
+            let mut games = data::load_games(data_file_path, game_name)?;
+            for game in played.iter().filter(|game| game.get_name() == game_name) {
+                if let Some(game) = games.get_mut(game.get_id()) {
+                    game.rename_player(&self.guest, &player_name)?;
+                }
+            }
+            renamed.push((game_name, games));
+        }
+
+        if registered || unregistered {
+            data::save_players(data_file_path, &players)?;
+        }
+        for (game_name, games) in &renamed {
+            data::save_games(data_file_path, game_name, games)?;
+        }
+
+        let message = format!(
+            "Promoted {} to player {} ({} matches).",
+            self.guest,
+            player_name,
+            played.len()
+        );
+        transaction.commit("promote-guest", message.clone())?;
+        println!("{}", message);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["guest:Marta".to_string()];
+
+        let promote = PromoteGuest::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(promote.guest, "guest:Marta");
+        assert_eq!(promote.player_name, "Marta");
+    }
+
+    #[test]
+    fn test_create_with_player_name() {
+        let args = vec!["Marta".to_string(), "marta92".to_string()];
+
+        let promote = PromoteGuest::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(promote.guest, "guest:Marta");
+        assert_eq!(promote.player_name, "marta92");
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_arguments() {
+        let result = PromoteGuest::create(&[], &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for promote-guest."
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::commands::list_games::parse_as_of;
+use crate::commands::models::rating::{self, RatingAlgorithm};
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    ALGORITHM_OPTIONAL_ARGUMENT, AS_OF_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT, INCLUDE_GUESTS_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 6] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    AS_OF_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct Ratings {
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+/// The algorithm given with `--algorithm`, or the one set in the config.
+pub fn select_algorithm(
+    optional_args: &HashMap<String, String>,
+) -> Result<RatingAlgorithm, String> {
+    match optional_args.get(ALGORITHM_OPTIONAL_ARGUMENT) {
+        Some(algorithm) => RatingAlgorithm::parse(algorithm),
+        None => Ok(
+            data::load_config(optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT))?
+                .get_rating_algorithm(),
+        ),
+    }
+}
+
+impl Ratings {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Ratings, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for ratings {}.", key));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for ratings.".to_string());
+        }
+        if let Some(algorithm) = optional_args.get(ALGORITHM_OPTIONAL_ARGUMENT) {
+            RatingAlgorithm::parse(algorithm)?;
+        }
+        parse_as_of(optional_args)?;
+
+        Ok(Ratings {
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let algorithm = select_algorithm(&self.optional_args)?;
+        let game_name = self.optional_args.get(GAME_OPTIONAL_ARGUMENT);
+        let games = match (parse_as_of(&self.optional_args)?, game_name) {
+            (Some(as_of), Some(game_name)) => {
+                data::load_games_as_of(data_file_path, as_of)?.with_name(game_name)
+            }
+            (Some(as_of), None) => data::load_games_as_of(data_file_path, as_of)?,
+            (None, Some(game_name)) => data::load_games(data_file_path, game_name)?,
+            (None, None) => data::load_all_games(data_file_path)?,
+        };
+        let mut ratings = rating::rate(&games.finished().order_by_date(), algorithm);
+        if !self
+            .optional_args
+            .contains_key(INCLUDE_GUESTS_OPTIONAL_ARGUMENT)
+        {
+            rating::exclude_guests(&mut ratings);
+        }
+        let ranked = rating::rank(&ratings);
+        let pool = game_name.map_or("global", |name| name.as_str());
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&serde_json::json!({
+                "algorithm": algorithm,
+                "pool": pool,
+                "ratings": ranked,
+            }))?,
+            OutputFormat::Table => {
+                println!("Ratings ({}, {} pool):", algorithm.get_name(), pool);
+                println!("{}", Table::new(rating::to_rating_rows(&ranked)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(
+            ALGORITHM_OPTIONAL_ARGUMENT.to_string(),
+            "glicko2".to_string(),
+        );
+
+        let result = Ratings::create(&[], &optional_args);
+        assert!(result.is_ok());
+        assert_eq!(
+            select_algorithm(&optional_args).unwrap(),
+            RatingAlgorithm::Glicko2
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_algorithm() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(ALGORITHM_OPTIONAL_ARGUMENT.to_string(), "elo".to_string());
+
+        let result = Ratings::create(&[], &optional_args);
+        assert!(result.is_err());
+    }
+}
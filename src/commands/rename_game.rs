@@ -0,0 +1,149 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::utils::{data, transaction::Transaction};
+use std::collections::HashMap;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct RenameGame {
+    old_name: String,
+    new_name: String,
+    optional_args: HashMap<String, String>,
+}
+
+impl RenameGame {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<RenameGame, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for rename-game {}.", key));
+            }
+        }
+
+        if args.len() != 2 {
+            return Err("Invalid number of arguments for rename-game.".to_string());
+        }
+        if args[1].trim().is_empty() {
+            return Err("The game name cannot be empty.".to_string());
+        }
+
+        Ok(RenameGame {
+            old_name: args[0].clone(),
+            new_name: args[1].trim().to_string(),
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    /// Moves every match of the old game to the new one, merging them with
+    /// the matches already recorded under the new name. Match ids are kept.
+    /// The catalog, seasons and tournaments follow the new name.
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let transaction = Transaction::begin(data_file_path)?;
+        let mut catalog = data::load_catalog(data_file_path)?;
+        let old_entry = catalog
+            .resolve(&self.old_name)
+            .map(|entry| entry.get_name().to_string());
+        let new_entry = catalog
+            .resolve(&self.new_name)
+            .map(|entry| entry.get_name().to_string());
+        // A catalog game is renamed under its canonical name, whatever name
+        // or alias it was given by.
+        let old_name = old_entry.as_deref().unwrap_or(&self.old_name);
+        // A name of another catalog game stands for its canonical name.
+        let target = match &new_entry {
+            Some(name) if new_entry != old_entry => name.clone(),
+            _ => self.new_name.clone(),
+        };
+        if target == old_name {
+            return Err(format!("The game is already named {}.", target));
+        }
+
+        let moved = data::load_games(data_file_path, old_name)?;
+        if moved.get_games().is_empty() {
+            return Err(format!("No matches found for {}.", old_name));
+        }
+        let mut games = data::load_games(data_file_path, &target)?;
+        let merged = !games.get_games().is_empty();
+        for game in moved.get_games().values() {
+            let mut game = game.clone();
+            game.set_name(target.clone());
+            games.add_game(game);
+        }
+        data::remove_games(data_file_path, old_name)?;
+        data::save_games(data_file_path, &target, &games)?;
+
+        if let Some(old_entry) = &old_entry
+            && new_entry.as_ref().is_none_or(|name| name == old_entry)
+        {
+            catalog.rename(old_entry, &target)?;
+            data::save_catalog(data_file_path, &catalog)?;
+        }
+        let mut seasons = data::load_seasons(data_file_path)?;
+        if seasons.rename_game(old_name, &target) {
+            data::save_seasons(data_file_path, &seasons)?;
+        }
+        let mut tournaments = data::load_tournaments(data_file_path)?;
+        if tournaments.rename_game(old_name, &target) {
+            data::save_tournaments(data_file_path, &tournaments)?;
+        }
+
+        let count = moved.get_games().len();
+        transaction.commit(
+            "rename-game",
+            format!(
+                "Renamed game {} to {} ({} matches).",
+                old_name, target, count
+            ),
+        )?;
+        if merged {
+            println!(
+                "Moved {} matches from {} into the existing game {}.",
+                count, old_name, target
+            );
+        } else {
+            println!(
+                "Renamed game {} to {} ({} matches).",
+                old_name, target, count
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["catna".to_string(), " Catan ".to_string()];
+
+        let rename = RenameGame::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(rename.old_name, "catna");
+        assert_eq!(rename.new_name, "Catan");
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_arguments() {
+        let args = vec!["catna".to_string()];
+
+        let result = RenameGame::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for rename-game."
+        );
+    }
+
+    #[test]
+    fn test_create_empty_name() {
+        let args = vec!["catna".to_string(), " ".to_string()];
+
+        let result = RenameGame::create(&args, &HashMap::new());
+        assert_eq!(result.unwrap_err(), "The game name cannot be empty.");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::commands::models::rating::RatingAlgorithm;
+use crate::commands::models::site::Site;
+use crate::commands::ratings::select_algorithm;
+use crate::commands::utils::data;
+use crate::commands::{
+    ALGORITHM_OPTIONAL_ARGUMENT, OUTPUT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 3] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    OUTPUT_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum ReportAction {
+    Html(String),
+}
+
+#[derive(Debug)]
+pub struct ReportCommand {
+    action: ReportAction,
+    optional_args: HashMap<String, String>,
+}
+
+impl ReportCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<ReportCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for report {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("html") if args.len() == 1 => ReportAction::Html(
+                optional_args
+                    .get(OUTPUT_OPTIONAL_ARGUMENT)
+                    .ok_or("Missing output directory for report. Use --output <dir>.")?
+                    .clone(),
+            ),
+            _ => {
+                return Err(
+                    "Invalid arguments for report. Use report html --output <dir>.".to_string(),
+                );
+            }
+        };
+        if let Some(algorithm) = optional_args.get(ALGORITHM_OPTIONAL_ARGUMENT) {
+            RatingAlgorithm::parse(algorithm)?;
+        }
+
+        Ok(ReportCommand {
+            action,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            ReportAction::Html(output) => {
+                let players = data::load_players(data_file_path)?;
+                let games = data::load_all_games(data_file_path)?
+                    .finished()
+                    .order_by_date();
+                let site = Site::build(
+                    &players,
+                    &games,
+                    select_algorithm(&self.optional_args)?,
+                    Utc::now().date_naive(),
+                );
+
+                for page in site.get_pages() {
+                    let path = Path::new(output).join(page.get_path());
+                    if let Some(dir) = path.parent() {
+                        fs::create_dir_all(dir)
+                            .map_err(|_| format!("Error creating directory: {}", dir.display()))?;
+                    }
+                    fs::write(&path, page.get_html())
+                        .map_err(|_| format!("Error writing file: {}", path.display()))?;
+                }
+                println!(
+                    "Generated {} pages in {}. Open {} in a browser.",
+                    site.get_pages().len(),
+                    output,
+                    Path::new(output).join("index.html").display()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_html() {
+        let args = vec!["html".to_string()];
+        let optional_args =
+            HashMap::from([(OUTPUT_OPTIONAL_ARGUMENT.to_string(), "site".to_string())]);
+
+        let command = ReportCommand::create(&args, &optional_args).unwrap();
+        assert_eq!(command.action, ReportAction::Html("site".to_string()));
+    }
+
+    #[test]
+    fn test_create_html_without_output() {
+        let args = vec!["html".to_string()];
+
+        let result = ReportCommand::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Missing output directory for report. Use --output <dir>."
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_format() {
+        let args = vec!["pdf".to_string()];
+        let optional_args =
+            HashMap::from([(OUTPUT_OPTIONAL_ARGUMENT.to_string(), "site".to_string())]);
+
+        let result = ReportCommand::create(&args, &optional_args);
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid arguments for report. Use report html --output <dir>."
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::player::Player;
+use crate::commands::predict::check_duplicates;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT, TIME_OPTIONAL_ARGUMENT,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use tabled::Table;
+use uuid::Uuid;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 3] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    TIME_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum RoundAction {
+    Start(Box<Game>),
+    Add(Uuid, BTreeMap<String, i64>),
+    Finish(Uuid),
+    Show(Uuid),
+}
+
+#[derive(Debug)]
+pub struct RoundCommand {
+    action: RoundAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+fn parse_id(id: &str) -> Result<Uuid, String> {
+    Uuid::from_str(id).map_err(|_| format!("Impossible to decode id {}.", id))
+}
+
+impl RoundCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<RoundCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for round {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("start") if args.len() >= 3 => {
+                let players = args[2..].to_vec();
+                check_duplicates(&players)?;
+                let mut game = Game::build(
+                    args[1].clone(),
+                    players.iter().map(|p| format!("{}::0", p)).collect(),
+                    optional_args
+                        .get(TIME_OPTIONAL_ARGUMENT)
+                        .map(|s| s.as_str()),
+                )?;
+                game.start_rounds();
+                RoundAction::Start(Box::new(game))
+            }
+            Some("add") if args.len() >= 3 => {
+                RoundAction::Add(parse_id(&args[1])?, Game::parse_round(&args[2..])?)
+            }
+            Some("finish") if args.len() == 2 => RoundAction::Finish(parse_id(&args[1])?),
+            Some("show") if args.len() == 2 => RoundAction::Show(parse_id(&args[1])?),
+            _ => {
+                return Err(
+                    "Invalid arguments for round. Use round start <game-name> <players>, round add <match-id> <player::points>, round finish <match-id> or round show <match-id>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(RoundCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            RoundAction::Start(game) => {
+                let players = data::load_players(data_file_path)?;
+                if let Some(missing) = game
+                    .get_scores()
+                    .keys()
+                    .find(|p| !players.exists(Player::new(p.to_string())))
+                {
+                    return Err(format!("Player {} does not exist.", missing));
+                }
+
+                let mut new_game = game.as_ref().clone();
+                if let Some(entry) = data::load_catalog(data_file_path)?.resolve(game.get_name()) {
+                    entry.check_player_count(new_game.get_scores().len())?;
+                    new_game.set_name(entry.get_name().to_string());
+                }
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut games = data::load_games(data_file_path, new_game.get_name())?;
+                games.add_game(new_game.clone());
+                data::save_games(data_file_path, new_game.get_name(), &games)?;
+                transaction.commit(
+                    "round",
+                    format!(
+                        "Started game of {} with id {}, scored by rounds.",
+                        new_game.get_name(),
+                        new_game.get_id()
+                    ),
+                )?;
+                println!(
+                    "Started game of {} with id: {}.",
+                    new_game.get_name(),
+                    new_game.get_id()
+                );
+            }
+            RoundAction::Add(id, round) => {
+                let transaction = Transaction::begin(data_file_path)?;
+                let game_name = find_game(data_file_path, id)?.get_name().to_string();
+                let mut games = data::load_games(data_file_path, &game_name)?;
+                let game = games
+                    .get_mut(id)
+                    .ok_or_else(|| format!("Game with id {} not found.", id))?;
+                game.add_round(round.clone())?;
+                let game = game.clone();
+                data::save_games(data_file_path, &game_name, &games)?;
+                transaction.commit(
+                    "round",
+                    format!(
+                        "Added round {} to game of {} with id {}.",
+                        game.get_rounds().len(),
+                        game_name,
+                        id
+                    ),
+                )?;
+                println!(
+                    "Added round {} to game of {}.",
+                    game.get_rounds().len(),
+                    game_name
+                );
+                println!("{}", Table::new(game.to_round_rows()));
+            }
+            RoundAction::Finish(id) => {
+                let transaction = Transaction::begin(data_file_path)?;
+                let game_name = find_game(data_file_path, id)?.get_name().to_string();
+                let mut games = data::load_games(data_file_path, &game_name)?;
+                let game = games
+                    .get_mut(id)
+                    .ok_or_else(|| format!("Game with id {} not found.", id))?;
+                game.finish_rounds()?;
+                let game = game.clone();
+                data::save_games(data_file_path, &game_name, &games)?;
+                transaction.commit(
+                    "round",
+                    format!("Finished game of {} with id {}.", game_name, id),
+                )?;
+                println!(
+                    "Finished game of {} after {} rounds.",
+                    game_name,
+                    game.get_rounds().len()
+                );
+                println!("{}", Table::new(game.to_round_rows()));
+            }
+            RoundAction::Show(id) => {
+                let game = find_game(data_file_path, id)?;
+                match self.format {
+                    OutputFormat::Json => output::print_json(&game)?,
+                    OutputFormat::Table => {
+                        println!(
+                            "Game of {} after {} rounds{}.",
+                            game.get_name(),
+                            game.get_rounds().len(),
+                            if game.is_in_progress() {
+                                ", in progress"
+                            } else {
+                                ""
+                            }
+                        );
+                        println!("{}", Table::new(game.to_round_rows()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn find_game(save_dir: Option<&String>, id: &Uuid) -> Result<Game, String> {
+    data::load_all_games(save_dir)?
+        .get_games()
+        .get(id)
+        .cloned()
+        .ok_or_else(|| format!("Game with id {} not found.", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_start() {
+        let args = vec![
+            "start".to_string(),
+            "Skull King".to_string(),
+            "alice".to_string(),
+            "bob".to_string(),
+        ];
+
+        let command = RoundCommand::create(&args, &HashMap::new()).unwrap();
+        let RoundAction::Start(game) = command.action else {
+            panic!("Expected a start action.");
+        };
+        assert_eq!(game.get_name(), "Skull King");
+        assert_eq!(game.get_scores()["alice"], 0);
+        assert!(game.get_rounds().is_empty());
+    }
+
+    #[test]
+    fn test_create_add() {
+        let id = Uuid::new_v4();
+        let args = vec![
+            "add".to_string(),
+            id.to_string(),
+            "alice::20".to_string(),
+            "bob::-10".to_string(),
+        ];
+
+        let command = RoundCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(
+            command.action,
+            RoundAction::Add(
+                id,
+                BTreeMap::from([("alice".to_string(), 20), ("bob".to_string(), -10)])
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_add_with_invalid_id() {
+        let args = vec![
+            "add".to_string(),
+            "not-an-id".to_string(),
+            "alice::20".to_string(),
+        ];
+
+        let result = RoundCommand::create(&args, &HashMap::new());
+        assert_eq!(result.unwrap_err(), "Impossible to decode id not-an-id.");
+    }
+
+    #[test]
+    fn test_create_finish() {
+        let id = Uuid::new_v4();
+        let args = vec!["finish".to_string(), id.to_string()];
+
+        let command = RoundCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(command.action, RoundAction::Finish(id));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::commands::models::season::{self, PointsScheme, Season};
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    END_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT, GAMES_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT, SCHEME_OPTIONAL_ARGUMENT,
+    START_OPTIONAL_ARGUMENT,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 7] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    START_OPTIONAL_ARGUMENT,
+    END_OPTIONAL_ARGUMENT,
+    GAMES_OPTIONAL_ARGUMENT,
+    SCHEME_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum SeasonAction {
+    Create(Season),
+    List,
+    Standings(String),
+}
+
+#[derive(Debug)]
+pub struct SeasonCommand {
+    action: SeasonAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+fn parse_date(optional_args: &HashMap<String, String>, key: &str) -> Result<NaiveDate, String> {
+    let date = optional_args
+        .get(key)
+        .ok_or_else(|| format!("Missing {} for season create.", key))?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| "Error parsing date. The input format is YYYY-MM-DD.".to_string())
+}
+
+impl SeasonCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<SeasonCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for season {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("create") if args.len() == 2 => {
+                let games = optional_args
+                    .get(GAMES_OPTIONAL_ARGUMENT)
+                    .map(|games| {
+                        games
+                            .split(',')
+                            .map(|g| g.trim().to_string())
+                            .filter(|g| !g.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let scheme = match optional_args.get(SCHEME_OPTIONAL_ARGUMENT) {
+                    Some(scheme) => PointsScheme::parse(scheme)?,
+                    None => PointsScheme::default(),
+                };
+                SeasonAction::Create(Season::build(
+                    args[1].clone(),
+                    parse_date(optional_args, START_OPTIONAL_ARGUMENT)?,
+                    parse_date(optional_args, END_OPTIONAL_ARGUMENT)?,
+                    games,
+                    scheme,
+                )?)
+            }
+            Some("list") if args.len() == 1 => SeasonAction::List,
+            Some("standings") if args.len() == 2 => SeasonAction::Standings(args[1].clone()),
+            _ => {
+                return Err(
+                    "Invalid arguments for season. Use season create <name>, season list or season standings <name>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(SeasonCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            SeasonAction::Create(season) => {
+                let mut season = season.clone();
+                season.resolve_games(&data::load_catalog(data_file_path)?);
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut seasons = data::load_seasons(data_file_path)?;
+                seasons.add_season(season.clone())?;
+                data::save_seasons(data_file_path, &seasons)?;
+                transaction.commit("season", format!("Created season {}.", season.get_name()))?;
+                println!("Created season {}.", season.get_name());
+            }
+            SeasonAction::List => {
+                let seasons = data::load_seasons(data_file_path)?;
+                println!("{}", Table::new(seasons.to_season_rows()));
+            }
+            SeasonAction::Standings(name) => {
+                let seasons = data::load_seasons(data_file_path)?;
+                let season = seasons.get(name)?;
+                let games = data::load_all_games(data_file_path)?;
+                let mut standings = season.standings(&games.finished().order_by_date());
+                if !self
+                    .optional_args
+                    .contains_key(INCLUDE_GUESTS_OPTIONAL_ARGUMENT)
+                {
+                    season::exclude_guests(&mut standings);
+                }
+
+                match self.format {
+                    OutputFormat::Json => output::print_json(&standings)?,
+                    OutputFormat::Table => {
+                        println!("{}", Table::new(season::to_standing_rows(&standings)));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_season_args() -> HashMap<String, String> {
+        HashMap::from([
+            (
+                START_OPTIONAL_ARGUMENT.to_string(),
+                "2025-01-01".to_string(),
+            ),
+            (END_OPTIONAL_ARGUMENT.to_string(), "2025-03-31".to_string()),
+            (
+                GAMES_OPTIONAL_ARGUMENT.to_string(),
+                "catan, azul".to_string(),
+            ),
+            (SCHEME_OPTIONAL_ARGUMENT.to_string(), "f1".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_create_season() {
+        let args = vec!["create".to_string(), "q1".to_string()];
+
+        let command = SeasonCommand::create(&args, &create_season_args()).unwrap();
+        let SeasonAction::Create(season) = command.action else {
+            panic!("Expected a create action.");
+        };
+        assert_eq!(season.get_name(), "q1");
+    }
+
+    #[test]
+    fn test_create_season_without_dates() {
+        let args = vec!["create".to_string(), "q1".to_string()];
+
+        let result = SeasonCommand::create(&args, &HashMap::new());
+        assert_eq!(result.unwrap_err(), "Missing --start for season create.");
+    }
+
+    #[test]
+    fn test_create_standings() {
+        let args = vec!["standings".to_string(), "q1".to_string()];
+
+        let command = SeasonCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(command.action, SeasonAction::Standings("q1".to_string()));
+    }
+
+    #[test]
+    fn test_create_unknown_action() {
+        let args = vec!["delete".to_string(), "q1".to_string()];
+
+        let result = SeasonCommand::create(&args, &HashMap::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::commands::models::player::Player;
+use crate::commands::models::season::PointsScheme;
+use crate::commands::models::session::Session;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, INCLUDE_GUESTS_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+    SCHEME_OPTIONAL_ARGUMENT, TIME_OPTIONAL_ARGUMENT,
+};
+use chrono::{NaiveDate, Utc};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 5] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    TIME_OPTIONAL_ARGUMENT,
+    SCHEME_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug, PartialEq)]
+enum SessionAction {
+    Start(Session),
+    End(String),
+    List,
+    /// The session, and the points scheme overriding its own.
+    Report(String, Option<PointsScheme>),
+}
+
+#[derive(Debug)]
+pub struct SessionCommand {
+    action: SessionAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl SessionCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<SessionCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for session {}.", key));
+            }
+        }
+
+        let scheme = optional_args
+            .get(SCHEME_OPTIONAL_ARGUMENT)
+            .map(|scheme| PointsScheme::parse(scheme))
+            .transpose()?;
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("start") if args.len() >= 2 => {
+                let date = match optional_args.get(TIME_OPTIONAL_ARGUMENT) {
+                    Some(time) => NaiveDate::parse_from_str(time, "%Y-%m-%d").map_err(|_| {
+                        "Error parsing date. The input format is YYYY-MM-DD.".to_string()
+                    })?,
+                    None => Utc::now().date_naive(),
+                };
+                SessionAction::Start(Session::build(
+                    args[1].clone(),
+                    date,
+                    args[2..].to_vec(),
+                    scheme.unwrap_or_default(),
+                )?)
+            }
+            Some("end") if args.len() == 2 => SessionAction::End(args[1].clone()),
+            Some("list") if args.len() == 1 => SessionAction::List,
+            Some("report") if args.len() == 2 => SessionAction::Report(args[1].clone(), scheme),
+            _ => {
+                return Err(
+                    "Invalid arguments for session. Use session start <id> [<players>], session end <id>, session list or session report <id>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(SessionCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            SessionAction::Start(session) => {
+                let players = data::load_players(data_file_path)?;
+                if let Some(missing) = session
+                    .get_attendees()
+                    .iter()
+                    .find(|p| !players.exists(Player::new(p.to_string())))
+                {
+                    return Err(format!("Player {} does not exist.", missing));
+                }
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut sessions = data::load_sessions(data_file_path)?;
+                sessions.add_session(session.clone())?;
+                data::save_sessions(data_file_path, &sessions)?;
+                transaction.commit("session", format!("Started session {}.", session.get_id()))?;
+                println!("Started session {}.", session.get_id());
+            }
+            SessionAction::End(id) => {
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut sessions = data::load_sessions(data_file_path)?;
+                sessions.get_mut(id)?.end()?;
+                data::save_sessions(data_file_path, &sessions)?;
+                transaction.commit("session", format!("Ended session {}.", id))?;
+                println!("Ended session {}.", id);
+            }
+            SessionAction::List => {
+                let sessions = data::load_sessions(data_file_path)?;
+                println!("{}", Table::new(sessions.to_session_rows()));
+            }
+            SessionAction::Report(id, scheme) => {
+                let sessions = data::load_sessions(data_file_path)?;
+                let session = sessions.get(id)?;
+                let games = data::load_all_games(data_file_path)?;
+                let mut report = session.report(
+                    &games.finished().order_by_date(),
+                    scheme.as_ref().unwrap_or(session.get_scheme()),
+                );
+                if !self
+                    .optional_args
+                    .contains_key(INCLUDE_GUESTS_OPTIONAL_ARGUMENT)
+                {
+                    report.exclude_guests();
+                }
+
+                match self.format {
+                    OutputFormat::Json => output::print_json(&report)?,
+                    OutputFormat::Table => {
+                        println!("{}", report.summary());
+                        println!("{}", Table::new(report.to_match_rows()));
+                        println!("{}", Table::new(report.to_standing_rows()));
+                        println!("{}", report.outcome());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_start() {
+        let args = vec![
+            "start".to_string(),
+            "friday".to_string(),
+            "alice".to_string(),
+            "bob".to_string(),
+        ];
+        let optional_args = HashMap::from([
+            (TIME_OPTIONAL_ARGUMENT.to_string(), "2025-08-07".to_string()),
+            (SCHEME_OPTIONAL_ARGUMENT.to_string(), "f1".to_string()),
+        ]);
+
+        let command = SessionCommand::create(&args, &optional_args).unwrap();
+        let SessionAction::Start(session) = command.action else {
+            panic!("Expected a start action.");
+        };
+        assert_eq!(session.get_id(), "friday");
+        assert_eq!(session.get_attendees(), ["alice", "bob"]);
+        assert_eq!(session.get_scheme(), &PointsScheme::parse("f1").unwrap());
+    }
+
+    #[test]
+    fn test_create_report_with_scheme() {
+        let args = vec!["report".to_string(), "friday".to_string()];
+        let optional_args =
+            HashMap::from([(SCHEME_OPTIONAL_ARGUMENT.to_string(), "2-1-0".to_string())]);
+
+        let command = SessionCommand::create(&args, &optional_args).unwrap();
+        assert_eq!(
+            command.action,
+            SessionAction::Report(
+                "friday".to_string(),
+                Some(PointsScheme::parse("2-1-0").unwrap())
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_unknown_action() {
+        let args = vec!["pause".to_string(), "friday".to_string()];
+
+        let result = SessionCommand::create(&args, &HashMap::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::commands::models::player_report::PlayerReport;
+use crate::commands::models::rating::RatingAlgorithm;
+use crate::commands::ratings::select_algorithm;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    ALGORITHM_OPTIONAL_ARGUMENT, FORMAT_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 3] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    ALGORITHM_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct ShowPlayer {
+    player_name: String,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl ShowPlayer {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<ShowPlayer, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for show-player {}.", key));
+            }
+        }
+
+        if args.len() != 1 {
+            return Err("Invalid number of arguments for show-player.".to_string());
+        }
+        if let Some(algorithm) = optional_args.get(ALGORITHM_OPTIONAL_ARGUMENT) {
+            RatingAlgorithm::parse(algorithm)?;
+        }
+
+        Ok(ShowPlayer {
+            player_name: args[0].clone(),
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let players = data::load_players(data_file_path)?;
+        let player = players.get(&self.player_name)?;
+        let games = data::load_all_games(data_file_path)?
+            .finished()
+            .order_by_date();
+        let report = PlayerReport::compute(player, &games, select_algorithm(&self.optional_args)?);
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&report)?,
+            OutputFormat::Table => {
+                println!("{}", report.summary(io::stdout().is_terminal()));
+                println!("{}", Table::new([report.get_player().to_row()]));
+                let records = report.to_game_rows();
+                if !records.is_empty() {
+                    println!("{}", Table::new(records));
+                    println!("{}", Table::new(report.to_history_rows()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["giovi98".to_string()];
+        let optional_args =
+            HashMap::from([(FORMAT_OPTIONAL_ARGUMENT.to_string(), "json".to_string())]);
+
+        let show_player = ShowPlayer::create(&args, &optional_args).unwrap();
+        assert_eq!(show_player.player_name, "giovi98");
+        assert_eq!(show_player.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_arguments() {
+        let result = ShowPlayer::create(&[], &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for show-player."
+        );
+    }
+
+    #[test]
+    fn test_create_with_unknown_algorithm() {
+        let args = vec!["giovi98".to_string()];
+        let optional_args =
+            HashMap::from([(ALGORITHM_OPTIONAL_ARGUMENT.to_string(), "elo".to_string())]);
+
+        assert!(ShowPlayer::create(&args, &optional_args).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::commands::models::streak;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, GAME_OPTIONAL_ARGUMENT, INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 4] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    INCLUDE_GUESTS_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+#[derive(Debug)]
+pub struct Stats {
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl Stats {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Stats, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for stats {}.", key));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for stats.".to_string());
+        }
+
+        Ok(Stats {
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let games = match self.optional_args.get(GAME_OPTIONAL_ARGUMENT) {
+            Some(game_name) => data::load_games(data_file_path, game_name)?,
+            None => data::load_all_games(data_file_path)?,
+        };
+        let mut streaks = streak::compute(&games.finished().order_by_date());
+        if !self
+            .optional_args
+            .contains_key(INCLUDE_GUESTS_OPTIONAL_ARGUMENT)
+        {
+            streak::exclude_guests(&mut streaks);
+        }
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&streaks)?,
+            OutputFormat::Table => {
+                println!("{}", Table::new(streak::to_streak_rows(&streaks)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(GAME_OPTIONAL_ARGUMENT.to_string(), "catan".to_string());
+
+        let result = Stats::create(&[], &optional_args);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_create_with_arguments() {
+        let args = vec!["catan".to_string()];
+
+        let result = Stats::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of arguments for stats."
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::models::config::StorageMode;
+use crate::commands::models::event::{self, Event, Projection};
+use crate::commands::utils::{data, transaction::Transaction};
+use std::collections::HashMap;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug, PartialEq)]
+enum StorageModeAction {
+    Show,
+    Switch(StorageMode),
+    Rebuild,
+}
+
+#[derive(Debug)]
+pub struct StorageModeCommand {
+    action: StorageModeAction,
+    optional_args: HashMap<String, String>,
+}
+
+impl StorageModeCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<StorageModeCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!(
+                    "Unknown optional command for storage-mode {}.",
+                    key
+                ));
+            }
+        }
+
+        let action = match args {
+            [] => StorageModeAction::Show,
+            [action] if action == "rebuild" => StorageModeAction::Rebuild,
+            [mode] => StorageModeAction::Switch(StorageMode::parse(mode)?),
+            _ => return Err("Invalid number of arguments for storage-mode.".to_string()),
+        };
+
+        Ok(StorageModeCommand {
+            action,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+        let mut config = data::load_config(data_file_path)?;
+
+        match self.action {
+            StorageModeAction::Show => {
+                println!("Storage mode: {}.", config.get_storage().get_name());
+            }
+            StorageModeAction::Switch(mode) if mode == config.get_storage() => {
+                println!("Storage mode is already {}.", mode.get_name());
+            }
+            StorageModeAction::Switch(mode) => {
+                if mode == StorageMode::Events {
+                    self.catch_up_events()?;
+                }
+                config.set_storage(mode);
+                data::save_config(data_file_path, &config)?;
+                println!("Switched storage mode to {}.", mode.get_name());
+            }
+            StorageModeAction::Rebuild => {
+                if config.get_storage() != StorageMode::Events {
+                    return Err(
+                        "Rebuilding requires the events storage mode. Run storage-mode events first."
+                            .to_string(),
+                    );
+                }
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let projection = Projection::replay(&data::load_events(data_file_path)?);
+                data::save_players(data_file_path, &projection.players)?;
+                data::replace_all_games(data_file_path, &projection.games)?;
+                transaction.commit(
+                    "storage-mode",
+                    "Rebuilt players and games from the event log.".to_string(),
+                )?;
+                println!(
+                    "Rebuilt {} players and {} games from the event log.",
+                    projection.players.get_players().len(),
+                    projection.games.get_games().len()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Brings the event log up to date with the current data before it
+    /// becomes canonical: an empty log is seeded from the data, otherwise the
+    /// changes made while in snapshot mode are appended.
+    fn catch_up_events(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let players = data::load_players(data_file_path)?;
+        let games = data::load_all_games(data_file_path)?;
+        let events = data::load_events(data_file_path)?;
+
+        let new_events = if events.is_empty() {
+            event::seed(&players, &games)
+        } else {
+            let projection = Projection::replay(&events);
+            event::diff(&projection.players, &projection.games, &players, &games)
+                .into_iter()
+                .map(Event::new)
+                .collect()
+        };
+
+        data::append_events(data_file_path, &new_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_show() {
+        let command = StorageModeCommand::create(&[], &HashMap::new()).unwrap();
+        assert_eq!(command.action, StorageModeAction::Show);
+    }
+
+    #[test]
+    fn test_create_switch() {
+        let args = vec!["events".to_string()];
+
+        let command = StorageModeCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(
+            command.action,
+            StorageModeAction::Switch(StorageMode::Events)
+        );
+    }
+
+    #[test]
+    fn test_create_rebuild() {
+        let args = vec!["rebuild".to_string()];
+
+        let command = StorageModeCommand::create(&args, &HashMap::new()).unwrap();
+        assert_eq!(command.action, StorageModeAction::Rebuild);
+    }
+
+    #[test]
+    fn test_create_unknown_mode() {
+        let args = vec!["sqlite".to_string()];
+
+        let result = StorageModeCommand::create(&args, &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown storage mode sqlite. Supported modes are snapshot and events."
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use crate::commands::models::game::Game;
+use crate::commands::models::tournament::{Tournament, TournamentFormat};
+use crate::commands::predict::{check_duplicates, resolve_lineup};
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::utils::{data, transaction::Transaction};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, GAME_OPTIONAL_ARGUMENT, SAVE_DIR_OPTIONAL_ARGUMENT,
+    TIME_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 4] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    TIME_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+
+/// Every match of a tournament is played by two players.
+const PLAYERS_PER_MATCH: usize = 2;
+
+#[derive(Debug, PartialEq)]
+enum TournamentAction {
+    Create {
+        name: String,
+        format: TournamentFormat,
+        game_name: String,
+        players: Vec<String>,
+    },
+    List,
+    Show(String),
+    Record(String, Box<Game>),
+}
+
+#[derive(Debug)]
+pub struct TournamentCommand {
+    action: TournamentAction,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl TournamentCommand {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<TournamentCommand, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for tournament {}.", key));
+            }
+        }
+
+        let action = match args.first().map(|a| a.as_str()) {
+            Some("create") if args.len() >= 3 => {
+                let game_name = optional_args
+                    .get(GAME_OPTIONAL_ARGUMENT)
+                    .ok_or("Missing --game for tournament create.")?;
+                let players = args[3..].to_vec();
+                check_duplicates(&players)?;
+                TournamentAction::Create {
+                    name: args[1].clone(),
+                    format: TournamentFormat::parse(&args[2])?,
+                    game_name: game_name.clone(),
+                    players,
+                }
+            }
+            Some("list") if args.len() == 1 => TournamentAction::List,
+            Some("show") if args.len() == 2 => TournamentAction::Show(args[1].clone()),
+            Some("record") if args.len() == 4 => TournamentAction::Record(
+                args[1].clone(),
+                Box::new(Game::build(
+                    String::new(),
+                    args[2..].to_vec(),
+                    optional_args
+                        .get(TIME_OPTIONAL_ARGUMENT)
+                        .map(|s| s.as_str()),
+                )?),
+            ),
+            _ => {
+                return Err(
+                    "Invalid arguments for tournament. Use tournament create <name> <format> <players>, tournament list, tournament show <name> or tournament record <name> <player::score> <player::score>."
+                        .to_string(),
+                );
+            }
+        };
+
+        Ok(TournamentCommand {
+            action,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        match &self.action {
+            TournamentAction::Create {
+                name,
+                format,
+                game_name,
+                players,
+            } => {
+                let players = resolve_lineup(&data::load_players(data_file_path)?, players)?;
+                let catalog = data::load_catalog(data_file_path)?;
+                let game_name = match catalog.resolve(game_name) {
+                    Some(entry) => {
+                        entry.check_player_count(PLAYERS_PER_MATCH)?;
+                        entry.get_name()
+                    }
+                    None => game_name,
+                };
+                let tournament =
+                    Tournament::build(name.clone(), game_name.to_string(), *format, players)?;
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut tournaments = data::load_tournaments(data_file_path)?;
+                tournaments.add_tournament(tournament.clone())?;
+                data::save_tournaments(data_file_path, &tournaments)?;
+                transaction.commit(
+                    "tournament",
+                    format!("Created tournament {}.", tournament.get_name()),
+                )?;
+                println!("Created tournament {}.", tournament.get_name());
+            }
+            TournamentAction::List => {
+                let tournaments = data::load_tournaments(data_file_path)?;
+                println!("{}", Table::new(tournaments.to_tournament_rows()));
+            }
+            TournamentAction::Show(name) => {
+                let tournaments = data::load_tournaments(data_file_path)?;
+                let tournament = tournaments.find(name)?;
+
+                match self.format {
+                    OutputFormat::Json => output::print_json(tournament)?,
+                    OutputFormat::Table => {
+                        println!("{}", Table::new(tournament.to_pairing_rows()));
+                        if !tournament.is_elimination() {
+                            println!("{}", Table::new(tournament.to_standing_rows()));
+                        }
+                        if let Some(champion) = tournament.get_champion() {
+                            println!("Won by {}.", champion);
+                        }
+                    }
+                }
+            }
+            TournamentAction::Record(name, result) => {
+                let players = data::load_players(data_file_path)?;
+                let names = result.get_scores().keys().cloned().collect::<Vec<_>>();
+                let mut result = (**result).clone();
+                for (name, player) in names.iter().zip(resolve_lineup(&players, &names)?) {
+                    result.rename_player(name, &player)?;
+                }
+
+                let transaction = Transaction::begin(data_file_path)?;
+                let mut tournaments = data::load_tournaments(data_file_path)?;
+                let tournament = tournaments.find_mut(name)?;
+                let mut game = Game::new(
+                    tournament.get_game_name().to_string(),
+                    result.get_scores().clone(),
+                    *result.get_datetime(),
+                    Vec::new(),
+                );
+                game.set_tournament(*tournament.get_id());
+                tournament.record(&game)?;
+
+                let mut games = data::load_games(data_file_path, game.get_name())?;
+                games.add_game(game.clone());
+                data::save_games(data_file_path, game.get_name(), &games)?;
+                let status = match tournament.get_champion() {
+                    Some(champion) => format!("Tournament {} won by {}.", name, champion),
+                    None => format!("Round {} of {} in progress.", tournament.get_round(), name),
+                };
+                data::save_tournaments(data_file_path, &tournaments)?;
+                transaction.commit(
+                    "tournament",
+                    format!(
+                        "Recorded game of tournament {} with id {}.",
+                        name,
+                        game.get_id()
+                    ),
+                )?;
+                println!(
+                    "Added game of {} with id: {}.",
+                    game.get_name(),
+                    game.get_id()
+                );
+                println!("{}", status);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_create_tournament() {
+        let args = create_args(&["create", "cup", "swiss", "alice", "bob", "carol"]);
+        let optional_args =
+            HashMap::from([(GAME_OPTIONAL_ARGUMENT.to_string(), "chess".to_string())]);
+
+        let command = TournamentCommand::create(&args, &optional_args).unwrap();
+        let TournamentAction::Create {
+            name,
+            format,
+            game_name,
+            players,
+        } = command.action
+        else {
+            panic!("Expected a create action.");
+        };
+        assert_eq!(name, "cup");
+        assert_eq!(format, TournamentFormat::Swiss);
+        assert_eq!(game_name, "chess");
+        assert_eq!(players, vec!["alice", "bob", "carol"]);
+    }
+
+    #[test]
+    fn test_create_tournament_without_game() {
+        let args = create_args(&["create", "cup", "swiss", "alice", "bob"]);
+
+        let result = TournamentCommand::create(&args, &HashMap::new());
+        assert_eq!(result.unwrap_err(), "Missing --game for tournament create.");
+    }
+
+    #[test]
+    fn test_create_tournament_with_unknown_format() {
+        let args = create_args(&["create", "cup", "ladder", "alice", "bob"]);
+        let optional_args =
+            HashMap::from([(GAME_OPTIONAL_ARGUMENT.to_string(), "chess".to_string())]);
+
+        let result = TournamentCommand::create(&args, &optional_args);
+        assert!(
+            result
+                .unwrap_err()
+                .starts_with("Unknown tournament format ladder.")
+        );
+    }
+
+    #[test]
+    fn test_create_record() {
+        let args = create_args(&["record", "cup", "alice::2", "bob::1"]);
+
+        let command = TournamentCommand::create(&args, &HashMap::new()).unwrap();
+        let TournamentAction::Record(name, game) = command.action else {
+            panic!("Expected a record action.");
+        };
+        assert_eq!(name, "cup");
+        assert_eq!(game.get_scores()["alice"], 2);
+    }
+
+    #[test]
+    fn test_create_record_needs_two_scores() {
+        let args = create_args(&["record", "cup", "alice::2"]);
+
+        let result = TournamentCommand::create(&args, &HashMap::new());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use crate::commands::SAVE_DIR_OPTIONAL_ARGUMENT;
+use crate::commands::models::journal::JournalEntry;
+use crate::commands::utils::transaction;
+use std::collections::HashMap;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 1] = [SAVE_DIR_OPTIONAL_ARGUMENT];
+
+#[derive(Debug)]
+pub struct Undo {
+    optional_args: HashMap<String, String>,
+}
+
+impl Undo {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Undo, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for undo {}.", key));
+            }
+        }
+
+        if !args.is_empty() {
+            return Err("Invalid number of arguments for undo.".to_string());
+        }
+
+        Ok(Undo {
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let journal = transaction::load_journal(data_file_path)?;
+        let target = journal.next_undo().ok_or("Nothing to undo.".to_string())?;
+
+        let entry = JournalEntry::undo(target);
+        transaction::apply_changes(data_file_path, entry.get_changes())
+            .map_err(|e| format!("Cannot undo \"{}\". {}", target.get_description(), e))?;
+        transaction::append_entry(data_file_path, &entry)?;
+
+        println!("Undid: {}", target.get_description());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let mut optional_args = HashMap::new();
+        optional_args.insert(
+            SAVE_DIR_OPTIONAL_ARGUMENT.to_string(),
+            "path/to/dir".to_string(),
+        );
+
+        let result = Undo::create(&[], &optional_args);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_create_invalid_number_of_args() {
+        let args = vec!["extra".to_string()];
+
+        let result = Undo::create(&args, &HashMap::new());
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), "Invalid number of arguments for undo.");
+    }
+}
@@ -1,3 +1,8 @@
+pub mod data;
 pub mod file_wrapper;
+pub mod json_lines_file;
+pub mod output;
 pub mod storage;
+pub mod transaction;
+#[allow(clippy::module_inception)]
 pub mod utils;
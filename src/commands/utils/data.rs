@@ -0,0 +1,601 @@
+use crate::commands::add_score::GAMES_FOLER;
+use crate::commands::models::catalog::{self, Catalog};
+use crate::commands::models::config::{CONFIG_FILE_NAME, Config, StorageMode};
+use crate::commands::models::event::{EVENTS_FILE_NAME, Event, Projection};
+use crate::commands::models::game::Games;
+use crate::commands::models::game_index::{self, GameIndex};
+use crate::commands::models::player::{self, Players};
+use crate::commands::models::season::{self, Seasons};
+use crate::commands::models::session::{self, Sessions};
+use crate::commands::models::tournament::{self, Tournaments};
+use crate::commands::utils::{
+    file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions, json_lines_file::JsonLinesFile,
+    storage::Storage, transaction::Transaction, utils::create_path,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// The players and games rebuilt from the event log, when the save dir uses
+/// the events storage mode. The log is then the canonical data, so every
+/// read goes through it.
+fn load_projection(save_dir: Option<&String>) -> Result<Option<Projection>, String> {
+    if load_config(save_dir)?.get_storage() != StorageMode::Events {
+        return Ok(None);
+    }
+
+    Ok(Some(Projection::replay(&load_events(save_dir)?)))
+}
+
+pub fn load_players(save_dir: Option<&String>) -> Result<Players, String> {
+    if let Some(projection) = load_projection(save_dir)? {
+        return Ok(projection.players);
+    }
+
+    load_or_empty(save_dir, player::FILE_NAME_DATA, Players::create_empty)
+}
+
+pub fn save_players(save_dir: Option<&String>, players: &Players) -> Result<(), String> {
+    let mut file = FileWrapper::from_string(
+        &[player::FILE_NAME_DATA],
+        save_dir,
+        FileWrapperOptions::default(),
+    )?;
+    file.save(players)
+}
+
+pub fn game_files(save_dir: Option<&String>) -> Result<Vec<PathBuf>, String> {
+    let game_dir = create_path(&[GAMES_FOLER], save_dir)?;
+    if !game_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    list_files(&game_dir)
+}
+
+pub fn list_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = fs::read_dir(dir)
+        .map_err(|_| "An error occurred while accessing the data.")?
+        .map(|res| res.map(|e| e.path()))
+        .collect::<Result<Vec<_>, io::Error>>()
+        .map_err(|_| "An error occurred while accessing the data.")?;
+    files.sort();
+
+    Ok(files)
+}
+
+pub fn load_all_games(save_dir: Option<&String>) -> Result<Games, String> {
+    if let Some(projection) = load_projection(save_dir)? {
+        return Ok(projection.games);
+    }
+
+    let mut all_games = Games::create_empy();
+    for game_file in game_files(save_dir)? {
+        let mut file = FileWrapper::from_path(game_file, FileWrapperOptions::default())?;
+        let games: Games = file.load()?;
+        all_games.extend(&games);
+    }
+
+    Ok(all_games)
+}
+
+pub fn load_game_index(save_dir: Option<&String>) -> Result<GameIndex, String> {
+    load_or_empty(
+        save_dir,
+        game_index::FILE_NAME_DATA,
+        GameIndex::create_empty,
+    )
+}
+
+pub fn save_game_index(save_dir: Option<&String>, index: &GameIndex) -> Result<(), String> {
+    save(save_dir, game_index::FILE_NAME_DATA, index)
+}
+
+fn load_game_file(save_dir: Option<&String>, slug: &str) -> Result<Games, String> {
+    let file_name = format!("{}.json", slug);
+    if !create_path(&[GAMES_FOLER, &file_name], save_dir)?.exists() {
+        return Ok(Games::create_empy());
+    }
+
+    let mut file = FileWrapper::from_string(
+        &[GAMES_FOLER, &file_name],
+        save_dir,
+        FileWrapperOptions::default(),
+    )?;
+    if file.is_empty()? {
+        Ok(Games::create_empy())
+    } else {
+        file.load()
+    }
+}
+
+fn save_game_file(save_dir: Option<&String>, slug: &str, games: &Games) -> Result<(), String> {
+    let file_name = format!("{}.json", slug);
+    let mut file = FileWrapper::from_string(
+        &[GAMES_FOLER, &file_name],
+        save_dir,
+        FileWrapperOptions::default(),
+    )?;
+    file.save(games)
+}
+
+fn remove_game_file(save_dir: Option<&String>, slug: &str) -> Result<(), String> {
+    let path = create_path(&[GAMES_FOLER, &format!("{}.json", slug)], save_dir)?;
+    if path.exists() {
+        fs::remove_file(&path).map_err(|_| format!("Error removing file: {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// The games as they were at the end of `as_of`, in UTC: the event log is
+/// replayed up to that day, so matches recorded, edited or deleted later are
+/// seen as they were then. Matches dated after `as_of` are left out.
+pub fn load_games_as_of(save_dir: Option<&String>, as_of: NaiveDate) -> Result<Games, String> {
+    if load_config(save_dir)?.get_storage() != StorageMode::Events {
+        return Err("Point in time queries require the events storage mode.".to_string());
+    }
+
+    let events = load_events(save_dir)?
+        .into_iter()
+        .filter(|event| event.get_recorded_at().date_naive() <= as_of)
+        .collect::<Vec<_>>();
+    Ok(Projection::replay(&events).games.played_until(as_of))
+}
+
+pub fn load_games(save_dir: Option<&String>, game_name: &str) -> Result<Games, String> {
+    if let Some(projection) = load_projection(save_dir)? {
+        return Ok(projection.games.with_name(game_name));
+    }
+
+    let index = load_game_index(save_dir)?;
+    let mut games = match index.get_slug(game_name) {
+        Some(slug) => load_game_file(save_dir, slug)?,
+        None => Games::create_empy(),
+    };
+    // Reads leave files of older versions alone, the first write migrates
+    // them.
+    for stem in unindexed_game_files(save_dir, &index)? {
+        games.extend(&load_game_file(save_dir, &stem)?.with_name(game_name));
+    }
+
+    Ok(games)
+}
+
+/// The names, without extension, of the game files the game index does not
+/// know about.
+fn unindexed_game_files(
+    save_dir: Option<&String>,
+    index: &GameIndex,
+) -> Result<Vec<String>, String> {
+    Ok(game_files(save_dir)?
+        .iter()
+        .filter_map(|path| path.file_stem().and_then(|stem| stem.to_str()))
+        .filter(|stem| index.get_name(stem).is_none())
+        .map(|stem| stem.to_string())
+        .collect())
+}
+
+/// Writes the games of `game_name` to its file, registering a new file in
+/// the game index the first time.
+pub fn save_games(save_dir: Option<&String>, game_name: &str, games: &Games) -> Result<(), String> {
+    let mut index = load_game_index(save_dir)?;
+    let (slug, added) = index.get_or_add(game_name);
+    if added {
+        save_game_index(save_dir, &index)?;
+    }
+    save_game_file(save_dir, &slug, games)
+}
+
+/// Removes the file of `game_name`, if any.
+pub fn remove_games(save_dir: Option<&String>, game_name: &str) -> Result<(), String> {
+    let mut index = load_game_index(save_dir)?;
+    if let Some(slug) = index.remove(game_name) {
+        remove_game_file(save_dir, &slug)?;
+        save_game_index(save_dir, &index)?;
+    }
+
+    Ok(())
+}
+
+/// Registers in the game index the game files it does not know about, such
+/// as those written by older versions, which named files after the game.
+/// Files whose name is not a safe slug are moved to one. The migration is
+/// journaled as a change of its own, and runs when a transaction begins so
+/// that commands which only read never write.
+pub fn migrate_game_files(save_dir: Option<&String>) -> Result<(), String> {
+    let mut index = load_game_index(save_dir)?;
+    let unindexed = unindexed_game_files(save_dir, &index)?;
+    if unindexed.is_empty() {
+        return Ok(());
+    }
+
+    let transaction = Transaction::begin_migration(save_dir)?;
+    let mut to_move = Vec::new();
+    for stem in unindexed {
+        let games = load_game_file(save_dir, &stem)?;
+        let game_name = games
+            .get_games()
+            .values()
+            .next()
+            .map_or(stem.clone(), |game| game.get_name().to_string());
+        if index.get_slug(&game_name).is_none()
+            && game_index::is_slug(&stem)
+            && !index.contains_slug(&stem)
+        {
+            index.insert(stem, game_name);
+        } else {
+            to_move.push((stem, game_name, games));
+        }
+    }
+    for (stem, game_name, games) in to_move {
+        let (slug, _) = index.get_or_add(&game_name);
+        let mut merged = load_game_file(save_dir, &slug)?;
+        merged.extend(&games);
+        remove_game_file(save_dir, &stem)?;
+        save_game_file(save_dir, &slug, &merged)?;
+    }
+    save_game_index(save_dir, &index)?;
+
+    transaction.commit(
+        "migrate",
+        "Registered the game files in the game index.".to_string(),
+    )
+}
+
+/// Writes every game to the file of its game name. Each file is overwritten
+/// with the games of that name, so `games` must hold all of them.
+pub fn save_all_games(save_dir: Option<&String>, games: &Games) -> Result<(), String> {
+    let mut games_by_name: HashMap<&str, Games> = HashMap::new();
+    for game in games.get_games().values() {
+        games_by_name
+            .entry(game.get_name())
+            .or_insert_with(Games::create_empy)
+            .add_game(game.clone());
+    }
+
+    for (game_name, games) in &games_by_name {
+        save_games(save_dir, game_name, games)?;
+    }
+
+    Ok(())
+}
+
+/// Like `save_all_games`, but also empties the game files whose games are
+/// no longer present.
+pub fn replace_all_games(save_dir: Option<&String>, games: &Games) -> Result<(), String> {
+    save_all_games(save_dir, games)?;
+
+    let index = load_game_index(save_dir)?;
+    for game_name in index.get_names() {
+        if !games
+            .get_games()
+            .values()
+            .any(|g| g.get_name() == game_name)
+        {
+            save_games(save_dir, game_name, &Games::create_empy())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads `file_name` from the save dir, or `empty()` if the file does not
+/// exist yet. The file is not created.
+fn load_or_empty<T: serde::de::DeserializeOwned>(
+    save_dir: Option<&String>,
+    file_name: &str,
+    empty: fn() -> T,
+) -> Result<T, String> {
+    if !create_path(&[file_name], save_dir)?.exists() {
+        return Ok(empty());
+    }
+
+    let mut file = FileWrapper::from_string(&[file_name], save_dir, FileWrapperOptions::default())?;
+    if file.is_empty()? {
+        Ok(empty())
+    } else {
+        file.load()
+    }
+}
+
+fn save<T: serde::Serialize>(
+    save_dir: Option<&String>,
+    file_name: &str,
+    data: &T,
+) -> Result<(), String> {
+    let mut file = FileWrapper::from_string(&[file_name], save_dir, FileWrapperOptions::default())?;
+    file.save(data)
+}
+
+pub fn load_config(save_dir: Option<&String>) -> Result<Config, String> {
+    load_or_empty(save_dir, CONFIG_FILE_NAME, Config::default)
+}
+
+pub fn save_config(save_dir: Option<&String>, config: &Config) -> Result<(), String> {
+    save(save_dir, CONFIG_FILE_NAME, config)
+}
+
+pub fn load_catalog(save_dir: Option<&String>) -> Result<Catalog, String> {
+    load_or_empty(save_dir, catalog::FILE_NAME_DATA, Catalog::create_empty)
+}
+
+pub fn save_catalog(save_dir: Option<&String>, catalog: &Catalog) -> Result<(), String> {
+    save(save_dir, catalog::FILE_NAME_DATA, catalog)
+}
+
+pub fn load_seasons(save_dir: Option<&String>) -> Result<Seasons, String> {
+    load_or_empty(save_dir, season::FILE_NAME_DATA, Seasons::create_empty)
+}
+
+pub fn save_seasons(save_dir: Option<&String>, seasons: &Seasons) -> Result<(), String> {
+    save(save_dir, season::FILE_NAME_DATA, seasons)
+}
+
+pub fn load_sessions(save_dir: Option<&String>) -> Result<Sessions, String> {
+    load_or_empty(save_dir, session::FILE_NAME_DATA, Sessions::create_empty)
+}
+
+pub fn save_sessions(save_dir: Option<&String>, sessions: &Sessions) -> Result<(), String> {
+    save(save_dir, session::FILE_NAME_DATA, sessions)
+}
+
+pub fn load_tournaments(save_dir: Option<&String>) -> Result<Tournaments, String> {
+    load_or_empty(
+        save_dir,
+        tournament::FILE_NAME_DATA,
+        Tournaments::create_empty,
+    )
+}
+
+pub fn save_tournaments(
+    save_dir: Option<&String>,
+    tournaments: &Tournaments,
+) -> Result<(), String> {
+    save(save_dir, tournament::FILE_NAME_DATA, tournaments)
+}
+
+pub fn load_events(save_dir: Option<&String>) -> Result<Vec<Event>, String> {
+    let mut file = JsonLinesFile::from_string(&[EVENTS_FILE_NAME], save_dir)?;
+    file.load()
+}
+
+pub fn append_events(save_dir: Option<&String>, events: &[Event]) -> Result<(), String> {
+    let mut file = JsonLinesFile::from_string(&[EVENTS_FILE_NAME], save_dir)?;
+    for event in events {
+        file.save(event)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::config::StorageMode;
+    use crate::commands::models::game::Game;
+    use crate::commands::models::player::Player;
+    use tempfile::tempdir;
+
+    #[test]
+    fn load_players_empty_save_dir() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let players = load_players(Some(&dir_path)).unwrap();
+        assert!(!players.exists(Player::new("alice".to_string())));
+    }
+
+    #[test]
+    fn save_and_load_players() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        save_players(Some(&dir_path), &players).unwrap();
+
+        let loaded = load_players(Some(&dir_path)).unwrap();
+        assert!(loaded.exists(Player::new("alice".to_string())));
+    }
+
+    #[test]
+    fn load_games_missing_file_does_not_create_it() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let games = load_games(Some(&dir_path), "catan").unwrap();
+        assert!(games.get_games().is_empty());
+        assert!(game_files(Some(&dir_path)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_and_load_all_games() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        for name in ["catan", "azul"] {
+            let mut games = Games::create_empy();
+            games.add_game(
+                Game::build(
+                    name.to_string(),
+                    vec!["alice::10".to_string()],
+                    Some("2025-01-01"),
+                )
+                .unwrap(),
+            );
+            save_games(Some(&dir_path), name, &games).unwrap();
+        }
+
+        let all_games = load_all_games(Some(&dir_path)).unwrap();
+        assert_eq!(all_games.get_games().len(), 2);
+    }
+
+    #[test]
+    fn save_all_games_groups_by_name() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let mut games = Games::create_empy();
+        for name in ["catan", "catan", "azul"] {
+            games.add_game(
+                Game::build(
+                    name.to_string(),
+                    vec!["alice::10".to_string()],
+                    Some("2025-01-01"),
+                )
+                .unwrap(),
+            );
+        }
+        save_all_games(Some(&dir_path), &games).unwrap();
+
+        assert_eq!(game_files(Some(&dir_path)).unwrap().len(), 2);
+        assert_eq!(
+            load_games(Some(&dir_path), "catan")
+                .unwrap()
+                .get_games()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn replace_all_games_empties_removed_games() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let mut games = Games::create_empy();
+        games.add_game(
+            Game::build(
+                "catan".to_string(),
+                vec!["alice::10".to_string()],
+                Some("2025-01-01"),
+            )
+            .unwrap(),
+        );
+        save_all_games(Some(&dir_path), &games).unwrap();
+
+        replace_all_games(Some(&dir_path), &Games::create_empy()).unwrap();
+        assert!(
+            load_all_games(Some(&dir_path))
+                .unwrap()
+                .get_games()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn save_games_uses_safe_file_name() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let mut games = Games::create_empy();
+        games.add_game(
+            Game::build(
+                "../Ticket to Ride".to_string(),
+                vec!["alice::10".to_string()],
+                Some("2025-01-01"),
+            )
+            .unwrap(),
+        );
+        save_all_games(Some(&dir_path), &games).unwrap();
+
+        assert!(
+            dir.path()
+                .join("games")
+                .join("Ticket-to-Ride.json")
+                .exists()
+        );
+        let loaded = load_games(Some(&dir_path), "../Ticket to Ride").unwrap();
+        assert_eq!(loaded.get_games().len(), 1);
+    }
+
+    #[test]
+    fn migrate_game_files_moves_unsafe_names() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        for game_name in ["catan", "Ticket to Ride"] {
+            let mut games = Games::create_empy();
+            games.add_game(
+                Game::build(
+                    game_name.to_string(),
+                    vec!["alice::10".to_string()],
+                    Some("2025-01-01"),
+                )
+                .unwrap(),
+            );
+            save_game_file(Some(&dir_path), game_name, &games).unwrap();
+        }
+
+        migrate_game_files(Some(&dir_path)).unwrap();
+
+        let games_dir = dir.path().join("games");
+        assert!(games_dir.join("catan.json").exists());
+        assert!(games_dir.join("Ticket-to-Ride.json").exists());
+        assert!(!games_dir.join("Ticket to Ride.json").exists());
+        let loaded = load_games(Some(&dir_path), "Ticket to Ride").unwrap();
+        assert_eq!(loaded.get_games().len(), 1);
+    }
+
+    #[test]
+    fn load_config_missing_file() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let config = load_config(Some(&dir_path)).unwrap();
+        assert_eq!(config.get_storage(), StorageMode::Snapshot);
+        assert!(!dir.path().join(CONFIG_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn load_games_as_of_replays_the_log_up_to_the_day() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let mut config = Config::default();
+        config.set_storage(StorageMode::Events);
+        save_config(Some(&dir_path), &config).unwrap();
+
+        let game = Game::build(
+            "catan".to_string(),
+            vec!["alice::10".to_string()],
+            Some("2025-01-10"),
+        )
+        .unwrap();
+        let events = [
+            serde_json::json!({
+                "recorded_at": "2025-01-10T20:00:00Z",
+                "type": "MatchRecorded",
+                "game": game,
+            }),
+            serde_json::json!({
+                "recorded_at": "2025-02-01T20:00:00Z",
+                "type": "MatchDeleted",
+                "game_id": game.get_id(),
+            }),
+        ];
+        let log = events
+            .iter()
+            .map(|event| format!("{}\n", event))
+            .collect::<String>();
+        fs::write(dir.path().join(EVENTS_FILE_NAME), log).unwrap();
+
+        let as_of = |date: &str| {
+            load_games_as_of(Some(&dir_path), date.parse().unwrap())
+                .unwrap()
+                .get_games()
+                .len()
+        };
+        assert_eq!(as_of("2025-01-09"), 0);
+        assert_eq!(as_of("2025-01-10"), 1);
+        assert_eq!(as_of("2025-01-31"), 1);
+        assert_eq!(as_of("2025-02-01"), 0);
+        assert!(
+            load_all_games(Some(&dir_path))
+                .unwrap()
+                .get_games()
+                .is_empty()
+        );
+    }
+}
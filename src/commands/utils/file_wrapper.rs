@@ -105,7 +105,7 @@ impl FileWrapper {
         match players_file {
             Ok(file) => Ok(FileWrapper {
                 path: file_path,
-                file: file,
+                file,
             }),
             Err(_err) => Err(format!(
                 "An error occured while trying to open: {}",
@@ -132,10 +132,7 @@ impl FileWrapper {
             .open(&path);
 
         match players_file {
-            Ok(file) => Ok(FileWrapper {
-                path: path,
-                file: file,
-            }),
+            Ok(file) => Ok(FileWrapper { path, file }),
             Err(_err) => Err(format!(
                 "An error occured while trying to open: {}",
                 path.display()
@@ -0,0 +1,147 @@
+use crate::commands::utils::storage::Storage;
+use crate::commands::utils::utils::create_path;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::fs::{File, OpenOptions, create_dir_all};
+use std::io::{Read, Seek, Write};
+use std::path::PathBuf;
+
+/// Append-only storage with one JSON document per line. `save` appends the
+/// entity as a new line and `load` reads every line as an element of a list.
+pub struct JsonLinesFile {
+    path: PathBuf,
+    file: File,
+}
+
+impl Storage for JsonLinesFile {
+    fn is_empty(&mut self) -> Result<bool, String> {
+        let file_data = self.get_data()?;
+        Ok(file_data.trim().is_empty())
+    }
+
+    fn save(&mut self, entity: &impl Serialize) -> Result<(), String> {
+        let data_serialized = serde_json::to_string(entity)
+            .map_err(|_| format!("Error serializing data for {}", self.path.display()))?;
+        self.file
+            .write_all(format!("{}\n", data_serialized).as_bytes())
+            .map_err(|_: std::io::Error| format!("Error writing file: {}", self.path.display()))?;
+        Ok(())
+    }
+
+    fn load<T: DeserializeOwned>(&mut self) -> Result<T, String> {
+        let file_data = self.get_data()?;
+
+        let lines = file_data
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<Value>, _>>()
+            .map_err(|_| format!("Error deserializing file: {}", self.path.display()))?;
+
+        serde_json::from_value(Value::Array(lines))
+            .map_err(|_| format!("Error deserializing file: {}", self.path.display()))
+    }
+
+    fn get_data(&mut self) -> Result<String, String> {
+        let mut existing_data = String::new();
+        self.file
+            .rewind()
+            .map_err(|e| format!("rewind error: {}", e))?;
+
+        match self.file.read_to_string(&mut existing_data) {
+            Ok(_) => Ok(existing_data),
+            Err(_) => Err(format!(
+                "An error occurred reading file: {}",
+                self.path.display()
+            )),
+        }
+    }
+}
+
+impl JsonLinesFile {
+    pub fn from_string(path: &[&str], base_dir: Option<&String>) -> Result<JsonLinesFile, String> {
+        let file_path = create_path(path, base_dir)?;
+
+        if let Some(parent_dir) = file_path.parent() {
+            create_dir_all(parent_dir).map_err(|e| {
+                format!(
+                    "Failed to create directories for path {}: {}",
+                    file_path.display(),
+                    e
+                )
+            })?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&file_path);
+
+        match file {
+            Ok(file) => Ok(JsonLinesFile {
+                path: file_path,
+                file,
+            }),
+            Err(_err) => Err(format!(
+                "An error occured while trying to open: {}",
+                file_path.display()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use tempfile::tempdir;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct DummyData {
+        name: String,
+        value: i32,
+    }
+
+    #[test]
+    fn test_save_appends_lines() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let mut file = JsonLinesFile::from_string(&["log.jsonl"], Some(&dir_path)).unwrap();
+
+        for value in [1, 2] {
+            file.save(&DummyData {
+                name: "Test".to_string(),
+                value,
+            })
+            .unwrap();
+        }
+
+        let loaded: Vec<DummyData> = file.load().unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1].value, 2);
+        assert_eq!(file.get_data().unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_load_empty_file() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let mut file = JsonLinesFile::from_string(&["log.jsonl"], Some(&dir_path)).unwrap();
+
+        assert!(file.is_empty().unwrap());
+        let loaded: Vec<DummyData> = file.load().unwrap();
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn test_load_invalid_line() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let mut file = JsonLinesFile::from_string(&["log.jsonl"], Some(&dir_path)).unwrap();
+        file.file.write_all(b"{invalid json\n").unwrap();
+
+        let result: Result<Vec<DummyData>, _> = file.load();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn parse(format: Option<&String>) -> Result<OutputFormat, String> {
+        match format.map(|f| f.as_str()) {
+            None | Some("table") => Ok(OutputFormat::Table),
+            Some("json") => Ok(OutputFormat::Json),
+            Some(other) => Err(format!(
+                "Unknown format {}. Supported formats are table and json.",
+                other
+            )),
+        }
+    }
+}
+
+pub fn print_json<T: Serialize>(value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|_| "Error serializing the output.".to_string())?;
+    println!("{}", json);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_defaults_to_table() {
+        assert_eq!(OutputFormat::parse(None).unwrap(), OutputFormat::Table);
+        assert_eq!(
+            OutputFormat::parse(Some(&"json".to_string())).unwrap(),
+            OutputFormat::Json
+        );
+    }
+
+    #[test]
+    fn parse_unknown_format() {
+        let result = OutputFormat::parse(Some(&"csv".to_string()));
+        assert_eq!(
+            result.unwrap_err(),
+            "Unknown format csv. Supported formats are table and json."
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use crate::commands::add_score::GAMES_FOLER;
+use crate::commands::models::config::{CONFIG_FILE_NAME, StorageMode};
+use crate::commands::models::event::{self, Event};
+use crate::commands::models::game::Games;
+use crate::commands::models::journal::{FileChange, JOURNAL_FILE_NAME, Journal, JournalEntry};
+use crate::commands::models::merge::CONFLICTS_FILE_NAME;
+use crate::commands::models::player::{self, Players};
+use crate::commands::models::{catalog, game_index, season, session, tournament};
+use crate::commands::utils::{
+    data, file_wrapper::FileWrapper, file_wrapper::FileWrapperOptions,
+    json_lines_file::JsonLinesFile, storage::Storage, utils::create_path,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+
+/// Data files, relative to the save dir, whose changes are journaled.
+const TRACKED_FILES: [&str; 8] = [
+    CONFIG_FILE_NAME,
+    CONFLICTS_FILE_NAME,
+    player::FILE_NAME_DATA,
+    catalog::FILE_NAME_DATA,
+    game_index::FILE_NAME_DATA,
+    season::FILE_NAME_DATA,
+    session::FILE_NAME_DATA,
+    tournament::FILE_NAME_DATA,
+];
+/// Folders whose files are all journaled.
+const TRACKED_FOLDERS: [&str; 1] = [GAMES_FOLER];
+
+type Snapshot = BTreeMap<String, Option<Value>>;
+
+/// Records the changes a command makes to the save dir as one journal entry.
+/// The tracked files are captured on `begin` and compared on `commit`. A
+/// transaction dropped without being committed, as when the command fails
+/// half way, restores the files captured on `begin`.
+pub struct Transaction {
+    save_dir: Option<String>,
+    before: Snapshot,
+    committed: bool,
+}
+
+impl Transaction {
+    /// Begins a transaction, first bringing the files written by older
+    /// versions up to date in a journal entry of their own.
+    pub fn begin(save_dir: Option<&String>) -> Result<Transaction, String> {
+        data::migrate_game_files(save_dir)?;
+        Transaction::begin_migration(save_dir)
+    }
+
+    /// Begins a transaction without migrating the save dir, for the
+    /// migration itself.
+    pub fn begin_migration(save_dir: Option<&String>) -> Result<Transaction, String> {
+        Ok(Transaction {
+            save_dir: save_dir.cloned(),
+            before: snapshot(save_dir)?,
+            committed: false,
+        })
+    }
+
+    pub fn commit(mut self, command: &str, description: String) -> Result<(), String> {
+        let save_dir = self.save_dir.as_ref();
+        let after = snapshot(save_dir)?;
+
+        let paths = self
+            .before
+            .keys()
+            .chain(after.keys())
+            .collect::<BTreeSet<_>>();
+        let changes = paths
+            .into_iter()
+            .flat_map(|path| {
+                FileChange::diff(
+                    path,
+                    self.before.get(path).and_then(Option::as_ref),
+                    after.get(path).and_then(Option::as_ref),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if changes.is_empty() {
+            self.committed = true;
+            return Ok(());
+        }
+
+        append_entry(
+            save_dir,
+            &JournalEntry::mutation(command, description, changes.clone()),
+        )?;
+        self.committed = true;
+        record_events(save_dir, &changes, &self.before, &after)
+    }
+
+    /// Writes back the tracked files that changed since `begin`.
+    fn rollback(&self) -> Result<(), String> {
+        let save_dir = self.save_dir.as_ref();
+        let after = snapshot(save_dir)?;
+
+        for path in self.before.keys().chain(after.keys()) {
+            let before = self.before.get(path).and_then(Option::as_ref);
+            if before != after.get(path).and_then(Option::as_ref) {
+                write_tracked_file(save_dir, path, before)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        if !self.committed
+            && let Err(err) = self.rollback()
+        {
+            eprintln!("Could not restore the data after the failure: {}", err);
+        }
+    }
+}
+
+pub fn load_journal(save_dir: Option<&String>) -> Result<Journal, String> {
+    let mut file = JsonLinesFile::from_string(&[JOURNAL_FILE_NAME], save_dir)?;
+    Ok(Journal::from_entries(file.load()?))
+}
+
+pub fn append_entry(save_dir: Option<&String>, entry: &JournalEntry) -> Result<(), String> {
+    let mut file = JsonLinesFile::from_string(&[JOURNAL_FILE_NAME], save_dir)?;
+    file.save(entry)
+}
+
+/// Applies every change, removing files that end up empty, and records the
+/// matching events. Fails without touching anything if a value no longer
+/// matches the `before` side of its change.
+pub fn apply_changes(save_dir: Option<&String>, changes: &[FileChange]) -> Result<(), String> {
+    let mut before = Snapshot::new();
+    for change in changes {
+        if !before.contains_key(&change.path) {
+            before.insert(
+                change.path.clone(),
+                read_tracked_file(save_dir, &change.path)?,
+            );
+        }
+    }
+
+    let mut after = before.clone();
+    for change in changes {
+        change.apply(after.get_mut(&change.path).unwrap())?;
+    }
+
+    for (path, content) in &after {
+        write_tracked_file(save_dir, path, content.as_ref())?;
+    }
+
+    record_events(save_dir, changes, &before, &after)
+}
+
+/// Writes `content` to the tracked file at `path`, or removes the file when
+/// there is no content.
+fn write_tracked_file(
+    save_dir: Option<&String>,
+    path: &str,
+    content: Option<&Value>,
+) -> Result<(), String> {
+    let path_parts = path.split('/').collect::<Vec<_>>();
+    match content {
+        Some(content) => {
+            let mut file =
+                FileWrapper::from_string(&path_parts, save_dir, FileWrapperOptions::default())?;
+            file.save(content)
+        }
+        None => {
+            let path = create_path(&path_parts, save_dir)?;
+            if path.exists() {
+                fs::remove_file(&path)
+                    .map_err(|_| format!("Error removing file: {}", path.display()))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Appends the events turning the files changed by `changes` from their
+/// `before` to their `after` content to the event log, when the save dir
+/// uses the events storage mode.
+fn record_events(
+    save_dir: Option<&String>,
+    changes: &[FileChange],
+    before: &Snapshot,
+    after: &Snapshot,
+) -> Result<(), String> {
+    if data::load_config(save_dir)?.get_storage() != StorageMode::Events {
+        return Ok(());
+    }
+
+    let mut players_before = Players::create_empty();
+    let mut players_after = Players::create_empty();
+    let mut games_before = Games::create_empy();
+    let mut games_after = Games::create_empy();
+    let paths = changes
+        .iter()
+        .map(|change| &change.path)
+        .collect::<BTreeSet<_>>();
+    for path in paths {
+        let before = before.get(path).cloned().flatten();
+        let after = after.get(path).cloned().flatten();
+        if path == player::FILE_NAME_DATA {
+            players_before = from_content(&before, Players::create_empty)?;
+            players_after = from_content(&after, Players::create_empty)?;
+        } else if path.starts_with(&format!("{}/", GAMES_FOLER)) {
+            games_before.extend(&from_content(&before, Games::create_empy)?);
+            games_after.extend(&from_content(&after, Games::create_empy)?);
+        }
+    }
+
+    let events = event::diff(&players_before, &games_before, &players_after, &games_after)
+        .into_iter()
+        .map(Event::new)
+        .collect::<Vec<_>>();
+    data::append_events(save_dir, &events)
+}
+
+fn from_content<T: serde::de::DeserializeOwned>(
+    content: &Option<Value>,
+    empty: fn() -> T,
+) -> Result<T, String> {
+    match content {
+        Some(content) => serde_json::from_value(content.clone())
+            .map_err(|_| "Error deserializing journaled data.".to_string()),
+        None => Ok(empty()),
+    }
+}
+
+fn snapshot(save_dir: Option<&String>) -> Result<Snapshot, String> {
+    let mut snapshot = Snapshot::new();
+
+    for file_name in TRACKED_FILES {
+        snapshot.insert(
+            file_name.to_string(),
+            read_tracked_file(save_dir, file_name)?,
+        );
+    }
+
+    for folder in TRACKED_FOLDERS {
+        let folder_path = create_path(&[folder], save_dir)?;
+        if !folder_path.exists() {
+            continue;
+        }
+        for file_path in data::list_files(&folder_path)? {
+            if let Some(file_name) = file_path.file_name().and_then(|n| n.to_str()) {
+                let path = format!("{}/{}", folder, file_name);
+                let content = read_tracked_file(save_dir, &path)?;
+                snapshot.insert(path, content);
+            }
+        }
+    }
+
+    Ok(snapshot)
+}
+
+fn read_tracked_file(save_dir: Option<&String>, path: &str) -> Result<Option<Value>, String> {
+    let path_parts = path.split('/').collect::<Vec<_>>();
+    if !create_path(&path_parts, save_dir)?.exists() {
+        return Ok(None);
+    }
+
+    let mut file = FileWrapper::from_string(&path_parts, save_dir, FileWrapperOptions::default())?;
+    if file.is_empty()? {
+        Ok(None)
+    } else {
+        file.load().map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::models::player::{Player, Players};
+    use tempfile::tempdir;
+
+    #[test]
+    fn commit_records_changed_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let transaction = Transaction::begin(Some(&dir_path)).unwrap();
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        data::save_players(Some(&dir_path), &players).unwrap();
+        transaction
+            .commit("add-player", "Added player alice.".to_string())
+            .unwrap();
+
+        let journal = load_journal(Some(&dir_path)).unwrap();
+        let entry = journal.next_undo().unwrap();
+        assert_eq!(entry.get_description(), "Added player alice.");
+        assert_eq!(entry.get_changes().len(), 1);
+        assert_eq!(entry.get_changes()[0].path, "players.json");
+        assert_eq!(entry.get_changes()[0].before, None);
+    }
+
+    #[test]
+    fn commit_without_changes_is_not_recorded() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let transaction = Transaction::begin(Some(&dir_path)).unwrap();
+        transaction
+            .commit("list-games", "Nothing.".to_string())
+            .unwrap();
+
+        let journal = load_journal(Some(&dir_path)).unwrap();
+        assert!(journal.next_undo().is_none());
+    }
+
+    #[test]
+    fn commit_records_changed_values_only() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let mut players = Players::create_empty();
+        players
+            .add_player(Player::new("alice".to_string()))
+            .unwrap();
+        data::save_players(Some(&dir_path), &players).unwrap();
+
+        let transaction = Transaction::begin(Some(&dir_path)).unwrap();
+        players.add_player(Player::new("bob".to_string())).unwrap();
+        data::save_players(Some(&dir_path), &players).unwrap();
+        transaction
+            .commit("add-player", "Added player bob.".to_string())
+            .unwrap();
+
+        let journal = load_journal(Some(&dir_path)).unwrap();
+        let changes = journal.next_undo().unwrap().get_changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].pointer, "/players/bob");
+        assert_eq!(changes[0].before, None);
+
+        let undo = JournalEntry::undo(journal.next_undo().unwrap());
+        apply_changes(Some(&dir_path), undo.get_changes()).unwrap();
+        let players = data::load_players(Some(&dir_path)).unwrap();
+        assert!(players.exists(Player::new("alice".to_string())));
+        assert!(!players.exists(Player::new("bob".to_string())));
+    }
+
+    #[test]
+    fn dropped_transaction_restores_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let transaction = Transaction::begin(Some(&dir_path)).unwrap();
+        data::save_players(Some(&dir_path), &Players::create_empty()).unwrap();
+        drop(transaction);
+
+        assert!(!dir.path().join("players.json").exists());
+        let journal = load_journal(Some(&dir_path)).unwrap();
+        assert!(journal.next_undo().is_none());
+    }
+
+    #[test]
+    fn apply_changes_restores_and_removes_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let transaction = Transaction::begin(Some(&dir_path)).unwrap();
+        data::save_players(Some(&dir_path), &Players::create_empty()).unwrap();
+        transaction
+            .commit("add-player", "Created players.".to_string())
+            .unwrap();
+
+        let journal = load_journal(Some(&dir_path)).unwrap();
+        let undo = JournalEntry::undo(journal.next_undo().unwrap());
+        apply_changes(Some(&dir_path), undo.get_changes()).unwrap();
+        assert!(!dir.path().join("players.json").exists());
+    }
+
+    #[test]
+    fn apply_changes_detects_external_edits() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let change = FileChange {
+            path: "players.json".to_string(),
+            pointer: String::new(),
+            before: Some(serde_json::json!({"players": {}})),
+            after: None,
+        };
+        let result = apply_changes(Some(&dir_path), &[change]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "File players.json was modified outside of score-cli."
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::commands::models::head_to_head::HeadToHead;
+use crate::commands::utils::data;
+use crate::commands::utils::output::{self, OutputFormat};
+use crate::commands::{
+    FORMAT_OPTIONAL_ARGUMENT, GAME_OPTIONAL_ARGUMENT, LAST_OPTIONAL_ARGUMENT,
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+};
+use std::collections::HashMap;
+use tabled::Table;
+
+const ADMITTED_OPTIONAL_ARGUMENTS: [&str; 4] = [
+    SAVE_DIR_OPTIONAL_ARGUMENT,
+    GAME_OPTIONAL_ARGUMENT,
+    LAST_OPTIONAL_ARGUMENT,
+    FORMAT_OPTIONAL_ARGUMENT,
+];
+const DEFAULT_LAST_ENCOUNTERS: usize = 5;
+
+#[derive(Debug)]
+pub struct Versus {
+    player: String,
+    opponent: String,
+    last: usize,
+    format: OutputFormat,
+    optional_args: HashMap<String, String>,
+}
+
+impl Versus {
+    pub fn create(
+        args: &[String],
+        optional_args: &HashMap<String, String>,
+    ) -> Result<Versus, String> {
+        for key in optional_args.keys() {
+            if !ADMITTED_OPTIONAL_ARGUMENTS.contains(&key.as_str()) {
+                return Err(format!("Unknown optional command for versus {}.", key));
+            }
+        }
+
+        if args.len() != 2 {
+            return Err("Invalid number of arguments for versus.".to_string());
+        }
+        if args[0] == args[1] {
+            return Err("A player cannot be compared with themselves.".to_string());
+        }
+
+        let last = match optional_args.get(LAST_OPTIONAL_ARGUMENT) {
+            Some(last) => last
+                .parse()
+                .map_err(|_| format!("Invalid number of encounters {}.", last))?,
+            None => DEFAULT_LAST_ENCOUNTERS,
+        };
+
+        Ok(Versus {
+            player: args[0].clone(),
+            opponent: args[1].clone(),
+            last,
+            format: OutputFormat::parse(optional_args.get(FORMAT_OPTIONAL_ARGUMENT))?,
+            optional_args: optional_args.to_owned(),
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let data_file_path = self.optional_args.get(SAVE_DIR_OPTIONAL_ARGUMENT);
+
+        let games = match self.optional_args.get(GAME_OPTIONAL_ARGUMENT) {
+            Some(game_name) => {
+                let catalog = data::load_catalog(data_file_path)?;
+                let entry = catalog.resolve(game_name);
+                let game_name = entry.map_or(game_name.as_str(), |entry| entry.get_name());
+                data::load_games(data_file_path, game_name)?
+            }
+            None => data::load_all_games(data_file_path)?,
+        };
+        let head_to_head = HeadToHead::compute(
+            &games.finished().order_by_date(),
+            &self.player,
+            &self.opponent,
+            self.last,
+        );
+
+        match self.format {
+            OutputFormat::Json => output::print_json(&head_to_head)?,
+            OutputFormat::Table if head_to_head.get_encounters() == 0 => {
+                println!(
+                    "No matches found between {} and {}.",
+                    self.player, self.opponent
+                );
+            }
+            OutputFormat::Table => {
+                println!("{}", Table::new(head_to_head.to_player_rows()));
+                println!("Last encounters:");
+                println!("{}", Table::new(head_to_head.to_encounter_rows()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_valid_input() {
+        let args = vec!["alice".to_string(), "bob".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(LAST_OPTIONAL_ARGUMENT.to_string(), "3".to_string());
+        optional_args.insert(FORMAT_OPTIONAL_ARGUMENT.to_string(), "json".to_string());
+
+        let versus = Versus::create(&args, &optional_args).unwrap();
+        assert_eq!(versus.player, "alice");
+        assert_eq!(versus.opponent, "bob");
+        assert_eq!(versus.last, 3);
+        assert_eq!(versus.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_create_same_player() {
+        let args = vec!["alice".to_string(), "alice".to_string()];
+
+        let result = Versus::create(&args, &HashMap::new());
+        assert_eq!(
+            result.unwrap_err(),
+            "A player cannot be compared with themselves."
+        );
+    }
+
+    #[test]
+    fn test_create_invalid_last() {
+        let args = vec!["alice".to_string(), "bob".to_string()];
+        let mut optional_args = HashMap::new();
+        optional_args.insert(LAST_OPTIONAL_ARGUMENT.to_string(), "many".to_string());
+
+        let result = Versus::create(&args, &optional_args);
+        assert_eq!(result.unwrap_err(), "Invalid number of encounters many.");
+    }
+}
@@ -9,7 +9,7 @@ fn test_cli_add_player_creates_file_with_player() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name", "--save-dir", temp_path])
         .assert()
         .success();
 
@@ -24,12 +24,12 @@ fn test_cli_add_player_file_already_exists_with_player() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
@@ -44,12 +44,12 @@ fn test_cli_add_player_already_exists() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name", "--save-dir", temp_path])
         .assert()
         .failure()
         .stderr(contains("Player player-name already exists."));
@@ -59,10 +59,27 @@ fn test_cli_add_player_already_exists() {
     assert!(content.contains("player-name"));
 }
 
+#[test]
+fn test_cli_add_player_rejects_names_differing_in_case() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "gio", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "GIO", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("GIO is already used by player gio."));
+}
+
 #[test]
 fn test_cli_add_player_fails_on_missing_name() {
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player"])
+    cmd.args(["add-player"])
         .assert()
         .failure()
         .stderr(contains("Probblem parsing command:").or(contains("Problem executing command")));
@@ -9,17 +9,17 @@ fn test_cli_add_score_creates_file_with_scores() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&[
+    cmd.args([
         "add-score",
         "catan",
         "player-name1::10",
@@ -42,17 +42,17 @@ fn test_cli_add_score_creates_file_with_scores_and_time() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&[
+    cmd.args([
         "add-score",
         "catan",
         "player-name1::10",
@@ -78,7 +78,7 @@ fn test_cli_add_score_no_players_data() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&[
+    cmd.args([
         "add-score",
         "catan",
         "player-name::10",
@@ -96,12 +96,12 @@ fn test_cli_add_score_missing_player() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&[
+    cmd.args([
         "add-score",
         "catan",
         "player-name1::10",
@@ -115,3 +115,187 @@ fn test_cli_add_score_missing_player() {
     .failure()
     .stderr(contains("Player player-name1 does not exist."));
 }
+
+#[test]
+fn test_cli_add_score_with_unsafe_game_name() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args([
+        "add-score",
+        "../Ticket to Ride: Europe",
+        "player-name1::10",
+        "--save-dir",
+        temp_path,
+    ])
+    .assert()
+    .success();
+
+    assert!(!temp.path().join("Ticket to Ride: Europe.json").exists());
+    let score_file_path = temp.path().join("games").join("Ticket-to-Ride-Europe.json");
+    let content = fs::read_to_string(score_file_path).unwrap();
+    assert!(content.contains("../Ticket to Ride: Europe"));
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("../Ticket to Ride: Europe"));
+}
+
+#[test]
+fn test_cli_add_score_migrates_legacy_game_files() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let games_dir = temp.path().join("games");
+    fs::create_dir_all(&games_dir).unwrap();
+    fs::write(
+        games_dir.join("Ticket to Ride.json"),
+        r#"{"games":{"849cf74e-0e19-45ce-a630-99916cb3b648":{"id":"849cf74e-0e19-45ce-a630-99916cb3b648","game_name":"Ticket to Ride","scores":{"player-name1":100},"time":"2025-08-07"}}}"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args([
+        "add-score",
+        "Ticket to Ride",
+        "player-name1::80",
+        "--save-dir",
+        temp_path,
+    ])
+    .assert()
+    .success();
+
+    assert!(!games_dir.join("Ticket to Ride.json").exists());
+    let content = fs::read_to_string(games_dir.join("Ticket-to-Ride.json")).unwrap();
+    assert!(content.contains("849cf74e-0e19-45ce-a630-99916cb3b648"));
+    assert!(content.contains("80"));
+}
+
+#[test]
+fn test_cli_add_score_auto_add_players() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args([
+        "add-score",
+        "catan",
+        "player-name1::10",
+        "player-name2::20",
+        "--auto-add-players",
+        "--save-dir",
+        temp_path,
+    ])
+    .assert()
+    .success()
+    .stdout(contains("Recorded guest:player-name1 as a guest."));
+
+    let players = fs::read_to_string(temp.path().join("players.json")).unwrap();
+    assert!(players.contains("guest:player-name1"));
+    let content = fs::read_to_string(temp.path().join("games").join("catan.json")).unwrap();
+    assert!(content.contains("guest:player-name1"));
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let players = fs::read_to_string(temp.path().join("players.json")).unwrap();
+    assert!(!players.contains("player-name1"));
+    let content =
+        fs::read_to_string(temp.path().join("games").join("catan.json")).unwrap_or_default();
+    assert!(!content.contains("player-name1"));
+}
+
+#[test]
+fn test_cli_add_score_to_unknown_session_writes_nothing() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let players = fs::read_to_string(temp.path().join("players.json")).unwrap();
+    let journal = fs::read_to_string(temp.path().join("journal.jsonl")).unwrap();
+
+    let mut cmd = Command::cargo_bin("score-cli").unwrap();
+    cmd.args([
+        "add-score",
+        "catan",
+        "player-name1::10",
+        "player-name2::20",
+        "--session",
+        "missing",
+        "--auto-add-players",
+        "--save-dir",
+        temp_path,
+    ])
+    .assert()
+    .failure()
+    .stderr(contains("Session missing not found."));
+
+    assert!(!temp.path().join("games").exists());
+    assert_eq!(
+        fs::read_to_string(temp.path().join("players.json")).unwrap(),
+        players
+    );
+    assert_eq!(
+        fs::read_to_string(temp.path().join("journal.jsonl")).unwrap(),
+        journal
+    );
+}
+
+#[test]
+fn test_cli_reads_leave_legacy_game_files_alone() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let games_dir = temp.path().join("games");
+    fs::create_dir_all(&games_dir).unwrap();
+    fs::write(
+        games_dir.join("Ticket to Ride.json"),
+        r#"{"games":{"849cf74e-0e19-45ce-a630-99916cb3b648":{"id":"849cf74e-0e19-45ce-a630-99916cb3b648","game_name":"Ticket to Ride","scores":{"player-name1":100,"player-name2":90},"time":"2025-08-07"}}}"#,
+    )
+    .unwrap();
+
+    for args in [
+        vec!["list-games"],
+        vec!["leaderboard", "Ticket to Ride"],
+        vec!["stats", "--game", "Ticket to Ride"],
+        vec!["stats"],
+    ] {
+        let mut cmd = Command::cargo_bin("score-cli").unwrap();
+        cmd.args(&args)
+            .args(["--save-dir", temp_path])
+            .assert()
+            .success()
+            .stdout(contains("player-name1"));
+    }
+
+    assert!(games_dir.join("Ticket to Ride.json").exists());
+    let entries = fs::read_dir(temp.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(entries, vec!["games"]);
+}
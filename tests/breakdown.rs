@@ -0,0 +1,112 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "game",
+            "add",
+            "7 Wonders",
+            "--categories",
+            "military,science,coins",
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_breakdown_shows_best_category() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "7 wonders",
+            "alice::45::military=25,science=10,coins=10",
+            "bob::50::military=5,science=40,coins=5",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("alice 45 (coins 10, military 25, science 10)"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["breakdown", "7 Wonders", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("military 25.0"))
+        .stdout(contains("+67%"))
+        .stdout(contains("all players"));
+}
+
+#[test]
+fn test_add_score_rejects_invalid_breakdown() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "7 Wonders",
+            "alice::45::military=25,science=10",
+            "bob::50",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("The categories of alice add up to 35, not 45."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "7 Wonders",
+            "alice::45::military=25,magic=20",
+            "bob::50",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Unknown category magic for 7 Wonders. Use one of military, science, coins.",
+        ));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "Wingspan",
+            "alice::45::birds=45",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Game Wingspan not found in the catalog."));
+}
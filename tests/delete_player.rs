@@ -10,13 +10,13 @@ fn test_cli_delete_player_removes_player_from_file() {
 
     let mut add_cmd = Command::cargo_bin("score-cli").unwrap();
     add_cmd
-        .args(&["add-player", "player-name", "--save-dir", temp_path])
+        .args(["add-player", "player-name", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut del_cmd = Command::cargo_bin("score-cli").unwrap();
     del_cmd
-        .args(&["delete-player", "player-name", "--save-dir", temp_path])
+        .args(["delete-player", "player-name", "--save-dir", temp_path])
         .assert()
         .success();
 
@@ -32,13 +32,13 @@ fn test_cli_delete_player_fails_player_not_present() {
 
     let mut add_cmd = Command::cargo_bin("score-cli").unwrap();
     add_cmd
-        .args(&["add-player", "player-name", "--save-dir", temp_path])
+        .args(["add-player", "player-name", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut del_cmd = Command::cargo_bin("score-cli").unwrap();
     del_cmd
-        .args(&[
+        .args([
             "delete-player",
             "different-player-name",
             "--save-dir",
@@ -56,7 +56,7 @@ fn test_cli_delete_player_fails_no_data() {
 
     let mut del_cmd = Command::cargo_bin("score-cli").unwrap();
     del_cmd
-        .args(&["delete-player", "player-name", "--save-dir", temp_path])
+        .args(["delete-player", "player-name", "--save-dir", temp_path])
         .assert()
         .failure()
         .stderr(contains("No players' data found."));
@@ -9,18 +9,18 @@ fn test_add_and_delete_score() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
     let assert = cmd
-        .args(&[
+        .args([
             "add-score",
             "catan",
             "player-name1::10",
@@ -41,7 +41,7 @@ fn test_add_and_delete_score() {
 
     let mut delete_cmd = Command::cargo_bin("score-cli").unwrap();
     delete_cmd
-        .args(&["delete-score", uuid, "--save-dir", temp_path])
+        .args(["delete-score", uuid, "--save-dir", temp_path])
         .assert()
         .success()
         .stdout(predicate::str::contains("Removed game with id"));
@@ -53,18 +53,18 @@ fn test_delete_score_id_not_present() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
     let assert = cmd
-        .args(&[
+        .args([
             "add-score",
             "catan",
             "player-name1::10",
@@ -85,7 +85,7 @@ fn test_delete_score_id_not_present() {
 
     let mut delete_cmd = Command::cargo_bin("score-cli").unwrap();
     delete_cmd
-        .args(&[
+        .args([
             "delete-score",
             "3990e3b4-123d-4d7f-9461-335269897805",
             "--save-dir",
@@ -104,18 +104,18 @@ fn test_delete_score_id_not_well_formatted() {
     let temp_path = temp.path().to_str().unwrap();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name1", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name1", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
-    cmd.args(&["add-player", "player-name2", "--save-dir", temp_path])
+    cmd.args(["add-player", "player-name2", "--save-dir", temp_path])
         .assert()
         .success();
 
     let mut cmd = Command::cargo_bin("score-cli").unwrap();
     let assert = cmd
-        .args(&[
+        .args([
             "add-score",
             "catan",
             "player-name1::10",
@@ -136,7 +136,7 @@ fn test_delete_score_id_not_well_formatted() {
 
     let mut delete_cmd = Command::cargo_bin("score-cli").unwrap();
     delete_cmd
-        .args(&["delete-score", "id-not-uuid", "--save-dir", temp_path])
+        .args(["delete-score", "id-not-uuid", "--save-dir", temp_path])
         .assert()
         .failure()
         .stderr(contains("Impossible to decode id id-not-uuid."));
@@ -0,0 +1,199 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::tempdir;
+
+fn run(save_dir: &str, args: &[&str]) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(args)
+        .args(["--save-dir", save_dir])
+        .assert()
+        .success();
+}
+
+fn add_player(save_dir: &str, player_name: &str) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["add-player", player_name, "--save-dir", save_dir])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_export_and_import_bundle_into_empty_dir() {
+    let source = tempdir().unwrap();
+    let source_path = source.path().to_str().unwrap();
+    let target = tempdir().unwrap();
+    let target_path = target.path().to_str().unwrap();
+    let bundle_path = source.path().join("bundle.json");
+
+    add_player(source_path, "player1");
+    add_player(source_path, "player2");
+    run(source_path, &["game", "add", "catan", "--max-players", "4"]);
+    run(
+        source_path,
+        &[
+            "season",
+            "create",
+            "q3",
+            "--start",
+            "2025-07-01",
+            "--end",
+            "2025-09-30",
+        ],
+    );
+    run(
+        source_path,
+        &[
+            "session",
+            "start",
+            "friday",
+            "player1",
+            "player2",
+            "--time",
+            "2025-08-07",
+        ],
+    );
+    run(
+        source_path,
+        &[
+            "tournament",
+            "create",
+            "cup",
+            "round-robin",
+            "player1",
+            "player2",
+            "--game",
+            "catan",
+        ],
+    );
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "player2::20",
+            "--time",
+            "2025-08-07",
+            "--save-dir",
+            source_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "export",
+            "--output",
+            bundle_path.to_str().unwrap(),
+            "--save-dir",
+            source_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Exported 2 players and 1 games"));
+
+    let bundle = fs::read_to_string(&bundle_path).unwrap();
+    assert!(bundle.contains("\"format_version\": 2"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import-bundle",
+            bundle_path.to_str().unwrap(),
+            "--save-dir",
+            target_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 2 players, 1 games and 4 other records"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", target_path])
+        .assert()
+        .success()
+        .stdout(contains("catan"))
+        .stdout(contains("2025-08-07"));
+    for file_name in [
+        "catalog.json",
+        "seasons.json",
+        "sessions.json",
+        "tournaments.json",
+        "game_index.json",
+    ] {
+        assert_eq!(
+            fs::read_to_string(target.path().join(file_name)).unwrap(),
+            fs::read_to_string(source.path().join(file_name)).unwrap(),
+            "{}",
+            file_name
+        );
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import-bundle",
+            bundle_path.to_str().unwrap(),
+            "--save-dir",
+            target_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 0 players, 0 games and 0 other records"));
+}
+
+#[test]
+fn test_import_bundle_reports_duplicate_players() {
+    let source = tempdir().unwrap();
+    let source_path = source.path().to_str().unwrap();
+    let target = tempdir().unwrap();
+    let target_path = target.path().to_str().unwrap();
+    let bundle_path = source.path().join("bundle.json");
+
+    add_player(source_path, "player1");
+    add_player(target_path, "player1");
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "export",
+            "--output",
+            bundle_path.to_str().unwrap(),
+            "--save-dir",
+            source_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import-bundle",
+            bundle_path.to_str().unwrap(),
+            "--save-dir",
+            target_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 0 players, 0 games and 0 other records"))
+        .stdout(contains(
+            "Player player1 already exists, kept the existing one.",
+        ));
+}
+
+#[test]
+fn test_export_missing_output() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["export", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Missing output file for export."));
+}
@@ -0,0 +1,139 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "game",
+            "add",
+            "Catan",
+            "--min-players",
+            "3",
+            "--max-players",
+            "4",
+            "--duration",
+            "90",
+            "--category",
+            "strategy",
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Added game Catan to the catalog."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "alias", "catan", "Settlers", "--save-dir", save_dir])
+        .assert()
+        .success()
+        .stdout(contains("Added alias Settlers to game catan."));
+}
+
+#[test]
+fn test_add_score_resolves_alias() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "settlers",
+            "alice::10",
+            "bob::8",
+            "carol::5",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Added game of Catan with id"));
+
+    assert!(temp.path().join("games").join("Catan.json").exists());
+    assert!(!temp.path().join("games").join("settlers.json").exists());
+}
+
+#[test]
+fn test_add_score_validates_player_count() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "CATAN",
+            "alice::10",
+            "bob::8",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Catan needs at least 3 players, got 2."));
+}
+
+#[test]
+fn test_game_rename_and_show() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "game",
+            "rename",
+            "Catan",
+            "Catan Classic",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Renamed game Catan to Catan Classic."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "show", "settlers", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Catan Classic"))
+        .stdout(contains("3-4"))
+        .stdout(contains("90 min"))
+        .stdout(contains("strategy"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "list", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Settlers, Catan"));
+}
+
+#[test]
+fn test_game_alias_already_used() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "add", "settlers", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("settlers is already used by game Catan."));
+}
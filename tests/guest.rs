@@ -0,0 +1,247 @@
+use assert_cmd::Command;
+use predicates::{prelude::PredicateBooleanExt, str::contains};
+use tempfile::tempdir;
+
+fn run(save_dir: &str, args: &[&str]) -> String {
+    let output = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(args)
+        .args(["--save-dir", save_dir])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob"] {
+        run(save_dir, &["add-player", player]);
+    }
+    run(
+        save_dir,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "guest:Marta::12",
+            "--time",
+            "2025-01-01",
+        ],
+    );
+    run(
+        save_dir,
+        &[
+            "add-score",
+            "catan",
+            "bob::8",
+            "guest:Marta::9",
+            "--time",
+            "2025-01-02",
+        ],
+    );
+}
+
+#[test]
+fn test_guests_are_left_out_of_rankings() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    // Guests still hold the records of the matches they played.
+    let output = run(temp_path, &["leaderboard", "catan"]);
+    let (standings, records) = output.split_once("Records:").unwrap();
+    assert!(standings.contains("alice"));
+    assert!(!standings.contains("guest:Marta"));
+    assert!(records.contains("guest:Marta"));
+
+    let output = run(temp_path, &["leaderboard", "catan", "--include-guests"]);
+    let (standings, _) = output.split_once("Records:").unwrap();
+    assert!(standings.contains("guest:Marta"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["ratings", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("guest:Marta").not());
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["ratings", "--include-guests", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("guest:Marta"));
+}
+
+#[test]
+fn test_promote_guest_keeps_history() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let output = run(temp_path, &["promote-guest", "Marta", "marta92"]);
+    assert!(output.contains("Promoted guest:Marta to player marta92 (2 matches)."));
+
+    let output = run(temp_path, &["show-player", "marta92"]);
+    assert!(output.contains("2 matches, 2 wins."));
+    assert!(output.contains("2025-01-01"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("guest:Marta").not());
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["promote-guest", "Marta", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("No matches found for guest:Marta."));
+}
+
+#[test]
+fn test_guests_are_left_out_of_statistics() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    run(
+        temp_path,
+        &[
+            "session",
+            "start",
+            "friday",
+            "alice",
+            "--time",
+            "2025-01-03",
+        ],
+    );
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::5",
+            "guest:Marta::9",
+            "--time",
+            "2025-01-03",
+            "--session",
+            "friday",
+        ],
+    );
+    run(
+        temp_path,
+        &[
+            "season",
+            "create",
+            "winter",
+            "--start",
+            "2025-01-01",
+            "--end",
+            "2025-01-31",
+        ],
+    );
+
+    for args in [
+        vec!["stats"],
+        vec!["season", "standings", "winter"],
+        vec!["session", "report", "friday", "--format", "json"],
+    ] {
+        let output = run(temp_path, &args);
+        assert!(output.contains("alice"));
+        let with_guests = run(
+            temp_path,
+            &[args.as_slice(), &["--include-guests"]].concat(),
+        );
+        assert!(with_guests.contains("guest:Marta"));
+        if args[0] != "session" {
+            assert!(!output.contains("guest:Marta"));
+        }
+    }
+
+    // The guest still attended the session and played its match.
+    let output = run(
+        temp_path,
+        &["session", "report", "friday", "--format", "json"],
+    );
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(report["winners"], serde_json::json!(["alice"]));
+    assert!(
+        report["standings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|standing| standing["player"] != "guest:Marta")
+    );
+}
+
+#[test]
+fn test_promote_guest_writes_nothing_on_conflict() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    run(temp_path, &["add-player", "marta92"]);
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "marta92::4",
+            "guest:Marta::6",
+            "--time",
+            "2025-01-03",
+        ],
+    );
+    run(temp_path, &["delete-player", "marta92"]);
+    let players = std::fs::read_to_string(temp.path().join("players.json")).unwrap();
+    let journal = std::fs::read_to_string(temp.path().join("journal.jsonl")).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["promote-guest", "Marta", "marta92", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Player marta92 is listed more than once."));
+
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("players.json")).unwrap(),
+        players
+    );
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("journal.jsonl")).unwrap(),
+        journal
+    );
+}
+
+#[test]
+fn test_promote_guest_replaces_registered_guest() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    run(temp_path, &["add-player", "alice"]);
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "Nina::7",
+            "--auto-add-players",
+        ],
+    );
+    let players_path = temp.path().join("players.json");
+    assert!(
+        std::fs::read_to_string(&players_path)
+            .unwrap()
+            .contains("guest:Nina")
+    );
+
+    let output = run(temp_path, &["promote-guest", "Nina"]);
+    assert!(output.contains("Promoted guest:Nina to player Nina (1 matches)."));
+
+    let players = std::fs::read_to_string(&players_path).unwrap();
+    assert!(!players.contains("guest:Nina"));
+    assert!(players.contains("\"Nina\""));
+}
@@ -0,0 +1,231 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::tempdir;
+
+const BG_STATS_BACKUP: &str = r#"{
+    "players": [{"id": 1, "name": "Giovanni"}, {"id": 2, "name": "Emma"}],
+    "games": [{"id": 7, "name": "catan"}],
+    "plays": [
+        {
+            "gameRefId": 7,
+            "playDate": "2025-08-07 21:30:00",
+            "playerScores": [
+                {"playerRefId": 1, "score": "10", "winner": true},
+                {"playerRefId": 2, "score": "8", "winner": false}
+            ]
+        }
+    ]
+}"#;
+
+const BGG_PLAYS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<plays username="giovi98" userid="1" total="1" page="1">
+    <play id="101" date="2025-08-08" quantity="1" length="45">
+        <item name="azul" objecttype="thing" objectid="230802"/>
+        <players>
+            <player username="giovi98" name="Giovanni" score="60" win="1"/>
+            <player username="" name="Emma" score="42" win="0"/>
+        </players>
+    </play>
+</plays>"#;
+
+#[test]
+fn test_import_bg_stats_with_player_map() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let backup_path = temp.path().join("backup.json");
+    fs::write(&backup_path, BG_STATS_BACKUP).unwrap();
+    let map_path = temp.path().join("map.json");
+    fs::write(&map_path, r#"{"Giovanni": "giovi98"}"#).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import",
+            "bgstats",
+            backup_path.to_str().unwrap(),
+            "--player-map",
+            map_path.to_str().unwrap(),
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 1 matches and 2 new players"));
+
+    let players = fs::read_to_string(temp.path().join("players.json")).unwrap();
+    assert!(players.contains("giovi98"));
+    assert!(!players.contains("Giovanni"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("catan"))
+        .stdout(contains("2025-08-07"))
+        .stdout(contains("giovi98"));
+}
+
+#[test]
+fn test_import_bgg_twice_skips_recorded_matches() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let plays_path = temp.path().join("plays.xml");
+    fs::write(&plays_path, BGG_PLAYS).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import",
+            "bgg",
+            plays_path.to_str().unwrap(),
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 1 matches and 2 new players"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import",
+            "bgg",
+            plays_path.to_str().unwrap(),
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 0 matches and 0 new players"))
+        .stdout(contains("Skipped 1 matches already recorded."));
+
+    let games = fs::read_to_string(temp.path().join("games").join("azul.json")).unwrap();
+    assert!(games.contains("\"winners\""));
+}
+
+#[test]
+fn test_import_bgg_play_quantity() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let plays_path = temp.path().join("plays.xml");
+    fs::write(
+        &plays_path,
+        BGG_PLAYS.replace("quantity=\"1\"", "quantity=\"3\""),
+    )
+    .unwrap();
+    let import = || {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args([
+                "import",
+                "bgg",
+                plays_path.to_str().unwrap(),
+                "--save-dir",
+                temp_path,
+            ])
+            .assert()
+            .success()
+    };
+
+    import().stdout(contains("Imported 3 matches and 2 new players"));
+    import()
+        .stdout(contains("Imported 0 matches"))
+        .stdout(contains("Skipped 1 matches already recorded."));
+
+    let games = fs::read_to_string(temp.path().join("games").join("azul.json")).unwrap();
+    assert_eq!(games.matches("\"game_name\"").count(), 3);
+}
+
+#[test]
+fn test_import_keeps_identical_plays_of_one_file() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    let play_start = BGG_PLAYS.find("    <play ").unwrap();
+    let play_end = BGG_PLAYS.find("</plays>").unwrap();
+    let play = &BGG_PLAYS[play_start..play_end];
+    let plays_path = temp.path().join("plays.xml");
+    fs::write(
+        &plays_path,
+        BGG_PLAYS.replace(play, &format!("{}{}", play, play.replace("101", "102"))),
+    )
+    .unwrap();
+    let import = || {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args([
+                "import",
+                "bgg",
+                plays_path.to_str().unwrap(),
+                "--save-dir",
+                temp_path,
+            ])
+            .assert()
+            .success()
+    };
+
+    import().stdout(contains("Imported 2 matches and 2 new players"));
+    import()
+        .stdout(contains("Imported 0 matches"))
+        .stdout(contains("Skipped 2 matches already recorded."));
+
+    let games = fs::read_to_string(temp.path().join("games").join("azul.json")).unwrap();
+    assert_eq!(games.matches("\"game_name\"").count(), 2);
+}
+
+#[test]
+fn test_import_records_known_players_under_their_name() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["add-player", "emma", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let plays_path = temp.path().join("plays.xml");
+    fs::write(&plays_path, BGG_PLAYS).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import",
+            "bgg",
+            plays_path.to_str().unwrap(),
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Imported 1 matches and 1 new players"));
+
+    let players = fs::read_to_string(temp.path().join("players.json")).unwrap();
+    assert!(!players.contains("Emma"));
+}
+
+#[test]
+fn test_import_missing_file() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "import",
+            "bgg",
+            "does-not-exist.xml",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "An error occurred reading file: does-not-exist.xml",
+        ));
+}
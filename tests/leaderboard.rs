@@ -0,0 +1,124 @@
+use assert_cmd::Command;
+use predicates::{prelude::*, str::contains};
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (scores, time) in [
+        (vec!["alice::10", "bob::8", "carol::2"], "2025-01-01"),
+        (vec!["alice::6", "bob::9"], "2025-01-02"),
+        (vec!["alice::12", "bob::3"], "2025-01-03"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", "catan"])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn test_leaderboard_table() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["leaderboard", "catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("66.7%"))
+        .stdout(contains("carol"))
+        .stdout(contains("Records:"))
+        .stdout(contains("lowest winning score"))
+        .stdout(contains("2025-01-03"));
+}
+
+#[test]
+fn test_leaderboard_json_with_min_plays() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "leaderboard",
+            "catan",
+            "--min-plays",
+            "2",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("carol").not());
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["matches"], 3);
+    assert_eq!(json["standings"][0]["player"], "alice");
+    assert_eq!(json["records"]["highest_score"]["value"], 12);
+    assert_eq!(json["records"]["largest_margin"]["value"], 9);
+}
+
+#[test]
+fn test_leaderboard_game_alias() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    for args in [
+        vec!["game", "add", "catan"],
+        vec!["game", "alias", "catan", "Settlers"],
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(args)
+            .args(["--save-dir", temp_path])
+            .assert()
+            .success();
+    }
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "leaderboard",
+            "Settlers",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["game_name"], "catan");
+    assert_eq!(json["matches"], 3);
+}
+
+#[test]
+fn test_leaderboard_unknown_game() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["leaderboard", "azul", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("No matches found for azul."));
+}
@@ -9,19 +9,19 @@ fn test_list_games_shows_added_game() {
 
     Command::cargo_bin("score-cli")
         .unwrap()
-        .args(&["add-player", "player1", "--save-dir", temp_path])
+        .args(["add-player", "player1", "--save-dir", temp_path])
         .assert()
         .success();
 
     Command::cargo_bin("score-cli")
         .unwrap()
-        .args(&["add-player", "player2", "--save-dir", temp_path])
+        .args(["add-player", "player2", "--save-dir", temp_path])
         .assert()
         .success();
 
     Command::cargo_bin("score-cli")
         .unwrap()
-        .args(&[
+        .args([
             "add-score",
             "catan",
             "player1::10",
@@ -37,7 +37,7 @@ fn test_list_games_shows_added_game() {
 
     Command::cargo_bin("score-cli")
         .unwrap()
-        .args(&["list-games", "--save-dir", temp_path])
+        .args(["list-games", "--save-dir", temp_path])
         .assert()
         .success()
         .stdout(contains("catan"))
@@ -47,3 +47,75 @@ fn test_list_games_shows_added_game() {
         .stdout(contains("player2"))
         .stdout(contains("20"));
 }
+
+#[test]
+fn test_list_games_shows_match_details() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    for player in ["player1", "player2"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", temp_path])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "player2::20",
+            "--start",
+            "2025-08-07T20:30+02:00",
+            "--duration",
+            "3h",
+            "--location",
+            "Marco's place",
+            "--tags",
+            "evening,rematch",
+            "--notes",
+            "Longest road decided it",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("2025-08-07"))
+        .stdout(contains("started 20:30 +02:00"))
+        .stdout(contains("took 3h"))
+        .stdout(contains("at Marco's place"))
+        .stdout(contains("#evening #rematch"))
+        .stdout(contains("Longest road decided it"));
+}
+
+#[test]
+fn test_add_score_rejects_start_on_other_day() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "--time",
+            "2025-08-08",
+            "--start",
+            "2025-08-07T20:30+02:00",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("cannot start on 2025-08-07"));
+}
@@ -0,0 +1,206 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn record_catan_night(save_dir: &str) {
+    for player in ["player1", "player2"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "player2::20",
+            "--time",
+            "2025-08-07",
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success();
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to.join("games")).unwrap();
+    fs::copy(from.join("players.json"), to.join("players.json")).unwrap();
+    fs::copy(
+        from.join("games").join("catan.json"),
+        to.join("games").join("catan.json"),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_merge_skips_match_recorded_twice() {
+    let ours = tempdir().unwrap();
+    let ours_path = ours.path().to_str().unwrap();
+    let theirs = tempdir().unwrap();
+    let theirs_path = theirs.path().to_str().unwrap();
+
+    record_catan_night(ours_path);
+    record_catan_night(theirs_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["merge", theirs_path, "--save-dir", ours_path])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Merged 0 new players, 0 new games and 0 other records",
+        ))
+        .stdout(contains(
+            "Skipped 1 matches recorded on both sides with different ids.",
+        ));
+}
+
+#[test]
+fn test_merge_reports_conflicting_edits() {
+    let ours = tempdir().unwrap();
+    let ours_path = ours.path().to_str().unwrap();
+    let theirs = tempdir().unwrap();
+    let theirs_path = theirs.path().to_str().unwrap();
+
+    record_catan_night(ours_path);
+    copy_dir(ours.path(), theirs.path());
+    let their_games = theirs.path().join("games").join("catan.json");
+    let edited = fs::read_to_string(&their_games)
+        .unwrap()
+        .replace("\"player1\": 10", "\"player1\": 12");
+    fs::write(&their_games, edited).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["merge", theirs_path, "--save-dir", ours_path])
+        .assert()
+        .success()
+        .stdout(contains("1 conflicting games kept unchanged"));
+
+    let conflicts = fs::read_to_string(ours.path().join("merge-conflicts.json")).unwrap();
+    assert!(conflicts.contains("\"ours\""));
+    assert!(conflicts.contains("\"theirs\""));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "merge",
+            theirs_path,
+            "--policy",
+            "theirs",
+            "--save-dir",
+            ours_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Resolved 1 conflicting games by policy."));
+
+    let our_games = fs::read_to_string(ours.path().join("games").join("catan.json")).unwrap();
+    assert!(our_games.contains("\"player1\": 12"));
+    assert!(!ours.path().join("merge-conflicts.json").exists());
+}
+
+#[test]
+fn test_merge_resolves_their_players_and_records() {
+    let ours = tempdir().unwrap();
+    let ours_path = ours.path().to_str().unwrap();
+    let theirs = tempdir().unwrap();
+    let theirs_path = theirs.path().to_str().unwrap();
+
+    record_catan_night(ours_path);
+    for player in ["Player1", "player3"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", theirs_path])
+            .assert()
+            .success();
+    }
+    for args in [
+        vec!["add-score", "azul", "Player1::30", "player3::25"],
+        vec!["game", "add", "azul", "--max-players", "4"],
+        vec!["session", "start", "friday", "Player1", "player3"],
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(args)
+            .args(["--save-dir", theirs_path])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["merge", theirs_path, "--save-dir", ours_path])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Merged 1 new players, 1 new games and 2 other records",
+        ));
+
+    let players = fs::read_to_string(ours.path().join("players.json")).unwrap();
+    assert!(!players.contains("Player1"));
+    let games = fs::read_to_string(ours.path().join("games").join("azul.json")).unwrap();
+    assert!(games.contains("\"player1\": 30"));
+    assert!(!games.contains("Player1"));
+    assert!(ours.path().join("catalog.json").exists());
+    let sessions = fs::read_to_string(ours.path().join("sessions.json")).unwrap();
+    assert!(sessions.contains("\"player1\""));
+    assert!(!sessions.contains("Player1"));
+}
+
+#[test]
+fn test_merge_theirs_moves_renamed_games() {
+    let ours = tempdir().unwrap();
+    let ours_path = ours.path().to_str().unwrap();
+    let theirs = tempdir().unwrap();
+    let theirs_path = theirs.path().to_str().unwrap();
+
+    record_catan_night(ours_path);
+    copy_dir(ours.path(), theirs.path());
+    let their_games = theirs.path().join("games").join("catan.json");
+    let renamed = fs::read_to_string(&their_games)
+        .unwrap()
+        .replace("\"game_name\": \"catan\"", "\"game_name\": \"Catan\"");
+    fs::write(&their_games, renamed).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "merge",
+            theirs_path,
+            "--policy",
+            "theirs",
+            "--save-dir",
+            ours_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Resolved 1 conflicting games by policy."));
+
+    let games = fs::read_dir(ours.path().join("games"))
+        .unwrap()
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect::<String>();
+    assert_eq!(games.matches("\"game_name\"").count(), 1);
+    assert!(games.contains("\"game_name\": \"Catan\""));
+}
+
+#[test]
+fn test_merge_missing_directory() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["merge", "does/not/exist", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Directory does/not/exist not found."));
+}
@@ -0,0 +1,174 @@
+use assert_cmd::Command;
+use predicates::{prelude::PredicateBooleanExt, str::contains};
+use tempfile::tempdir;
+
+fn add_player(save_dir: &str, player_name: &str) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["add-player", player_name, "--save-dir", save_dir])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_player_edit_and_show() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    add_player(temp_path, "giovi98");
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "player",
+            "edit",
+            "giovi98",
+            "--display-name",
+            "Giovanni",
+            "--nickname",
+            "Gio",
+            "--favorite-games",
+            "Catan,Azul",
+            "--color",
+            "green",
+            "--emoji",
+            "🎲",
+            "--joined",
+            "2024-03-01",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Updated the profile of giovi98."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["player", "show", "giovi98", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("🎲 Giovanni"))
+        .stdout(contains("Gio"))
+        .stdout(contains("Catan, Azul"))
+        .stdout(contains("2024-03-01"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "player",
+            "edit",
+            "giovi98",
+            "--color",
+            "purple",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Invalid color purple."));
+}
+
+#[test]
+fn test_list_players() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    add_player(temp_path, "giovi98");
+    add_player(temp_path, "emma00");
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "player",
+            "edit",
+            "emma00",
+            "--active",
+            "false",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-players", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("giovi98"))
+        .stdout(contains("emma00"))
+        .stdout(contains("no"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-players", "--format", "json", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("\"active\": false"))
+        .stdout(contains("\"joined\""));
+}
+
+#[test]
+fn test_add_score_resolves_aliases() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    add_player(temp_path, "giovi98");
+    add_player(temp_path, "emma00");
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["player", "alias", "giovi98", "gio", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Added alias gio to player giovi98."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "GIO::10",
+            "Emma00::8",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("giovi98"))
+        .stdout(contains("emma00"))
+        .stdout(contains("GIO").not());
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "giovi::10",
+            "emma00::8",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Player giovi does not exist. Did you mean giovi98?",
+        ));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "gio::10",
+            "giovi98::8",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Player giovi98 is listed more than once."));
+}
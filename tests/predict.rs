@@ -0,0 +1,166 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol", "dave"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (scores, time) in [
+        (["alice::10", "bob::8", "carol::2", "dave::1"], "2025-01-01"),
+        (["alice::12", "bob::9", "carol::4", "dave::3"], "2025-01-02"),
+        (["alice::11", "bob::7", "carol::5", "dave::2"], "2025-01-03"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", "catan"])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn test_predict_favours_stronger_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "predict",
+            "catan",
+            "carol",
+            "alice",
+            "guest:erin",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json[0]["player"], "alice");
+    let total = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["probability"].as_f64().unwrap())
+        .sum::<f64>();
+    assert!((total - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_predict_table() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["predict", "catan", "alice", "bob", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Win probabilities for catan (trueskill):"))
+        .stdout(contains("%"));
+}
+
+#[test]
+fn test_balance_pairs_strong_with_weak() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "balance",
+            "alice",
+            "bob",
+            "carol",
+            "dave",
+            "--teams",
+            "2",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("alice, dave"))
+        .stdout(contains("bob, carol"))
+        .stdout(contains("Strength gap:"));
+}
+
+#[test]
+fn test_predict_rejects_unknown_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["predict", "catan", "alice", "bobb", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Player bobb does not exist. Did you mean bob?"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "balance",
+            "alice",
+            "bob",
+            "carol",
+            "erin",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Player erin does not exist."));
+}
+
+#[test]
+fn test_balance_resolves_aliases() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["player", "alias", "alice", "ali", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "balance",
+            "ALI",
+            "bob",
+            "carol",
+            "dave",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("alice, dave"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["balance", "ali", "alice", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Player alice is listed more than once."));
+}
@@ -0,0 +1,85 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (game, scores, time) in [
+        ("catan", ["alice::10", "bob::8", "carol::2"], "2025-01-01"),
+        ("catan", ["alice::12", "bob::9", "carol::4"], "2025-01-02"),
+        ("azul", ["alice::40", "bob::30", "carol::50"], "2025-01-03"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", game])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+fn ratings_json(save_dir: &str, extra_args: &[&str]) -> serde_json::Value {
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["ratings", "--format", "json", "--save-dir", save_dir])
+        .args(extra_args)
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn test_ratings_table() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["ratings", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Ratings (trueskill, global pool):"))
+        .stdout(contains("uncertainty"))
+        .stdout(contains("±"));
+}
+
+#[test]
+fn test_ratings_per_game_pool() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let json = ratings_json(temp_path, &["--game", "azul", "--algorithm", "glicko2"]);
+    assert_eq!(json["algorithm"], "glicko2");
+    assert_eq!(json["pool"], "azul");
+    assert_eq!(json["ratings"][0]["player"], "carol");
+    assert_eq!(json["ratings"][0]["matches"], 1);
+    assert!(json["ratings"][0]["deviation"].as_f64().unwrap() < 350.0);
+}
+
+#[test]
+fn test_ratings_algorithm_from_config() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    std::fs::write(
+        temp.path().join("config.json"),
+        r#"{"rating_algorithm": "glicko2"}"#,
+    )
+    .unwrap();
+
+    let json = ratings_json(temp_path, &[]);
+    assert_eq!(json["algorithm"], "glicko2");
+    assert_eq!(json["ratings"][0]["player"], "alice");
+}
@@ -0,0 +1,150 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (game, scores) in [
+        ("catan", ["alice::10", "bob::8"]),
+        ("catna", ["alice::3", "bob::9"]),
+        ("catna", ["alice::7", "bob::2"]),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", game])
+            .args(scores)
+            .args(["--time", "2025-01-10", "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+fn read_games(path: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_rename_game_merges_into_existing_file() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    let games_dir = temp.path().join("games");
+    let moved_ids = read_games(&games_dir.join("catna.json"))["games"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["rename-game", "catna", "catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Moved 2 matches from catna into the existing game catan.",
+        ));
+
+    assert!(!games_dir.join("catna.json").exists());
+    let games = read_games(&games_dir.join("catan.json"));
+    let games = games["games"].as_object().unwrap();
+    assert_eq!(games.len(), 3);
+    for id in moved_ids {
+        assert_eq!(games[&id]["game_name"], "catan");
+    }
+}
+
+#[test]
+fn test_rename_game_updates_catalog_and_can_be_undone() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "add", "catan", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["rename-game", "catan", "Catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Renamed game catan to Catan (1 matches)."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "show", "Catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Catan"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    assert!(temp.path().join("games").join("catan.json").exists());
+    assert!(!temp.path().join("games").join("Catan.json").exists());
+}
+
+#[test]
+fn test_rename_unknown_game() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["rename-game", "azul", "Azul", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("No matches found for azul."));
+}
+
+#[test]
+fn test_rename_game_by_alias() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    for args in [
+        vec!["game", "add", "catan"],
+        vec!["game", "alias", "catan", "Settlers"],
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(args)
+            .args(["--save-dir", temp_path])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["rename-game", "Settlers", "Catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Renamed game catan to Catan (1 matches)."));
+
+    let games_dir = temp.path().join("games");
+    assert!(!games_dir.join("catan.json").exists());
+    assert!(games_dir.join("Catan.json").exists());
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["game", "show", "Settlers", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Catan"));
+}
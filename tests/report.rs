@@ -0,0 +1,64 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::tempdir;
+
+fn run(save_dir: &str, args: &[&str]) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(args)
+        .args(["--save-dir", save_dir])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_report_html_writes_site() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let site = temp.path().join("site");
+    for player in ["alice", "bob"] {
+        run(temp_path, &["add-player", player]);
+    }
+    run(
+        temp_path,
+        &["add-score", "Ticket to Ride", "alice::80", "bob::95"],
+    );
+    run(temp_path, &["add-score", "catan", "alice::10", "bob::8"]);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "report",
+            "html",
+            "--output",
+            site.to_str().unwrap(),
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Generated 5 pages"));
+
+    let index = fs::read_to_string(site.join("index.html")).unwrap();
+    assert!(index.contains("<a href=\"games/Ticket-to-Ride.html\">Ticket to Ride</a>"));
+    assert!(index.contains("<svg"));
+    let game = fs::read_to_string(site.join("games").join("catan.html")).unwrap();
+    assert!(game.contains("<a href=\"../players/alice.html\">alice</a>"));
+    assert!(site.join("players").join("bob.html").exists());
+}
+
+#[test]
+fn test_report_html_requires_output() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["report", "html", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Missing output directory for report. Use --output <dir>.",
+        ));
+}
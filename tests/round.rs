@@ -0,0 +1,168 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use regex::Regex;
+use tempfile::tempdir;
+
+fn start_match(save_dir: &str) -> String {
+    for player in ["alice", "bob"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    let output = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "round",
+            "start",
+            "skull king",
+            "alice",
+            "bob",
+            "--time",
+            "2025-08-07",
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let id = Regex::new(r"id: ([0-9a-f-]{36})").unwrap();
+    id.captures(&String::from_utf8(output).unwrap()).unwrap()[1].to_string()
+}
+
+#[test]
+fn test_round_add_keeps_running_total() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let id = start_match(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "round",
+            "add",
+            &id,
+            "alice::20",
+            "bob::30",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Added round 1 to game of skull king."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "round",
+            "add",
+            &id,
+            "alice::40",
+            "bob::-10",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Added round 2 to game of skull king."))
+        .stdout(contains("20 40"))
+        .stdout(contains("60"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["round", "show", &id, "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Game of skull king after 2 rounds, in progress."))
+        .stdout(contains("30 -10"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["leaderboard", "skull king", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("No matches found for skull king."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["round", "finish", &id, "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Finished game of skull king after 2 rounds."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["leaderboard", "skull king", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("alice"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["round", "add", &id, "alice::10", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains(format!("Match {} is already finished.", id)));
+}
+
+#[test]
+fn test_round_add_allows_negative_totals() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let id = start_match(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "round",
+            "add",
+            &id,
+            "alice::-20",
+            "bob::-50",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("-50"));
+
+    let output = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "round",
+            "show",
+            &id,
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let game: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(game["scores"]["alice"], -20);
+    assert_eq!(game["scores"]["bob"], -50);
+    assert_eq!(game["in_progress"], true);
+}
+
+#[test]
+fn test_round_add_rejects_unknown_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let id = start_match(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["round", "add", &id, "carol::20", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains(format!("Player carol is not in match {}.", id)));
+}
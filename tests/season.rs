@@ -0,0 +1,139 @@
+use assert_cmd::Command;
+use predicates::{prelude::*, str::contains};
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (game, scores, time) in [
+        ("catan", ["alice::10", "bob::8", "carol::2"], "2025-01-10"),
+        ("catan", ["alice::6", "bob::9", "carol::7"], "2025-02-10"),
+        ("azul", ["alice::50", "bob::40", "carol::30"], "2025-02-11"),
+        ("catan", ["alice::1", "bob::2", "carol::30"], "2025-04-01"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", game])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+fn create_season(save_dir: &str, extra_args: &[&str]) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "season",
+            "create",
+            "q1",
+            "--start",
+            "2025-01-01",
+            "--end",
+            "2025-03-31",
+            "--save-dir",
+            save_dir,
+        ])
+        .args(extra_args)
+        .assert()
+        .success()
+        .stdout(contains("Created season q1."));
+}
+
+#[test]
+fn test_season_standings_win_draw_loss() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    create_season(temp_path, &["--games", "catan"]);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "season",
+            "standings",
+            "q1",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json[0]["points"], 3);
+    assert_eq!(json[0]["matches"], 2);
+    assert_eq!(json[2]["player"], "carol");
+    assert_eq!(json[2]["points"], 0);
+}
+
+#[test]
+fn test_season_standings_f1() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    create_season(temp_path, &["--scheme", "f1"]);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["season", "standings", "q1", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("65"));
+}
+
+#[test]
+fn test_season_list_and_duplicate() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    create_season(temp_path, &[]);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["season", "list", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("q1"))
+        .stdout(contains("3-1-0"))
+        .stdout(contains("all"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "season",
+            "create",
+            "q1",
+            "--start",
+            "2025-01-01",
+            "--end",
+            "2025-03-31",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Season q1 already exists."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Undid: Created season q1."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["season", "list", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("q1").not());
+}
@@ -0,0 +1,158 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn run(temp_path: &str, args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(args)
+        .args(["--save-dir", temp_path])
+        .assert()
+}
+
+#[test]
+fn test_session_report() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    for player in ["alice", "bob", "carol", "dave"] {
+        run(temp_path, &["add-player", player]).success();
+    }
+    run(
+        temp_path,
+        &["session", "start", "friday", "dave", "--time", "2025-08-07"],
+    )
+    .success()
+    .stdout(contains("Started session friday."));
+
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "bob::5",
+            "--time",
+            "2025-08-07",
+            "--duration",
+            "1h30m",
+            "--session",
+            "friday",
+        ],
+    )
+    .success();
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "azul",
+            "alice::40",
+            "carol::45",
+            "--time",
+            "2025-08-07",
+            "--duration",
+            "45",
+            "--session",
+            "friday",
+        ],
+    )
+    .success();
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "carol::5",
+            "--time",
+            "2025-08-07",
+            "--session",
+            "friday",
+        ],
+    )
+    .success();
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "bob::10",
+            "carol::5",
+            "--time",
+            "2025-08-07",
+        ],
+    )
+    .success();
+
+    run(temp_path, &["session", "report", "friday"])
+        .success()
+        .stdout(contains("Attendees: alice, bob, carol, dave."))
+        .stdout(contains("catan x2"))
+        .stdout(contains("Won by alice with 6 points."))
+        .stdout(contains(
+            "Total play time: 2h 15m (1 of 3 matches without a duration).",
+        ));
+
+    run(
+        temp_path,
+        &["session", "report", "friday", "--scheme", "placement:1,0"],
+    )
+    .success()
+    .stdout(contains("Won by alice with 2 points."));
+}
+
+#[test]
+fn test_session_end_closes_it() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    for player in ["alice", "bob"] {
+        run(temp_path, &["add-player", player]).success();
+    }
+    run(temp_path, &["session", "start", "friday"]).success();
+    run(temp_path, &["session", "end", "friday"])
+        .success()
+        .stdout(contains("Ended session friday."));
+
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "bob::5",
+            "--session",
+            "friday",
+        ],
+    )
+    .failure()
+    .stderr(contains("Session friday has already ended."));
+
+    run(temp_path, &["session", "list"])
+        .success()
+        .stdout(contains("friday"))
+        .stdout(contains("ended"));
+}
+
+#[test]
+fn test_add_score_unknown_session() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    for player in ["alice", "bob"] {
+        run(temp_path, &["add-player", player]).success();
+    }
+    run(
+        temp_path,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "bob::5",
+            "--session",
+            "friday",
+        ],
+    )
+    .failure()
+    .stderr(contains("Session friday not found."));
+}
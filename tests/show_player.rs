@@ -0,0 +1,111 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use regex::Regex;
+use tempfile::tempdir;
+
+fn run(save_dir: &str, args: &[&str]) -> String {
+    let output = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(args)
+        .args(["--save-dir", save_dir])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol", "dave"] {
+        run(save_dir, &["add-player", player]);
+    }
+    run(
+        save_dir,
+        &[
+            "add-score",
+            "catan",
+            "alice::10",
+            "bob::8",
+            "--time",
+            "2025-01-01",
+        ],
+    );
+    run(
+        save_dir,
+        &[
+            "add-score",
+            "azul",
+            "alice::40",
+            "bob::50",
+            "carol::45",
+            "--time",
+            "2025-01-02",
+        ],
+    );
+    run(
+        save_dir,
+        &[
+            "add-score",
+            "catan",
+            "alice::12",
+            "carol::8",
+            "--time",
+            "2025-01-03",
+        ],
+    );
+}
+
+#[test]
+fn test_list_players_sorted_by_games_played() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let output = run(temp_path, &["list-players", "--sort", "games"]);
+    let order = Regex::new(r"(?s)alice.*bob.*carol.*dave").unwrap();
+    assert!(order.is_match(&output));
+    assert!(output.contains("2025-01-03"));
+
+    let output = run(temp_path, &["list-players", "--sort", "last-played"]);
+    let order = Regex::new(r"(?s)alice.*carol.*bob.*dave").unwrap();
+    assert!(order.is_match(&output));
+
+    let output = run(temp_path, &["list-players", "--format", "json"]);
+    assert!(output.contains("\"games_played\": 3"));
+}
+
+#[test]
+fn test_show_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let output = run(temp_path, &["show-player", "alice"]);
+    assert!(output.contains("3 matches, 2 wins. Form: W-L-W"));
+    assert!(output.contains("rank 2 of 3"));
+    assert!(output.contains("3 of 3"));
+    assert!(output.contains("100%"));
+    let newest_first = Regex::new(r"(?s)2025-01-03.*2025-01-02.*2025-01-01").unwrap();
+    assert!(newest_first.is_match(&output));
+
+    let output = run(temp_path, &["show-player", "alice", "--format", "json"]);
+    assert!(output.contains("\"history\""));
+    assert!(output.contains("\"best_score\": 12"));
+
+    let output = run(temp_path, &["show-player", "dave"]);
+    assert!(output.contains("No matches played."));
+}
+
+#[test]
+fn test_show_unknown_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["show-player", "nobody", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Player nobody not found."));
+}
@@ -0,0 +1,86 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (game, scores, time) in [
+        ("catan", ["alice::10", "bob::8"], "2025-01-01"),
+        ("catan", ["alice::6", "bob::8"], "2025-01-02"),
+        ("azul", ["alice::50", "bob::40"], "2025-01-03"),
+        ("catan", ["alice::9", "bob::8"], "2025-01-04"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", game])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn test_stats_shows_form() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["stats", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("W-L-W-W"))
+        .stdout(contains("L-W-L-L"));
+}
+
+#[test]
+fn test_stats_per_game_json() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "stats",
+            "--game",
+            "catan",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["alice"]["plays"], 3);
+    assert_eq!(json["alice"]["current_win_streak"], 1);
+    assert_eq!(json["alice"]["form"], "W-L-W");
+    assert_eq!(json["bob"]["longest_drought"], 1);
+}
+
+#[test]
+fn test_leaderboard_shows_form() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["leaderboard", "catan", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("form"))
+        .stdout(contains("W-L-W"));
+}
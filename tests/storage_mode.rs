@@ -0,0 +1,242 @@
+use assert_cmd::Command;
+use predicates::{prelude::*, str::contains};
+use tempfile::tempdir;
+
+fn add_catan_game(save_dir: &str, time: &str) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "player2::20",
+            "--time",
+            time,
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success();
+}
+
+fn setup(save_dir: &str) {
+    for player in ["player1", "player2"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+    add_catan_game(save_dir, "2025-01-10");
+}
+
+#[test]
+fn test_switch_to_events_seeds_log() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Switched storage mode to events."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Storage mode: events."));
+
+    let events = std::fs::read_to_string(temp.path().join("events.jsonl")).unwrap();
+    assert_eq!(events.matches("PlayerAdded").count(), 2);
+    assert_eq!(events.matches("MatchRecorded").count(), 1);
+}
+
+#[test]
+fn test_mutations_append_events() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success();
+    add_catan_game(temp_path, "2025-02-10");
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    let events = std::fs::read_to_string(temp.path().join("events.jsonl")).unwrap();
+    assert_eq!(events.matches("MatchRecorded").count(), 2);
+    assert_eq!(events.matches("MatchDeleted").count(), 1);
+}
+
+#[test]
+fn test_list_games_as_of() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "list-games",
+            "--as-of",
+            "2025-01-01",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Point in time queries require the events storage mode.",
+        ));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "list-games",
+            "--as-of",
+            "2025-01-09",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("catan").not());
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "list-games",
+            "--as-of",
+            "2025-01-10",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("catan"));
+}
+
+#[test]
+fn test_as_of_leaves_out_matches_recorded_later() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success();
+    // Recorded today, but played before the first match.
+    add_catan_game(temp_path, "2025-01-05");
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "leaderboard",
+            "catan",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("\"matches\": 2,"));
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "leaderboard",
+            "catan",
+            "--as-of",
+            "2025-01-10",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success()
+        .stdout(contains("\"matches\": 1,"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["ratings", "--as-of", "2025-01-04", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("player1").not());
+}
+
+#[test]
+fn test_events_mode_reads_from_log() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success();
+    std::fs::remove_dir_all(temp.path().join("games")).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("2025-01-10"));
+}
+
+#[test]
+fn test_rebuild_from_events() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "rebuild", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Rebuilding requires the events storage mode."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "events", "--save-dir", temp_path])
+        .assert()
+        .success();
+    std::fs::remove_file(temp.path().join("games").join("catan.json")).unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["storage-mode", "rebuild", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Rebuilt 2 players and 1 games from the event log.",
+        ));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("catan"));
+}
@@ -0,0 +1,239 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str, players: &[&str]) {
+    for player in players {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+fn create_tournament(save_dir: &str, format: &str, players: &[&str]) {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["tournament", "create", "cup", format])
+        .args(players)
+        .args(["--game", "chess", "--save-dir", save_dir])
+        .assert()
+        .success()
+        .stdout(contains("Created tournament cup."));
+}
+
+fn record(save_dir: &str, scores: [&str; 2]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["tournament", "record", "cup"])
+        .args(scores)
+        .args(["--time", "2025-05-01", "--save-dir", save_dir])
+        .assert()
+}
+
+#[test]
+fn test_single_elimination_advances_to_final() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let players = ["alice", "bob", "carol", "dave"];
+    setup(temp_path, &players);
+    create_tournament(temp_path, "single-elimination", &players);
+
+    record(temp_path, ["alice::3", "dave::1"])
+        .success()
+        .stdout(contains("Round 1 of cup in progress."));
+    record(temp_path, ["bob::2", "carol::4"])
+        .success()
+        .stdout(contains("Round 2 of cup in progress."));
+    record(temp_path, ["alice::5", "carol::2"])
+        .success()
+        .stdout(contains("Tournament cup won by alice."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["tournament", "show", "cup", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("alice vs carol"))
+        .stdout(contains("Won by alice."));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("chess").count(3));
+}
+
+#[test]
+fn test_round_robin_standings() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let players = ["alice", "bob", "carol"];
+    setup(temp_path, &players);
+    create_tournament(temp_path, "round-robin", &players);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "tournament",
+            "show",
+            "cup",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let tournament: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(tournament["format"], "round_robin");
+    assert_eq!(tournament["rounds"].as_array().unwrap().len(), 1);
+
+    record(temp_path, ["bob::1", "carol::1"]).success();
+    record(temp_path, ["alice::2", "carol::0"]).success();
+    record(temp_path, ["alice::2", "bob::1"])
+        .success()
+        .stdout(contains("Tournament cup won by alice."));
+}
+
+#[test]
+fn test_record_rejects_unscheduled_match() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let players = ["alice", "bob", "carol", "dave"];
+    setup(temp_path, &players);
+    create_tournament(temp_path, "single-elimination", &players);
+
+    record(temp_path, ["alice::3", "bob::1"])
+        .failure()
+        .stderr(contains(
+            "No pending match between alice and bob in the current round.",
+        ));
+    record(temp_path, ["alice::1", "dave::1"])
+        .failure()
+        .stderr(contains("Elimination matches cannot end in a tie."));
+}
+
+#[test]
+fn test_create_tournament_with_unknown_player() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path, &["alice"]);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "tournament",
+            "create",
+            "cup",
+            "swiss",
+            "alice",
+            "bob",
+            "--game",
+            "chess",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("Player bob does not exist."));
+}
+
+#[test]
+fn test_create_tournament_resolves_names() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path, &["alice", "bob"]);
+    for args in [
+        vec!["game", "add", "Chess", "--max-players", "2"],
+        vec!["game", "alias", "Chess", "scacchi"],
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(args)
+            .args(["--save-dir", temp_path])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "tournament",
+            "create",
+            "cup",
+            "single-elimination",
+            "Alice",
+            "BOB",
+            "--game",
+            "scacchi",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "tournament",
+            "show",
+            "cup",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let tournament: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(tournament["game_name"], "Chess");
+    assert_eq!(tournament["players"], serde_json::json!(["alice", "bob"]));
+
+    record(temp_path, ["ALICE::1", "bob::0"])
+        .success()
+        .stdout(contains("Tournament cup won by alice."));
+}
+
+#[test]
+fn test_create_tournament_checks_catalog_player_count() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path, &["alice", "bob", "carol"]);
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "game",
+            "add",
+            "catan",
+            "--min-players",
+            "3",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "tournament",
+            "create",
+            "cup",
+            "round-robin",
+            "alice",
+            "bob",
+            "carol",
+            "--game",
+            "catan",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("catan needs at least 3 players, got 2."));
+}
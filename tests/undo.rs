@@ -0,0 +1,152 @@
+use assert_cmd::Command;
+use predicates::{prelude::*, str::contains};
+use regex::Regex;
+use tempfile::tempdir;
+
+fn add_catan_game(save_dir: &str) -> String {
+    for player in ["player1", "player2"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::10",
+            "player2::20",
+            "--time",
+            "2025-08-07",
+            "--save-dir",
+            save_dir,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let re = Regex::new(r"(?i)id: ([0-9a-fA-F\-]{36})").unwrap();
+    re.captures(&output).expect("UUID not found in output")[1].to_string()
+}
+
+#[test]
+fn test_undo_and_redo_delete_score() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let game_id = add_catan_game(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["delete-score", &game_id, "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Undid: Removed game of catan"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains(game_id.as_str()));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["redo", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Redid: Removed game of catan"));
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["list-games", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains(game_id.as_str()).not());
+}
+
+#[test]
+fn test_undo_add_score_removes_game_file() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    add_catan_game(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("Undid: Added game of catan"));
+
+    assert!(!temp.path().join("games").join("catan.json").exists());
+}
+
+#[test]
+fn test_history_lists_mutations() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    add_catan_game(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .success();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["history", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("add-player"))
+        .stdout(contains("Added player player1."))
+        .stdout(contains("add-score"))
+        .stdout(contains("undone"))
+        .stdout(contains("Undid: Added game of catan"));
+}
+
+#[test]
+fn test_undo_with_empty_journal() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["undo", "--save-dir", temp_path])
+        .assert()
+        .failure()
+        .stderr(contains("Nothing to undo."));
+}
+
+#[test]
+fn test_journal_records_only_changed_matches() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    let first_id = add_catan_game(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "add-score",
+            "catan",
+            "player1::12",
+            "player2::8",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let journal = std::fs::read_to_string(temp.path().join("journal.jsonl")).unwrap();
+    let last_entry = journal.lines().last().unwrap();
+    assert!(last_entry.contains("Added game of catan"));
+    assert!(!last_entry.contains(first_id.as_str()));
+}
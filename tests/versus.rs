@@ -0,0 +1,130 @@
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn setup(save_dir: &str) {
+    for player in ["alice", "bob", "carol"] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-player", player, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+
+    for (game, scores, time) in [
+        ("catan", ["alice::10", "bob::8"], "2025-01-01"),
+        ("catan", ["alice::6", "bob::8"], "2025-01-02"),
+        ("azul", ["alice::50", "bob::40"], "2025-01-03"),
+        ("azul", ["alice::50", "carol::40"], "2025-01-04"),
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(["add-score", game])
+            .args(scores)
+            .args(["--time", time, "--save-dir", save_dir])
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn test_versus_table() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["versus", "alice", "bob", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("+3.33"))
+        .stdout(contains("Last encounters:"))
+        .stdout(contains("2025-01-03"))
+        .stdout(contains("2025-01-04").count(0));
+}
+
+#[test]
+fn test_versus_json_filtered_by_game() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "versus",
+            "alice",
+            "bob",
+            "--game",
+            "catan",
+            "--last",
+            "1",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["encounters"], 2);
+    assert_eq!(json["players"][0]["wins"], 1);
+    assert_eq!(json["players"][1]["wins"], 1);
+    assert_eq!(json["last_encounters"].as_array().unwrap().len(), 1);
+    assert_eq!(json["last_encounters"][0]["winner"], "bob");
+}
+
+#[test]
+fn test_versus_game_alias() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+    for args in [
+        vec!["game", "add", "catan"],
+        vec!["game", "alias", "catan", "Settlers"],
+    ] {
+        Command::cargo_bin("score-cli")
+            .unwrap()
+            .args(args)
+            .args(["--save-dir", temp_path])
+            .assert()
+            .success();
+    }
+
+    let assert = Command::cargo_bin("score-cli")
+        .unwrap()
+        .args([
+            "versus",
+            "alice",
+            "bob",
+            "--game",
+            "Settlers",
+            "--format",
+            "json",
+            "--save-dir",
+            temp_path,
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["encounters"], 2);
+}
+
+#[test]
+fn test_versus_without_encounters() {
+    let temp = tempdir().unwrap();
+    let temp_path = temp.path().to_str().unwrap();
+    setup(temp_path);
+
+    Command::cargo_bin("score-cli")
+        .unwrap()
+        .args(["versus", "bob", "carol", "--save-dir", temp_path])
+        .assert()
+        .success()
+        .stdout(contains("No matches found between bob and carol."));
+}